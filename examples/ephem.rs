@@ -1,18 +1,17 @@
-use pracstro::{sol, time};
+use pracstro::{moon, sol, time, CelObj};
 
 fn main() {
     let now = time::Date::now();
-    for p in sol::PLANETS {
-        let (ra, de) = p.location(now).equatorial();
-        let ((rah, ram, _), (ded, dem, _)) = (ra.clock(), de.to_latitude().degminsec());
+    let objs = [&sol::SUN as &dyn CelObj, &moon::MOON]
+        .into_iter()
+        .chain(sol::PLANETS.map(|p| p as &dyn CelObj));
+    for o in objs {
         println!(
-            "{:<10} {:>2}h{:02} RA {:>3}°{:02}' De {:.2} AU",
-            p.name,
-            rah,
-            ram,
-            ded,
-            dem,
-            p.distance(now)
+            "{:<10} {:.0} {:.2} {:>6.2}",
+            o.name(),
+            o.location(now),
+            o.distance(now),
+            o.magnitude(now)
         );
     }
 }
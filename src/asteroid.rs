@@ -0,0 +1,281 @@
+/*! Minor planets, such as asteroids, from the orbital elements of the Minor Planet Center
+
+* Reading a line of `MPCORB.DAT`: [`MinorPlanet::from_mpcorb_line()`]
+* Position in the orbit, as with planets: [`MinorPlanet::elements_at()`]
+* Brightness with the H, G system: [`MinorPlanet::magnitude()`]
+* Phase angle and illuminated fraction
+
+Minor planets are [`CelObj`]s, so they can be observed and [`register()`](crate::register)ed like anything else.
+Their orbits are fixed ellipses about the sun, so positions drift away from the truth over years from the epoch.
+```
+# use pracstro::{asteroid::MinorPlanet, time::Date, CelObj};
+let ceres = MinorPlanet::from_mpcorb_line(
+    "00001    3.34  0.15 K2555 188.70269   73.27343   80.25221   10.58780  0.0795762  0.21424651   2.7660512  0 MPO912975  7341 125 1801-2025 0.68 M-v 30k MPCLINUX   0000 (1) Ceres                   20250318",
+)
+.unwrap();
+let now = Date::now();
+println!("{} {:.0} {:.1}", ceres.name(), ceres.location(now), ceres.magnitude(now));
+```
+
+Format from <https://minorplanetcenter.net/iau/info/MPOrbitFormat.html>
+*/
+
+use crate::celobj::CelObj;
+use crate::coord::{Coord, Distance, Vector3};
+use crate::sol;
+use crate::time::{Angle, Date, J2000};
+
+/// A line of MPC orbital elements that couldn't be read, from [`MinorPlanet::from_mpcorb_line()`]
+/// or [`Comet::from_mpc_comet_line()`](crate::comet::Comet::from_mpc_comet_line)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseMpcError {
+    /// The field of [`MinorPlanet`] or [`Comet`](crate::comet::Comet) that is missing or unreadable
+    pub field: &'static str,
+}
+impl std::fmt::Display for ParseMpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid MPC orbital elements, bad value for {}",
+            self.field
+        )
+    }
+}
+impl std::error::Error for ParseMpcError {}
+impl From<ParseMpcError> for crate::Error {
+    fn from(e: ParseMpcError) -> Self {
+        crate::Error::ParseMpc { field: e.field }
+    }
+}
+
+/// Reads a date packed into five characters by the MPC, such as `K2555` for 2025 May 5
+///
+/// The first character is the century (`I` for 1800, `J` for 1900, `K` for 2000), then the two digit year,
+/// then the month and day, written 1-9 and then A-V for 10-31.
+pub(crate) fn unpack_date(s: &str) -> Option<Date> {
+    let digit = |c: u8| match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'A'..=b'V' => Some(c - b'A' + 10),
+        _ => None,
+    };
+    let &[c, y1, y2, m, d] = s.as_bytes() else {
+        return None;
+    };
+    if !c.is_ascii_uppercase() || !y1.is_ascii_digit() || !y2.is_ascii_digit() {
+        return None;
+    }
+    let year = (c - b'A' + 10) as i64 * 100 + ((y1 - b'0') * 10 + (y2 - b'0')) as i64;
+    Date::try_from_calendar(year, digit(m)?, digit(d)?, Angle::default()).ok()
+}
+
+/// An asteroid or other minor planet on an elliptical orbit about the sun, with its brightness in the H, G system
+///
+/// Angles are in the ecliptic frame of J2000, as given by the MPC.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinorPlanet {
+    /// Name, or the designation if it has none, such as "(1) Ceres"
+    pub name: String,
+    /// Absolute magnitude, the magnitude at 1 AU from the sun and earth, seen fully lit
+    pub h: f64,
+    /// Slope parameter, how much the brightness falls off with phase
+    pub g: f64,
+    /// Date of the elements
+    pub epoch: Date,
+    /// Mean anomaly at the epoch
+    pub m: Angle,
+    /// Argument of perihelion
+    pub w: Angle,
+    /// Longitude of the ascending node
+    pub o: Angle,
+    /// Inclination
+    pub i: Angle,
+    /// Eccentricity
+    pub e: f64,
+    /// Mean daily motion (degrees per day)
+    pub n: f64,
+    /// Semi-Major Axis (AU)
+    pub a: f64,
+}
+impl MinorPlanet {
+    /// Reads a minor planet from a line of the fixed width format used in `MPCORB.DAT` by the Minor Planet Center
+    ///
+    /// A blank slope parameter is taken as the usual 0.15. The readable designation at the end of the line is used
+    /// as the name when it is there, the packed designation at the start otherwise.
+    pub fn from_mpcorb_line(line: &str) -> Result<MinorPlanet, ParseMpcError> {
+        let text = |a: usize, b: usize| line.get(a - 1..b.min(line.len())).unwrap_or("").trim();
+        let number = |a, b, field| {
+            text(a, b)
+                .parse::<f64>()
+                .map_err(|_| ParseMpcError { field })
+        };
+        let angle = |a, b, field| number(a, b, field).map(Angle::from_degrees);
+
+        let name = match text(167, 194) {
+            "" => text(1, 7),
+            readable => readable,
+        };
+        if name.is_empty() {
+            return Err(ParseMpcError { field: "name" });
+        }
+        Ok(MinorPlanet {
+            name: name.to_string(),
+            h: number(9, 13, "h")?,
+            g: match text(15, 19) {
+                "" => 0.15,
+                _ => number(15, 19, "g")?,
+            },
+            epoch: unpack_date(text(21, 25)).ok_or(ParseMpcError { field: "epoch" })?,
+            m: angle(27, 35, "m")?,
+            w: angle(38, 46, "w")?,
+            o: angle(49, 57, "o")?,
+            i: angle(60, 68, "i")?,
+            e: number(71, 79, "e")?,
+            n: number(81, 91, "n")?,
+            a: number(93, 103, "a")?,
+        })
+    }
+
+    /// The orbital elements and anomalies at a date, moving the mean anomaly along from the epoch
+    pub fn elements_at(&self, d: Date) -> sol::Elements {
+        let m = self.m + Angle::from_degrees(self.n * (d.julian() - self.epoch.julian()));
+        let w = self.w + self.o;
+        sol::Elements::new(self.a, self.e, self.i, w + m, w, self.o, m)
+    }
+
+    /// Returns the heliocentric location as rectangular coordinates in AU, in the same frame as [`sol::Planet::locationcart()`]
+    pub fn locationcart(&self, d: Date) -> Vector3 {
+        sol::to_equatorial(self.elements_at(d).position())
+    }
+
+    /// Returns the geocentric location
+    pub fn location(&self, d: Date) -> Coord {
+        (self.locationcart(d) - sol::earth_geocenter(d)).into()
+    }
+
+    /// Returns the distance from the earth
+    pub fn distance(&self, d: Date) -> Distance {
+        Distance::from_au((self.locationcart(d) - sol::earth_geocenter(d)).norm())
+    }
+
+    /// Returns the location relative to the barycenter of the solar system, in AU, see [`sol::ssb_offset()`]
+    pub fn location_barycentric(&self, d: Date) -> Vector3 {
+        self.locationcart(d) + sol::ssb_offset(d)
+    }
+
+    /// The angle between the sun and earth as seen from the minor planet
+    pub fn phaseangle(&self, d: Date) -> Angle {
+        let p = self.locationcart(d);
+        let to_earth = sol::earth_geocenter(d) - p;
+        Angle::acos(-p.dot(to_earth) / (p.norm() * to_earth.norm()))
+    }
+
+    /// The illuminated fraction of the disk, taking it as a sphere
+    pub fn illumfrac(&self, d: Date) -> f64 {
+        0.5 * (1.0 + self.phaseangle(d).cos())
+    }
+
+    /// Apparent magnitude, from the absolute magnitude and slope parameter
+    ///
+    /// From the H, G system of Bowell et al. (1989), adopted by the IAU in 1985
+    pub fn magnitude(&self, d: Date) -> f64 {
+        let r = self.locationcart(d).norm();
+        let tan = (self.phaseangle(d) / 2.0).tan();
+        let phi1 = (-3.33 * tan.powf(0.63)).exp();
+        let phi2 = (-1.87 * tan.powf(1.22)).exp();
+        self.h + 5.0 * (r * self.distance(d).au()).log10()
+            - 2.5 * ((1.0 - self.g) * phi1 + self.g * phi2).log10()
+    }
+}
+
+/// The origin of [`CelObj::locationcart()`] is the sun, as with [`sol::Planet`]
+impl CelObj for MinorPlanet {
+    fn locationcart(&self, d: Date) -> Vector3 {
+        MinorPlanet::locationcart(self, d)
+    }
+    fn location(&self, d: Date) -> Coord {
+        MinorPlanet::location(self, d)
+    }
+    fn location_of_date(&self, d: Date) -> Coord {
+        MinorPlanet::location(self, d).precess_rigorous(J2000, d)
+    }
+    fn distance(&self, d: Date) -> Distance {
+        MinorPlanet::distance(self, d)
+    }
+    fn illumfrac(&self, d: Date) -> Option<f64> {
+        Some(MinorPlanet::illumfrac(self, d))
+    }
+    fn magnitude(&self, d: Date) -> f64 {
+        MinorPlanet::magnitude(self, d)
+    }
+    /// Minor planets are too small to be seen as a disk
+    fn angdia(&self, _d: Date) -> Angle {
+        Angle::default()
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERES: &str = "00001    3.34  0.15 K2555 188.70269   73.27343   80.25221   10.58780  0.0795762  0.21424651   2.7660512  0 MPO912975  7341 125 1801-2025 0.68 M-v 30k MPCLINUX   0000 (1) Ceres                   20250318";
+
+    #[test]
+    fn test_unpack_date() {
+        let date = |y, m, d| Some(Date::from_calendar(y, m, d, Angle::default()));
+        assert_eq!(unpack_date("K2555"), date(2025, 5, 5));
+        assert_eq!(unpack_date("J96A1"), date(1996, 10, 1));
+        assert_eq!(unpack_date("K24CV"), date(2024, 12, 31));
+        assert_eq!(unpack_date("K25D1"), None);
+        assert_eq!(unpack_date("K255"), None);
+    }
+
+    #[test]
+    fn test_from_mpcorb_line() {
+        let ceres = MinorPlanet::from_mpcorb_line(CERES).unwrap();
+        assert_eq!(ceres.name, "(1) Ceres");
+        assert_eq!(
+            (ceres.h, ceres.g, ceres.e, ceres.a),
+            (3.34, 0.15, 0.0795762, 2.7660512)
+        );
+        assert_approx_eq!(ceres.i, Angle::from_degrees(10.5878));
+
+        // Without the readable designation or the slope parameter
+        let short = format!("{}     {}", &CERES[..14], &CERES[19..104]);
+        let packed = MinorPlanet::from_mpcorb_line(&short).unwrap();
+        assert_eq!((packed.name.as_str(), packed.g), ("00001", 0.15));
+
+        let bad = CERES.replacen("0.0795762", "0.07x5762", 1);
+        assert_eq!(
+            MinorPlanet::from_mpcorb_line(&bad),
+            Err(ParseMpcError { field: "e" })
+        );
+        assert_eq!(
+            MinorPlanet::from_mpcorb_line(""),
+            Err(ParseMpcError { field: "name" })
+        );
+        assert_eq!(
+            crate::Error::from(ParseMpcError { field: "e" }).to_string(),
+            ParseMpcError { field: "e" }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_opposition() {
+        // Ceres was at opposition on 2025 October 2, at magnitude 7.6 in Cetus
+        let ceres = MinorPlanet::from_mpcorb_line(CERES).unwrap();
+        let d = Date::from_calendar(2025, 10, 2, Angle::default());
+        let ecliptic = |c: Coord| c.to_ecliptic(crate::time::J2000).lon();
+        let diff = ecliptic(ceres.location(d)) - ecliptic(sol::SUN.location(d));
+        assert!((diff.degrees() - 180.0).abs() < 2.0);
+        assert!((ceres.magnitude(d) - 7.6).abs() < 0.1);
+        assert!(ceres.illumfrac(d) > 0.99);
+        assert_eq!(
+            ceres.location(d).constellation(crate::time::J2000),
+            crate::constellation::Constellation::Cetus
+        );
+    }
+}
@@ -0,0 +1,692 @@
+/*! Built in catalogs of stars and deep-sky objects
+
+With the `catalog` feature, [`BRIGHT_STARS`] contains the brightest and the navigational stars, along with others with proper names,
+and [`lookup()`] finds one by name. [`MESSIER`] and [`NGC`] contain deep-sky objects, found with [`lookup_deep_sky()`].
+Other star lists, such as extracts of Gaia or Hipparcos, can be read with [`from_csv()`].
+```
+# use pracstro::{catalog, time::Date, CelObj};
+let sirius = catalog::lookup("sirius").unwrap();
+sirius.location(Date::now());
+let andromeda = catalog::lookup_deep_sky("M31").unwrap();
+assert_eq!(andromeda.common_name, Some("Andromeda Galaxy"));
+```
+*/
+
+use crate::celobj::CelObj;
+use crate::coord::{Coord, Distance, Vector3};
+use crate::star::Star;
+use crate::time::{Angle, AngularRate, Date, J2000};
+use std::borrow::Cow;
+
+/// A star from its J2000 ICRS position in degrees, proper motion and parallax in milliarcseconds (per year)
+const fn star(
+    name: &'static str,
+    ra: f64,
+    de: f64,
+    pm_ra: f64,
+    pm_dec: f64,
+    plx: f64,
+    vmag: f64,
+) -> Star {
+    Star {
+        name: Cow::Borrowed(name),
+        coord_icrs: Coord::from_equatorial(Angle::from_degrees(ra), Angle::from_degrees(de)),
+        pm_ra: AngularRate::from_arcsec_per_year(pm_ra / 1000.0),
+        pm_dec: AngularRate::from_arcsec_per_year(pm_dec / 1000.0),
+        parallax: Angle::from_degrees(plx / 3_600_000.0),
+        vmag,
+        epoch: J2000,
+    }
+}
+
+/// Bright and named stars, in order of brightness
+///
+/// Positions, proper motions, and parallaxes are from Hipparcos, magnitudes are visual. The combined light and
+/// motion is given for close pairs such as Rigil Kentaurus.
+///
+/// Since stars are found by name, only stars with a proper name are included: those brighter than about
+/// magnitude 2.5, and fainter ones with traditional names. That is 194 stars, rather than the 300 or so
+/// to magnitude 3.5, many of which are only known by their Bayer designation. For a complete list to some
+/// magnitude, read an extract of Hipparcos with [`from_csv()`].
+#[rustfmt::skip]
+pub static BRIGHT_STARS: [Star; 194] = [
+    star("Sirius", 101.28717, -16.71611, -546.0, -1223.1, 379.2, -1.46),
+    star("Canopus", 95.98796, -52.69567, 19.9, 23.2, 10.6, -0.74),
+    star("Rigil Kentaurus", 219.90204, -60.83400, -3679.3, 473.7, 747.1, -0.27),
+    star("Arcturus", 213.91529, 19.18242, -1093.4, -2000.1, 88.8, -0.05),
+    star("Vega", 279.23475, 38.78369, 200.9, 286.2, 130.2, 0.03),
+    star("Capella", 79.17233, 45.99800, 75.5, -427.1, 77.3, 0.08),
+    star("Rigel", 78.63446, -8.20164, 1.3, 0.5, 3.8, 0.13),
+    star("Procyon", 114.82550, 5.22500, -714.6, -1036.8, 284.6, 0.34),
+    star("Achernar", 24.42854, -57.23675, 87.0, -38.2, 23.4, 0.46),
+    star("Betelgeuse", 88.79296, 7.40706, 27.5, 11.3, 6.6, 0.5),
+    star("Hadar", 210.95588, -60.37303, -33.3, -23.2, 8.3, 0.61),
+    star("Altair", 297.69583, 8.86833, 536.2, 385.3, 194.9, 0.76),
+    star("Acrux", 186.64958, -63.09908, -35.8, -14.9, 10.1, 0.76),
+    star("Aldebaran", 68.98017, 16.50931, 63.5, -188.9, 48.9, 0.86),
+    star("Antares", 247.35192, -26.43200, -12.1, -23.3, 5.9, 0.96),
+    star("Spica", 201.29825, -11.16133, -42.4, -30.7, 13.1, 0.97),
+    star("Pollux", 116.32896, 28.02619, -626.6, -45.8, 96.5, 1.14),
+    star("Fomalhaut", 344.41271, -29.62225, 328.9, -164.7, 130.1, 1.16),
+    star("Deneb", 310.35796, 45.28033, 2.0, 1.9, 2.3, 1.25),
+    star("Mimosa", 191.93029, -59.68878, -43.0, -16.2, 11.7, 1.25),
+    star("Regulus", 152.09296, 11.96722, -248.7, 5.6, 41.1, 1.4),
+    star("Adhara", 104.65646, -28.97208, 3.2, 1.3, 8.1, 1.5),
+    star("Castor", 113.64942, 31.88828, -191.5, -145.2, 64.1, 1.58),
+    star("Shaula", 263.40217, -37.10383, -8.5, -30.8, 5.7, 1.62),
+    star("Gacrux", 187.79150, -57.11322, 28.2, -265.1, 36.8, 1.63),
+    star("Bellatrix", 81.28275, 6.34969, -8.1, -12.9, 12.9, 1.64),
+    star("Elnath", 81.57296, 28.60744, 22.8, -173.6, 24.4, 1.65),
+    star("Miaplacidus", 138.29992, -69.71719, -156.5, 108.9, 28.8, 1.68),
+    star("Alnilam", 84.05337, -1.20192, 1.5, -1.1, 1.7, 1.69),
+    star("Alnair", 332.05825, -46.96097, 127.6, -147.9, 32.3, 1.74),
+    star("Alnitak", 85.18971, -1.94258, 3.2, 2.0, 4.4, 1.77),
+    star("Alioth", 193.50729, 55.95983, 111.7, -8.2, 39.5, 1.77),
+    star("Dubhe", 165.93196, 61.75103, -134.1, -34.7, 26.5, 1.79),
+    star("Mirfak", 51.08071, 49.86117, 24.1, -26.0, 6.4, 1.79),
+    star("Regor", 122.38313, -47.33658, -6.1, 10.4, 2.9, 1.83),
+    star("Wezen", 107.09783, -26.39319, -2.8, 3.3, 1.8, 1.84),
+    star("Kaus Australis", 276.04300, -34.38461, -39.6, -124.2, 22.8, 1.85),
+    star("Avior", 125.62850, -59.50947, -25.5, 22.7, 5.2, 1.86),
+    star("Alkaid", 206.88517, 49.31328, -121.2, -15.6, 31.4, 1.86),
+    star("Sargas", 264.32971, -42.99783, 6.1, -0.9, 12.0, 1.87),
+    star("Menkalinan", 89.88217, 44.94744, -56.4, -0.9, 39.7, 1.9),
+    star("Atria", 252.16625, -69.02772, 17.9, -32.9, 8.4, 1.91),
+    star("Alhena", 99.42796, 16.39928, -2.0, -66.9, 31.1, 1.93),
+    star("Peacock", 306.41192, -56.73508, 7.7, -86.2, 18.2, 1.94),
+    star("Alsephina", 131.17596, -54.70883, 28.8, -103.9, 40.9, 1.96),
+    star("Mirzam", 95.67496, -17.95592, -3.5, -0.5, 6.5, 1.98),
+    star("Alphard", 141.89683, -8.65861, -15.2, 34.4, 18.1, 1.98),
+    star("Polaris", 37.95454, 89.26411, 44.5, -11.9, 7.5, 1.98),
+    star("Hamal", 31.79338, 23.46242, 190.7, -145.8, 49.5, 2.0),
+    star("Algieba", 154.99312, 19.84150, 310.8, -152.9, 25.1, 2.01),
+    star("Diphda", 10.89738, -17.98661, 232.8, 32.7, 33.9, 2.04),
+    star("Nunki", 283.81638, -26.29672, 13.9, -52.7, 14.3, 2.05),
+    star("Mirach", 17.43300, 35.62056, 175.9, -112.2, 16.4, 2.05),
+    star("Menkent", 211.67062, -36.36994, -519.3, -517.9, 55.5, 2.06),
+    star("Alpheratz", 2.09692, 29.09044, 135.7, -162.9, 33.6, 2.06),
+    star("Rasalhague", 263.73362, 12.56003, 108.1, -221.6, 67.1, 2.07),
+    star("Tiaki", 340.66688, -46.88458, 135.7, -4.5, 18.4, 2.07),
+    star("Kochab", 222.67638, 74.15550, -32.6, 11.9, 24.9, 2.08),
+    star("Saiph", 86.93913, -9.66961, 1.6, -1.2, 5.0, 2.09),
+    star("Almach", 30.97479, 42.32972, 43.1, -50.9, 9.2, 2.1),
+    star("Algol", 47.04221, 40.95564, 2.4, -1.4, 35.1, 2.12),
+    star("Denebola", 177.26492, 14.57206, -497.7, -114.7, 90.2, 2.13),
+    star("Muhlifain", 190.37933, -48.95986, -187.3, -1.2, 25.0, 2.2),
+    star("Aspidiske", 139.27254, -59.27522, -19.0, 13.1, 4.3, 2.21),
+    star("Suhail", 136.99900, -43.43258, -23.2, 14.3, 5.7, 2.21),
+    star("Alphecca", 233.67196, 26.71469, 120.4, -89.6, 43.5, 2.23),
+    star("Mizar", 200.98142, 54.92536, 121.2, -22.0, 38.0, 2.23),
+    star("Sadr", 305.55708, 40.25667, 2.4, -0.9, 1.8, 2.23),
+    star("Eltanin", 269.15154, 51.48889, -8.5, -23.1, 21.1, 2.23),
+    star("Mintaka", 83.00167, -0.29908, 0.6, -0.7, 3.6, 2.23),
+    star("Schedar", 10.12683, 56.53733, 50.9, -32.1, 14.3, 2.24),
+    star("Naos", 120.89604, -40.00314, -30.8, 16.7, 3.0, 2.25),
+    star("Caph", 2.29454, 59.14978, 523.5, -180.4, 59.9, 2.27),
+    star("Dschubba", 240.08337, -22.62169, -10.2, -35.4, 6.6, 2.29),
+    star("Larawag", 252.54087, -34.29322, -611.8, -255.9, 50.4, 2.29),
+    star("Epsilon Centauri", 204.97192, -53.46639, -14.6, -12.8, 8.7, 2.3),
+    star("Alpha Lupi", 220.48233, -47.38819, -21.2, -24.0, 7.0, 2.3),
+    star("Eta Centauri", 218.87675, -42.15783, -34.4, -32.4, 10.7, 2.33),
+    star("Merak", 165.46033, 56.38242, 81.4, 33.5, 41.1, 2.37),
+    star("Izar", 221.24675, 27.07422, -50.9, 21.1, 15.5, 2.37),
+    star("Enif", 326.04650, 9.87500, 26.9, 0.4, 4.9, 2.39),
+    star("Girtab", 265.62200, -39.02997, -6.5, -25.6, 6.8, 2.39),
+    star("Ankaa", 6.57104, -42.30600, 233.1, -356.3, 38.5, 2.4),
+    star("Scheat", 345.94358, 28.08278, 187.8, 137.6, 16.6, 2.42),
+    star("Sabik", 257.59454, -15.72492, 41.2, 97.7, 36.9, 2.43),
+    star("Phecda", 178.45771, 53.69475, 107.7, 11.0, 39.2, 2.44),
+    star("Alderamin", 319.64488, 62.58558, 150.6, 49.1, 66.5, 2.45),
+    star("Aludra", 111.02375, -29.30311, -3.8, 6.7, 1.0, 2.45),
+    star("Markeb", 140.52842, -55.01067, -10.7, 11.2, 6.0, 2.47),
+    star("Navi", 14.17721, 60.71675, 25.7, -3.8, 5.9, 2.47),
+    star("Aljanah", 311.55283, 33.97025, 356.2, 330.3, 44.9, 2.48),
+    star("Markab", 346.19021, 15.20528, 60.4, -41.3, 24.5, 2.49),
+    star("Delta Centauri", 182.08958, -50.72242, -47.5, -6.1, 7.9, 2.52),
+    star("Menkar", 45.56987, 4.08975, -10.4, -76.9, 13.1, 2.54),
+    star("Zeta Centauri", 208.88496, -47.28839, -57.1, -44.8, 8.5, 2.55),
+    star("Han", 249.28975, -10.56708, 13.1, 25.4, 8.9, 2.56),
+    star("Zosma", 168.52708, 20.52372, 143.4, -130.4, 56.5, 2.56),
+    star("Arneb", 83.18258, -17.82228, 3.6, 1.2, 2.5, 2.58),
+    star("Gienah", 183.95154, -17.54192, -158.6, 21.9, 21.2, 2.59),
+    star("Ascella", 285.65304, -29.88011, 14.0, 1.7, 37.4, 2.6),
+    star("Zubeneschamali", 229.25171, -9.38292, -96.4, -20.8, 17.6, 2.61),
+    star("Acrab", 241.35929, -19.80544, -5.2, -24.0, 8.1, 2.62),
+    star("Unukalhai", 236.06696, 6.42564, 134.7, 44.1, 44.1, 2.63),
+    star("Sheratan", 28.66004, 20.80803, 96.3, -108.8, 55.6, 2.64),
+    star("Phact", 84.91225, -34.07411, 0.6, -24.1, 12.2, 2.65),
+    star("Kraz", 188.59679, -23.39675, 1.1, -56.6, 23.3, 2.65),
+    star("Ruchbah", 21.45396, 60.23528, 297.2, -49.5, 32.8, 2.68),
+    star("Muphrid", 208.67117, 18.39772, -60.9, -356.3, 88.2, 2.68),
+    star("Hassaleh", 74.24842, 33.16608, 3.6, -18.5, 6.4, 2.69),
+    star("Alpha Muscae", 189.29592, -69.13556, -40.2, -12.8, 10.4, 2.69),
+    star("Lesath", 262.69100, -37.29581, -1.4, -29.9, 4.6, 2.7),
+    star("Kaus Media", 275.24850, -29.82811, 32.6, -25.6, 10.7, 2.7),
+    star("Pi Puppis", 109.28567, -37.09747, -10.6, 7.0, 3.4, 2.7),
+    star("Tarazed", 296.56492, 10.61325, 15.7, -3.1, 7.1, 2.72),
+    star("Yed Prior", 243.58642, -3.69433, -45.8, -142.7, 19.1, 2.74),
+    star("Porrima", 190.41517, -1.44936, -615.5, 61.0, 85.6, 2.74),
+    star("Zubenelgenubi", 222.71963, -16.04178, -105.7, -68.4, 43.0, 2.75),
+    star("Cebalrai", 265.86812, 4.56731, -40.7, 158.8, 39.9, 2.77),
+    star("Kornephoros", 247.55500, 21.48961, -98.4, -14.5, 23.4, 2.78),
+    star("Cursa", 76.96246, -5.08644, -83.4, -75.4, 36.7, 2.78),
+    star("Vindemiatrix", 195.54417, 10.95914, -275.1, 20.0, 29.8, 2.79),
+    star("Rastaban", 262.60817, 52.30139, -15.6, 11.6, 9.0, 2.79),
+    star("Beta Hydri", 6.43779, -77.25425, 2220.1, 324.4, 134.1, 2.8),
+    star("Kaus Borealis", 276.99267, -25.42169, -44.8, -185.7, 41.7, 2.81),
+    star("Zeta Herculis", 250.32150, 31.60272, -462.6, 345.1, 92.6, 2.81),
+    star("Paikauhale", 248.97063, -28.21603, -9.9, -22.8, 6.9, 2.82),
+    star("Algenib", 3.30896, 15.18358, 4.7, -8.2, 9.8, 2.83),
+    star("Tureis", 121.88604, -24.30433, -83.4, 46.2, 51.3, 2.83),
+    star("Beta Trianguli Australis", 238.78567, -63.43072, -188.5, -401.9, 81.2, 2.83),
+    star("Nihal", 82.06133, -20.75944, -5.0, -85.9, 20.5, 2.84),
+    star("Alpha Arae", 262.96037, -49.87614, -31.3, -67.2, 12.2, 2.84),
+    star("Deneb Algedi", 326.76017, -16.12728, 263.3, -296.2, 84.6, 2.85),
+    star("Beta Arae", 261.32496, -55.52989, -8.3, -24.6, 5.0, 2.85),
+    star("Atik", 58.53300, 31.88364, 5.8, -9.9, 4.3, 2.85),
+    star("Alpha Hydri", 29.69246, -61.56986, 262.5, 27.5, 45.7, 2.86),
+    star("Alpha Tucanae", 334.62538, -60.25958, -71.5, -38.2, 16.4, 2.86),
+    star("Alcyone", 56.87117, 24.10514, 19.3, -43.7, 8.1, 2.87),
+    star("Sadalsuud", 322.88971, -5.57117, 18.8, -8.2, 6.0, 2.87),
+    star("Tejat", 95.74012, 22.51358, 56.8, -110.4, 14.1, 2.87),
+    star("Acamar", 44.56533, -40.30472, -52.9, 21.9, 20.2, 2.88),
+    star("Gomeisa", 111.78767, 8.28931, -50.3, -38.5, 19.2, 2.89),
+    star("Fang", 239.71296, -26.11411, -11.6, -26.3, 5.6, 2.89),
+    star("Epsilon Persei", 59.46346, 40.01022, 13.5, -23.9, 5.1, 2.89),
+    star("Cor Caroli", 194.00696, 38.31839, -235.1, 53.5, 28.6, 2.9),
+    star("Alniyat", 245.29717, -25.59281, -10.6, -16.2, 4.7, 2.9),
+    star("Sadalmelik", 331.44600, -0.31986, 18.3, -9.4, 6.2, 2.94),
+    star("Matar", 340.75058, 30.22139, 13.2, -25.7, 15.2, 2.94),
+    star("Algorab", 187.46608, -16.51544, -210.0, -139.3, 37.6, 2.95),
+    star("Zaurak", 59.50738, -13.50853, 61.6, -111.8, 16.0, 2.97),
+    star("Alnasl", 271.45204, -30.42408, -55.6, -181.5, 33.8, 2.98),
+    star("Mebsuta", 100.98304, 25.13111, -6.1, -13.1, 3.6, 2.98),
+    star("Algenubi", 146.46279, 23.77425, -46.1, -9.6, 13.0, 2.98),
+    star("Almaaz", 75.49221, 43.82331, 0.2, -2.3, 1.5, 2.99),
+    star("Okab", 286.35254, 13.86347, -7.3, -95.6, 39.3, 2.99),
+    star("Minkar", 182.53117, -22.61978, -71.5, 10.6, 10.8, 3.0),
+    star("Tianguan", 84.41121, 21.14256, 2.4, -18.0, 7.3, 3.0),
+    star("Beta Trianguli", 32.38596, 34.98731, 148.7, -39.6, 25.9, 3.0),
+    star("Furud", 95.07829, -30.06336, 7.3, 1.6, 9.0, 3.02),
+    star("Seginus", 218.01946, 38.30825, -115.7, 151.9, 37.6, 3.04),
+    star("Albireo", 292.68033, 27.95967, -7.1, -6.2, 7.5, 3.05),
+    star("Pherkad", 230.18217, 71.83403, -17.7, 17.9, 6.7, 3.05),
+    star("Rasalgethi", 258.66192, 14.39033, -7.3, 36.1, 9.1, 3.06),
+    star("Dabih", 305.25279, -14.78139, 38.4, 6.6, 9.5, 3.08),
+    star("Sarin", 258.75796, 24.83919, -21.0, -157.0, 43.4, 3.12),
+    star("Aldhibah", 257.19667, 65.71469, -21.1, 19.0, 9.9, 3.17),
+    star("Errai", 354.83687, 77.63228, -65.8, 156.3, 72.5, 3.21),
+    star("Alfirk", 322.16500, 70.56072, 12.6, 8.7, 4.8, 3.23),
+    star("Yed Posterior", 244.58038, -4.69250, 82.6, 40.9, 30.6, 3.23),
+    star("Sulafat", 284.73592, 32.68956, -2.8, 1.1, 5.3, 3.25),
+    star("Edasich", 231.23237, 58.96606, -8.4, 17.0, 32.2, 3.29),
+    star("Megrez", 183.85650, 57.03261, 103.6, 7.8, 40.1, 3.31),
+    star("Chertan", 168.56000, 15.42958, -60.3, -79.1, 19.6, 3.33),
+    star("Segin", 28.59887, 63.67011, 31.2, -18.0, 7.9, 3.37),
+    star("Heze", 203.67329, -0.59581, -278.9, 48.6, 44.0, 3.37),
+    star("Auva", 193.90087, 3.39747, -471.4, -52.4, 16.4, 3.38),
+    star("Homam", 340.36550, 10.83144, 78.9, -11.3, 15.6, 3.4),
+    star("Mothallah", 28.27046, 29.57883, 11.1, -234.0, 51.5, 3.41),
+    star("Adhafera", 154.17258, 23.41731, 19.8, -6.8, 12.6, 3.43),
+    star("Nekkar", 225.48650, 40.39056, -40.2, -28.6, 14.5, 3.49),
+    star("Tarf", 124.12883, 9.18556, -46.8, -48.7, 11.2, 3.52),
+    star("Sheliak", 282.52000, 33.36267, 1.1, -4.5, 3.4, 3.52),
+    star("Wasat", 110.03075, 21.98233, -18.7, -7.3, 54.5, 3.53),
+    star("Biham", 332.54996, 6.19786, 262.5, 35.7, 35.3, 3.53),
+    star("Ain", 67.15413, 19.18044, 107.2, -36.8, 22.2, 3.53),
+    star("Algedi", 304.51358, -12.54486, 61.2, 2.5, 30.0, 3.57),
+    star("Zavijava", 177.67383, 1.76472, 740.2, -270.4, 91.5, 3.61),
+    star("Thuban", 211.09729, 64.37586, -56.5, 17.2, 10.6, 3.65),
+    star("Alshain", 298.82829, 6.40675, 46.4, -481.4, 73.0, 3.71),
+    star("Ran", 53.23267, -9.45825, -976.4, 18.0, 310.9, 3.73),
+    star("Zaniah", 184.97650, -0.66681, -61.5, -27.7, 12.4, 3.89),
+    star("Menkib", 59.74125, 35.79103, 4.3, 2.9, 2.6, 4.04),
+    star("Alkes", 164.94358, -18.29878, -462.6, 129.1, 19.9, 4.08),
+    star("Acubens", 134.62175, 11.85769, 41.4, -29.6, 18.8, 4.25),
+    star("Polaris Australis", 317.19525, -88.95650, 25.7, 5.0, 11.0, 5.45),
+];
+
+/// Finds a star in [`BRIGHT_STARS`] by name, ignoring case
+pub fn lookup(name: &str) -> Option<&'static Star> {
+    BRIGHT_STARS
+        .iter()
+        .find(|s| s.name.eq_ignore_ascii_case(name))
+}
+
+/// The kind of a deep-sky object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeepSkyKind {
+    /// A dense, spherical cluster of old stars
+    GlobularCluster,
+    /// A loose cluster of young stars
+    OpenCluster,
+    /// An emission or reflection nebula
+    Nebula,
+    /// The shell of gas thrown off by a dying star
+    PlanetaryNebula,
+    /// The remains of a supernova
+    SupernovaRemnant,
+    /// A galaxy
+    Galaxy,
+    /// A double star
+    DoubleStar,
+    /// A chance grouping of stars
+    Asterism,
+    /// A dense part of the milky way
+    StarCloud,
+}
+use DeepSkyKind::*;
+
+/// A deep-sky object, such as a galaxy or cluster
+///
+/// The location is the mean position of date, precessed from J2000.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeepSky {
+    /// Catalog designation, such as "M31" or "NGC 869"
+    pub name: &'static str,
+    /// Common name, such as "Andromeda Galaxy"
+    pub common_name: Option<&'static str>,
+    /// The kind of object
+    pub kind: DeepSkyKind,
+    /// Position at J2000
+    pub coord: Coord,
+    /// Visual magnitude
+    pub vmag: f64,
+    /// Major and minor axes of the apparent size
+    pub size: (Angle, Angle),
+}
+
+/// A deep-sky object from its J2000 position in degrees, and size in arcminutes
+#[allow(clippy::too_many_arguments)]
+const fn dso(
+    name: &'static str,
+    common_name: Option<&'static str>,
+    kind: DeepSkyKind,
+    ra: f64,
+    de: f64,
+    vmag: f64,
+    major: f64,
+    minor: f64,
+) -> DeepSky {
+    DeepSky {
+        name,
+        common_name,
+        kind,
+        coord: Coord::from_equatorial(Angle::from_degrees(ra), Angle::from_degrees(de)),
+        vmag,
+        size: (
+            Angle::from_degrees(major / 60.0),
+            Angle::from_degrees(minor / 60.0),
+        ),
+    }
+}
+
+/// Deep-sky objects are infinitely far away, so [`CelObj::locationcart()`] is a unit vector
+impl CelObj for DeepSky {
+    fn locationcart(&self, d: Date) -> Vector3 {
+        self.coord.precess_rigorous(J2000, d).cartesian(1.0)
+    }
+    fn distance(&self, _: Date) -> Distance {
+        Distance::from_au(f64::INFINITY)
+    }
+    fn magnitude(&self, _: Date) -> f64 {
+        self.vmag
+    }
+    fn angdia(&self, _: Date) -> Angle {
+        self.size.0
+    }
+    fn name(&self) -> &str {
+        self.name
+    }
+}
+
+/// The Messier catalog, in order
+///
+/// Sizes and magnitudes are rounded, and for nebulae the size is of the brightest part.
+#[rustfmt::skip]
+pub static MESSIER: [DeepSky; 110] = [
+    dso("M1", Some("Crab Nebula"), SupernovaRemnant, 83.6250, 22.0167, 8.4, 6.0, 4.0),
+    dso("M2", None, GlobularCluster, 323.3750, -0.8167, 6.5, 16.0, 16.0),
+    dso("M3", None, GlobularCluster, 205.5500, 28.3833, 6.2, 18.0, 18.0),
+    dso("M4", None, GlobularCluster, 245.9000, -26.5333, 5.6, 36.0, 36.0),
+    dso("M5", None, GlobularCluster, 229.6500, 2.0833, 5.6, 23.0, 23.0),
+    dso("M6", Some("Butterfly Cluster"), OpenCluster, 265.0250, -32.2167, 4.2, 25.0, 25.0),
+    dso("M7", Some("Ptolemy Cluster"), OpenCluster, 268.4750, -34.8167, 3.3, 80.0, 80.0),
+    dso("M8", Some("Lagoon Nebula"), Nebula, 270.9500, -24.3833, 6.0, 90.0, 40.0),
+    dso("M9", None, GlobularCluster, 259.8000, -18.5167, 7.7, 12.0, 12.0),
+    dso("M10", None, GlobularCluster, 254.2750, -4.1000, 6.6, 20.0, 20.0),
+    dso("M11", Some("Wild Duck Cluster"), OpenCluster, 282.7750, -6.2667, 6.3, 14.0, 14.0),
+    dso("M12", None, GlobularCluster, 251.8000, -1.9500, 6.7, 16.0, 16.0),
+    dso("M13", Some("Hercules Cluster"), GlobularCluster, 250.4250, 36.4667, 5.8, 20.0, 20.0),
+    dso("M14", None, GlobularCluster, 264.4000, -3.2500, 7.6, 11.0, 11.0),
+    dso("M15", None, GlobularCluster, 322.5000, 12.1667, 6.2, 18.0, 18.0),
+    dso("M16", Some("Eagle Nebula"), OpenCluster, 274.7000, -13.7833, 6.0, 7.0, 7.0),
+    dso("M17", Some("Omega Nebula"), Nebula, 275.2000, -16.1833, 6.0, 11.0, 11.0),
+    dso("M18", None, OpenCluster, 274.9750, -17.1333, 7.5, 9.0, 9.0),
+    dso("M19", None, GlobularCluster, 255.6500, -26.2667, 6.8, 17.0, 17.0),
+    dso("M20", Some("Trifid Nebula"), Nebula, 270.6500, -23.0333, 6.3, 28.0, 28.0),
+    dso("M21", None, OpenCluster, 271.1500, -22.5000, 6.5, 13.0, 13.0),
+    dso("M22", None, GlobularCluster, 279.1000, -23.9000, 5.1, 32.0, 32.0),
+    dso("M23", None, OpenCluster, 269.2000, -19.0167, 6.9, 27.0, 27.0),
+    dso("M24", Some("Sagittarius Star Cloud"), StarCloud, 274.2250, -18.4833, 4.6, 90.0, 90.0),
+    dso("M25", None, OpenCluster, 277.9000, -19.2500, 4.6, 32.0, 32.0),
+    dso("M26", None, OpenCluster, 281.3000, -9.4000, 8.0, 15.0, 15.0),
+    dso("M27", Some("Dumbbell Nebula"), PlanetaryNebula, 299.9000, 22.7167, 7.5, 8.0, 6.0),
+    dso("M28", None, GlobularCluster, 276.1250, -24.8667, 6.8, 11.0, 11.0),
+    dso("M29", None, OpenCluster, 305.9750, 38.5333, 7.1, 7.0, 7.0),
+    dso("M30", None, GlobularCluster, 325.1000, -23.1833, 7.2, 12.0, 12.0),
+    dso("M31", Some("Andromeda Galaxy"), Galaxy, 10.6750, 41.2667, 3.4, 178.0, 63.0),
+    dso("M32", None, Galaxy, 10.6750, 40.8667, 8.1, 8.0, 6.0),
+    dso("M33", Some("Triangulum Galaxy"), Galaxy, 23.4750, 30.6500, 5.7, 73.0, 45.0),
+    dso("M34", None, OpenCluster, 40.5000, 42.7833, 5.5, 35.0, 35.0),
+    dso("M35", None, OpenCluster, 92.2250, 24.3333, 5.3, 28.0, 28.0),
+    dso("M36", None, OpenCluster, 84.0250, 34.1333, 6.3, 12.0, 12.0),
+    dso("M37", None, OpenCluster, 88.1000, 32.5500, 6.2, 24.0, 24.0),
+    dso("M38", None, OpenCluster, 82.1750, 35.8333, 7.4, 21.0, 21.0),
+    dso("M39", None, OpenCluster, 323.0500, 48.4333, 4.6, 32.0, 32.0),
+    dso("M40", Some("Winnecke 4"), DoubleStar, 185.6000, 58.0833, 8.4, 0.8, 0.8),
+    dso("M41", None, OpenCluster, 101.5000, -20.7333, 4.5, 38.0, 38.0),
+    dso("M42", Some("Orion Nebula"), Nebula, 83.8500, -5.4500, 4.0, 85.0, 60.0),
+    dso("M43", Some("De Mairan's Nebula"), Nebula, 83.9000, -5.2667, 9.0, 20.0, 15.0),
+    dso("M44", Some("Beehive Cluster"), OpenCluster, 130.0250, 19.9833, 3.7, 95.0, 95.0),
+    dso("M45", Some("Pleiades"), OpenCluster, 56.7500, 24.1167, 1.6, 110.0, 110.0),
+    dso("M46", None, OpenCluster, 115.4500, -14.8167, 6.1, 27.0, 27.0),
+    dso("M47", None, OpenCluster, 114.1500, -14.5000, 4.4, 30.0, 30.0),
+    dso("M48", None, OpenCluster, 123.4500, -5.8000, 5.8, 54.0, 54.0),
+    dso("M49", None, Galaxy, 187.4500, 8.0000, 8.4, 9.0, 7.5),
+    dso("M50", None, OpenCluster, 105.8000, -8.3333, 5.9, 16.0, 16.0),
+    dso("M51", Some("Whirlpool Galaxy"), Galaxy, 202.4750, 47.2000, 8.4, 11.0, 7.0),
+    dso("M52", None, OpenCluster, 351.0500, 61.5833, 7.3, 13.0, 13.0),
+    dso("M53", None, GlobularCluster, 198.2250, 18.1667, 7.6, 13.0, 13.0),
+    dso("M54", None, GlobularCluster, 283.7750, -30.4833, 7.6, 12.0, 12.0),
+    dso("M55", None, GlobularCluster, 295.0000, -30.9667, 6.3, 19.0, 19.0),
+    dso("M56", None, GlobularCluster, 289.1500, 30.1833, 8.3, 8.8, 8.8),
+    dso("M57", Some("Ring Nebula"), PlanetaryNebula, 283.4000, 33.0333, 8.8, 1.4, 1.0),
+    dso("M58", None, Galaxy, 189.4250, 11.8167, 9.7, 6.0, 5.0),
+    dso("M59", None, Galaxy, 190.5000, 11.6500, 9.6, 5.0, 3.5),
+    dso("M60", None, Galaxy, 190.9250, 11.5500, 8.8, 7.0, 6.0),
+    dso("M61", None, Galaxy, 185.4750, 4.4667, 9.7, 6.0, 5.5),
+    dso("M62", None, GlobularCluster, 255.3000, -30.1167, 6.5, 15.0, 15.0),
+    dso("M63", Some("Sunflower Galaxy"), Galaxy, 198.9500, 42.0333, 8.6, 12.0, 7.5),
+    dso("M64", Some("Black Eye Galaxy"), Galaxy, 194.1750, 21.6833, 8.5, 10.0, 5.0),
+    dso("M65", None, Galaxy, 169.7250, 13.0833, 9.3, 8.0, 1.5),
+    dso("M66", None, Galaxy, 170.0500, 12.9833, 8.9, 9.0, 4.0),
+    dso("M67", None, OpenCluster, 132.8250, 11.8167, 6.1, 30.0, 30.0),
+    dso("M68", None, GlobularCluster, 189.8750, -26.7500, 7.8, 11.0, 11.0),
+    dso("M69", None, GlobularCluster, 277.8500, -32.3500, 7.6, 7.0, 7.0),
+    dso("M70", None, GlobularCluster, 280.8000, -32.3000, 7.9, 8.0, 8.0),
+    dso("M71", None, GlobularCluster, 298.4500, 18.7833, 8.2, 7.0, 7.0),
+    dso("M72", None, GlobularCluster, 313.3750, -12.5333, 9.3, 6.0, 6.0),
+    dso("M73", None, Asterism, 314.7250, -12.6333, 9.0, 2.8, 2.8),
+    dso("M74", None, Galaxy, 24.1750, 15.7833, 9.4, 10.0, 9.0),
+    dso("M75", None, GlobularCluster, 301.5250, -21.9167, 8.5, 6.8, 6.8),
+    dso("M76", Some("Little Dumbbell Nebula"), PlanetaryNebula, 25.6000, 51.5667, 10.1, 2.7, 1.8),
+    dso("M77", None, Galaxy, 40.6750, -0.0167, 8.9, 7.0, 6.0),
+    dso("M78", None, Nebula, 86.6750, 0.0500, 8.3, 8.0, 6.0),
+    dso("M79", None, GlobularCluster, 81.1250, -24.5500, 7.7, 9.6, 9.6),
+    dso("M80", None, GlobularCluster, 244.2500, -22.9833, 7.3, 10.0, 10.0),
+    dso("M81", Some("Bode's Galaxy"), Galaxy, 148.9000, 69.0667, 6.9, 27.0, 14.0),
+    dso("M82", Some("Cigar Galaxy"), Galaxy, 148.9500, 69.6833, 8.4, 11.0, 4.6),
+    dso("M83", Some("Southern Pinwheel Galaxy"), Galaxy, 204.2500, -29.8667, 7.6, 13.0, 12.0),
+    dso("M84", None, Galaxy, 186.2750, 12.8833, 9.1, 6.5, 5.5),
+    dso("M85", None, Galaxy, 186.3500, 18.1833, 9.1, 7.0, 5.0),
+    dso("M86", None, Galaxy, 186.5500, 12.9500, 8.9, 9.0, 6.0),
+    dso("M87", Some("Virgo A"), Galaxy, 187.7000, 12.3833, 8.6, 8.0, 6.0),
+    dso("M88", None, Galaxy, 188.0000, 14.4167, 9.6, 7.0, 4.0),
+    dso("M89", None, Galaxy, 188.9250, 12.5500, 9.8, 5.0, 5.0),
+    dso("M90", None, Galaxy, 189.2000, 13.1667, 9.5, 10.0, 4.5),
+    dso("M91", None, Galaxy, 188.8500, 14.5000, 10.2, 5.4, 4.4),
+    dso("M92", None, GlobularCluster, 259.2750, 43.1333, 6.4, 14.0, 14.0),
+    dso("M93", None, OpenCluster, 116.1500, -23.8667, 6.0, 22.0, 22.0),
+    dso("M94", None, Galaxy, 192.7250, 41.1167, 8.2, 11.0, 9.0),
+    dso("M95", None, Galaxy, 161.0000, 11.7000, 9.7, 7.0, 5.0),
+    dso("M96", None, Galaxy, 161.7000, 11.8167, 9.2, 7.0, 5.0),
+    dso("M97", Some("Owl Nebula"), PlanetaryNebula, 168.7000, 55.0167, 9.9, 3.4, 3.3),
+    dso("M98", None, Galaxy, 183.4500, 14.9000, 10.1, 9.5, 3.0),
+    dso("M99", None, Galaxy, 184.7000, 14.4167, 9.9, 5.4, 4.8),
+    dso("M100", None, Galaxy, 185.7250, 15.8167, 9.3, 7.0, 6.0),
+    dso("M101", Some("Pinwheel Galaxy"), Galaxy, 210.8000, 54.3500, 7.9, 29.0, 27.0),
+    dso("M102", Some("Spindle Galaxy"), Galaxy, 226.6250, 55.7667, 9.9, 6.5, 3.0),
+    dso("M103", None, OpenCluster, 23.3000, 60.7000, 7.4, 6.0, 6.0),
+    dso("M104", Some("Sombrero Galaxy"), Galaxy, 190.0000, -11.6167, 8.0, 9.0, 4.0),
+    dso("M105", None, Galaxy, 161.9500, 12.5833, 9.3, 5.4, 4.8),
+    dso("M106", None, Galaxy, 184.7500, 47.3000, 8.4, 19.0, 8.0),
+    dso("M107", None, GlobularCluster, 248.1250, -13.0500, 7.9, 13.0, 13.0),
+    dso("M108", None, Galaxy, 167.8750, 55.6667, 10.0, 8.0, 1.0),
+    dso("M109", None, Galaxy, 179.4000, 53.3833, 9.8, 7.0, 4.0),
+    dso("M110", None, Galaxy, 10.1000, 41.6833, 8.5, 17.0, 10.0),
+];
+
+/// Bright and well known objects from the New General Catalog which aren't in [`MESSIER`]
+#[rustfmt::skip]
+pub static NGC: [DeepSky; 16] = [
+    dso("NGC 104", Some("47 Tucanae"), GlobularCluster, 6.0250, -72.0833, 4.1, 31.0, 31.0),
+    dso("NGC 253", Some("Sculptor Galaxy"), Galaxy, 11.9000, -25.2833, 7.1, 27.0, 7.0),
+    dso("NGC 457", Some("Owl Cluster"), OpenCluster, 19.7750, 58.3333, 6.4, 13.0, 13.0),
+    dso("NGC 869", Some("h Persei"), OpenCluster, 34.7500, 57.1333, 5.3, 30.0, 30.0),
+    dso("NGC 884", Some("Chi Persei"), OpenCluster, 35.6000, 57.1167, 6.1, 30.0, 30.0),
+    dso("NGC 2070", Some("Tarantula Nebula"), Nebula, 84.6750, -69.1000, 8.0, 40.0, 25.0),
+    dso("NGC 2392", Some("Eskimo Nebula"), PlanetaryNebula, 112.3000, 20.9167, 9.2, 0.8, 0.8),
+    dso("NGC 3372", Some("Carina Nebula"), Nebula, 161.2750, -59.8667, 1.0, 120.0, 120.0),
+    dso("NGC 4755", Some("Jewel Box"), OpenCluster, 193.4000, -60.3667, 4.2, 10.0, 10.0),
+    dso("NGC 5128", Some("Centaurus A"), Galaxy, 201.3750, -43.0167, 6.8, 25.0, 20.0),
+    dso("NGC 5139", Some("Omega Centauri"), GlobularCluster, 201.7000, -47.4833, 3.9, 36.0, 36.0),
+    dso("NGC 6543", Some("Cat's Eye Nebula"), PlanetaryNebula, 269.6500, 66.6333, 8.1, 0.4, 0.4),
+    dso("NGC 6960", Some("Western Veil Nebula"), SupernovaRemnant, 311.4250, 30.7167, 7.0, 70.0, 6.0),
+    dso("NGC 7000", Some("North America Nebula"), Nebula, 314.8250, 44.5167, 4.0, 120.0, 100.0),
+    dso("NGC 7009", Some("Saturn Nebula"), PlanetaryNebula, 316.0500, -11.3667, 8.0, 0.5, 0.5),
+    dso("NGC 7293", Some("Helix Nebula"), PlanetaryNebula, 337.4000, -20.8333, 7.3, 16.0, 16.0),
+];
+
+/// Finds an object in [`MESSIER`] or [`NGC`] by designation or common name, ignoring case and spaces
+///
+/// "M 31", "m31", and "andromeda galaxy" all find the same object.
+pub fn lookup_deep_sky(name: &str) -> Option<&'static DeepSky> {
+    let matches = |s: &str| {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_lowercase())
+            .eq(name
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .map(|c| c.to_ascii_lowercase()))
+    };
+    MESSIER
+        .iter()
+        .chain(NGC.iter())
+        .find(|o| matches(o.name) || o.common_name.is_some_and(matches))
+}
+
+/// A line of a CSV star list that couldn't be read, from [`from_csv()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CsvError {
+    /// The line number, starting from 1
+    pub line: usize,
+    /// The kind of error if the line couldn't be read at all, such as [`std::io::ErrorKind::InvalidData`] for text
+    /// that isn't UTF-8, or `None` if it was read but isn't a star
+    pub io: Option<std::io::ErrorKind>,
+}
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.io {
+            Some(kind) => write!(f, "couldn't read line {} of the catalog: {kind}", self.line),
+            None => write!(f, "invalid catalog entry on line {}", self.line),
+        }
+    }
+}
+impl std::error::Error for CsvError {}
+impl From<CsvError> for crate::Error {
+    fn from(e: CsvError) -> Self {
+        crate::Error::Catalog {
+            line: e.line,
+            io: e.io,
+        }
+    }
+}
+
+/**
+Reads a list of stars from comma separated values
+
+Each line has the columns `name,ra,dec,pm_ra,pm_dec,mag,epoch`, with an optional last column `parallax`:
+- `ra` and `dec` - The ICRS position at the epoch, in degrees
+- `pm_ra` and `pm_dec` - The proper motion in milliarcseconds per year, `pm_ra` as μα·cos(δ)
+- `mag` - The visual magnitude
+- `epoch` - The Julian epoch of the position, such as `2000.0`, or `2016.0` for Gaia DR3
+- `parallax` - The parallax in milliarcseconds
+
+Blank lines, lines starting with `#`, and a header line starting with `name,` before the first star are skipped.
+Names can't contain commas, and empty proper motions and parallaxes are taken as zero.
+```
+# use pracstro::catalog;
+let csv = "name,ra,dec,pm_ra,pm_dec,mag,epoch,parallax
+Barnard's Star,269.44850,4.73942,-801.551,10362.394,9.51,2016.0,546.976
+";
+let stars = catalog::from_csv(csv.as_bytes()).unwrap();
+assert_eq!(stars[0].name, "Barnard's Star");
+```
+*/
+pub fn from_csv(reader: impl std::io::Read) -> Result<Vec<Star>, CsvError> {
+    use std::io::BufRead;
+    let mut stars = Vec::new();
+    for (i, line) in std::io::BufReader::new(reader).lines().enumerate() {
+        let line = line.map_err(|e| CsvError {
+            line: i + 1,
+            io: Some(e.kind()),
+        })?;
+        let line = line.trim();
+        if line.is_empty()
+            || line.starts_with('#')
+            || (stars.is_empty() && line.starts_with("name,"))
+        {
+            continue;
+        }
+        stars.push(csv_star(line).ok_or(CsvError {
+            line: i + 1,
+            io: None,
+        })?);
+    }
+    Ok(stars)
+}
+
+/// A star from one line of [`from_csv()`]
+fn csv_star(line: &str) -> Option<Star> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if !(7..=8).contains(&fields.len()) || fields[0].is_empty() {
+        return None;
+    }
+    let num = |i: usize, default: Option<f64>| match fields.get(i) {
+        Some(f) if !f.is_empty() => f.parse::<f64>().ok().filter(|x| x.is_finite()),
+        _ => default,
+    };
+    let (ra, de) = (num(1, None)?, num(2, None)?);
+    if de.abs() > 90.0 {
+        return None;
+    }
+    Some(Star {
+        name: Cow::Owned(fields[0].to_string()),
+        coord_icrs: Coord::from_equatorial(Angle::from_degrees(ra), Angle::from_degrees(de)),
+        pm_ra: AngularRate::from_arcsec_per_year(num(3, Some(0.0))? / 1000.0),
+        pm_dec: AngularRate::from_arcsec_per_year(num(4, Some(0.0))? / 1000.0),
+        vmag: num(5, None)?,
+        epoch: Date::from_julian_epoch(num(6, None)?),
+        parallax: Angle::from_degrees(num(7, Some(0.0))? / 3_600_000.0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constellation::Constellation;
+    use crate::CelObj;
+
+    #[test]
+    fn test_bright_stars() {
+        let sirius = lookup("SIRIUS").unwrap();
+        assert_eq!(sirius.name, "Sirius");
+        assert_eq!(
+            sirius.coord_icrs.constellation(J2000),
+            Constellation::CanisMajor
+        );
+        assert!((sirius.distance(J2000).au() / 206264.8 - 2.64).abs() < 0.01);
+        assert!(lookup("Vulcan").is_none());
+        assert!(BRIGHT_STARS.windows(2).all(|w| w[0].vmag <= w[1].vmag));
+        let polaris = lookup("polaris").unwrap();
+        assert_eq!(
+            polaris.coord_icrs.constellation(J2000),
+            Constellation::UrsaMinor
+        );
+        // Polaris is closest to the pole around 2100
+        let d = crate::time::Date::from_calendar(2100, 3, 24, Angle::default());
+        assert!(polaris.location(d).declination().degrees() > 89.5);
+    }
+
+    #[test]
+    fn test_deep_sky() {
+        let m31 = lookup_deep_sky("m 31").unwrap();
+        assert_eq!(lookup_deep_sky("Andromeda galaxy"), Some(m31));
+        assert_eq!(m31.kind, Galaxy);
+        assert_eq!(m31.coord.constellation(J2000), Constellation::Andromeda);
+        assert!(m31.angdia(J2000).degrees() > 2.0);
+        assert_eq!(
+            lookup_deep_sky("NGC5139").unwrap().common_name,
+            Some("Omega Centauri")
+        );
+        assert!(lookup_deep_sky("M111").is_none());
+        for (i, m) in MESSIER.iter().enumerate() {
+            assert_eq!(m.name, format!("M{}", i + 1));
+        }
+        // The Pleiades drift by precession, about 1.4° in a century
+        let d = crate::time::Date::from_calendar(2100, 1, 1, Angle::default());
+        let m45 = lookup_deep_sky("pleiades").unwrap();
+        assert!((m45.location(d).dist(m45.coord).degrees() - 1.4).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "# Two stars
+name,ra,dec,pm_ra,pm_dec,mag,epoch
+Sirius,101.28716,-16.71612,-546.01,-1223.07,-1.46,2000.0,379.21
+
+Vega, 279.23473, 38.78369, 200.94, 286.23, 0.03, 2000.0
+";
+        let stars = from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(stars.len(), 2);
+        assert_eq!(stars[1].name, "Vega");
+        assert_eq!(stars[1].parallax, Angle::default());
+        let sirius = lookup("Sirius").unwrap();
+        assert_approx_eq!(
+            stars[0].coord_icrs,
+            sirius.coord_icrs,
+            Angle::from_degminsec(0, 0, 1.0)
+        );
+        assert_approx_eq!(
+            stars[0].parallax,
+            sirius.parallax,
+            Angle::from_degminsec(0, 0, 0.01)
+        );
+
+        let bad = |line| CsvError { line, io: None };
+        assert_eq!(from_csv("Vega,279,38,0,0,0.03".as_bytes()), Err(bad(1)));
+        assert_eq!(
+            from_csv("Vega,279,38,0,0,0.03,2000\nBad,1,95,0,0,1,2000".as_bytes()),
+            Err(bad(2))
+        );
+        // Text that isn't UTF-8 can't be read, and the kind of error is kept
+        let unreadable = CsvError {
+            line: 2,
+            io: Some(std::io::ErrorKind::InvalidData),
+        };
+        assert_eq!(
+            from_csv(&b"Vega,279,38,0,0,0.03,2000\nB\xffd,1,5,0,0,1,2000"[..]),
+            Err(unreadable)
+        );
+        assert_eq!(
+            crate::Error::from(unreadable).to_string(),
+            unreadable.to_string()
+        );
+        assert_eq!(
+            crate::Error::from(bad(3)),
+            crate::Error::Catalog { line: 3, io: None }
+        );
+    }
+}
@@ -1,22 +1,364 @@
-//! Celestial object trait for generics
+/*! Celestial object trait for generics
 
-use crate::coord::Coord;
-use crate::time;
+[`CelObj`] is implemented for [`Sun`](crate::sol::Sun), [`Planet`](crate::sol::Planet), [`Moon`](crate::moon::Moon),
+[`MinorPlanet`](crate::asteroid::MinorPlanet), and [`Comet`](crate::comet::Comet), and with the `probe` feature,
+for `probe::SegmentedPlanet`, so code can be written once for any of them.
+
+# Origins
+[`CelObj::location()`] and [`CelObj::distance()`] are always as seen from the earth, but the origin and frame of
+[`CelObj::locationcart()`] depend on the object:
+
+| Object | Origin | Frame |
+|--------|--------|-------|
+| [`Planet`](crate::sol::Planet), [`MinorPlanet`](crate::asteroid::MinorPlanet), [`Comet`](crate::comet::Comet) | The sun | Equatorial, J2000 |
+| [`Sun`](crate::sol::Sun) | The earth | Equatorial, J2000 |
+| [`Moon`](crate::moon::Moon) | The earth | Equatorial, of date |
+| [`Star`](crate::star::Star) | The earth | Equatorial, of date |
+
+[`CelObj::location_of_date()`] is always of date, to compare with the sidereal time or the moon.
+
+For the barycenter of the solar system as the origin, add [`sol::ssb_offset()`] to a heliocentric position,
+or use `location_barycentric()`, such as [`Planet::location_barycentric()`](crate::sol::Planet::location_barycentric).
+
+Objects can be looked up by name with [`lookup()`], among [`bodies()`], which also contains any added with [`register()`].
+```
+let jupiter = pracstro::lookup("jupiter").unwrap();
+assert_eq!(jupiter.name(), "Jupiter");
+```
+*/
+
+use crate::coord::{self, Coord, Distance, NoRiseSet, Observer, RiseSet, Vector3};
+use crate::{moon, sol, time};
+use std::sync::RwLock;
 
 /// A celestial object in pracstro is defined by the ability to query its cartesian coordinates from time
 pub trait CelObj {
     /// The cartesian coordinates of the object
-    fn locationcart(&self, d: time::Date) -> (f64, f64, f64);
+    fn locationcart(&self, d: time::Date) -> Vector3;
+
+    /// The visual magnitude of the object
+    fn magnitude(&self, d: time::Date) -> f64;
+
+    /// The angular diameter of the object
+    fn angdia(&self, d: time::Date) -> time::Angle;
+
+    /// The name of the object, such as "Jupiter"
+    fn name(&self) -> &str;
+
+    /// The illuminated fraction of the object as seen from the earth, if it shines by reflected light
+    fn illumfrac(&self, _d: time::Date) -> Option<f64> {
+        None
+    }
 
     /// The 2D Polar Coordinates of the object
     fn location(&self, d: time::Date) -> Coord {
-        let (x, y, z) = self.locationcart(d);
-        Coord::from_cartesian(x, y, z)
+        self.locationcart(d).into()
     }
 
-    /// The distance from the reference frame to the object, in AU
-    fn distance(&self, d: time::Date) -> f64 {
-        let (x, y, z) = self.locationcart(d);
-        (x * x + y * y + z * z).sqrt()
+    /// The 2D Polar Coordinates of the object, referred to the equator and equinox of the date
+    ///
+    /// This is the frame of the sidereal time and of the moon. The default is [`CelObj::location()`], for objects
+    /// already of date, objects whose location is of J2000 precess it.
+    fn location_of_date(&self, d: time::Date) -> Coord {
+        self.location(d)
+    }
+
+    /// The distance from the reference frame to the object
+    fn distance(&self, d: time::Date) -> Distance {
+        Distance::from_au(self.locationcart(d).norm())
+    }
+
+    /// How fast the object is moving across the sky, in right ascension and declination of date
+    ///
+    /// Found from the positions half an hour on either side of the date, see [`coord::sky_motion()`]
+    fn sky_motion(&self, d: time::Date) -> (time::AngularRate, time::AngularRate) {
+        coord::sky_motion(|t| self.location_of_date(t), d)
+    }
+
+    /// Everything about the object as seen by an observer, corrected for refraction and parallax
+    /// ```
+    /// # use pracstro::{coord, moon, time, CelObj};
+    /// let obs = coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+    /// let now = time::Date::from_calendar(2025, 3, 10, time::Angle::from_clock(21, 0, 0.0));
+    /// let seen = moon::MOON.observe(&obs, now);
+    /// seen.altitude.to_latitude().degrees(); // How high the moon is
+    /// ```
+    fn observe(&self, obs: &Observer, d: time::Date) -> Observation {
+        self.observe_with(obs, d, ObserveOptions::default())
+    }
+
+    /// Everything about the object as seen by an observer, with the corrections chosen by `opts`
+    fn observe_with(&self, obs: &Observer, d: time::Date, opts: ObserveOptions) -> Observation {
+        let (geocentric, mut location) = (self.distance(d), self.location_of_date(d));
+        let mut distance = geocentric;
+        if opts.parallax {
+            let v = location.cartesian(geocentric.au())
+                - obs.celestial(d) * Distance::from_meters(1.0).au();
+            (location, distance) = (v.into(), Distance::from_au(v.norm()));
+        }
+        let (azimuth, mut altitude) = location.horizon_for(obs, d);
+        let angdia = self.angdia(d) * (geocentric.au() / distance.au());
+
+        // The altitude of the center of the object when its upper limb touches the horizon
+        let mut h0 = time::Angle::default() - angdia / 2.0;
+        if opts.refraction {
+            altitude += coord::refraction(altitude);
+            h0 += coord::H0_STAR;
+        }
+        if opts.parallax {
+            h0 += time::Angle::asin(coord::WGS84_A / geocentric.meters());
+        }
+
+        Observation {
+            location,
+            azimuth,
+            altitude,
+            distance,
+            magnitude: self.magnitude(d),
+            angdia,
+            illumfrac: self.illumfrac(d),
+            riseset: self.riseset_moving(d, obs, h0),
+        }
+    }
+
+    /// Rise, transit, and set of the object, following its motion through the day, see [`coord::riseset_moving()`]
+    fn riseset_moving(
+        &self,
+        date: time::Date,
+        obs: &Observer,
+        h0: time::Angle,
+    ) -> Result<RiseSet, NoRiseSet> {
+        coord::riseset_moving(|d| self.location_of_date(d), date, obs, h0)
+    }
+}
+
+/// Corrections made by [`CelObj::observe_with()`], all on by default
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ObserveOptions {
+    /// Raise the altitude by atmospheric refraction, see [`coord::refraction()`]
+    pub refraction: bool,
+    /// Move the position to the observer, rather than the center of the earth, which matters most for the moon
+    pub parallax: bool,
+}
+impl Default for ObserveOptions {
+    fn default() -> Self {
+        ObserveOptions {
+            refraction: true,
+            parallax: true,
+        }
+    }
+}
+
+/// An object as seen by an observer at a date, see [`CelObj::observe()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Observation {
+    /// Right ascension and declination, referred to the equator and equinox of the date
+    pub location: Coord,
+    /// Azimuth, from the north towards the east
+    pub azimuth: time::Angle,
+    /// Altitude above the horizon
+    pub altitude: time::Angle,
+    /// Distance from the observer
+    pub distance: Distance,
+    /// Visual magnitude
+    pub magnitude: f64,
+    /// Angular diameter
+    pub angdia: time::Angle,
+    /// Illuminated fraction, for objects that shine by reflected light
+    pub illumfrac: Option<f64>,
+    /// Rise, transit, and set of the upper limb on the day of the observation
+    pub riseset: Result<RiseSet, NoRiseSet>,
+}
+
+/// Objects added with [`register()`]
+static REGISTERED: RwLock<Vec<&'static (dyn CelObj + Sync)>> = RwLock::new(Vec::new());
+
+/// All known objects, the sun, moon, and planets other than the earth, then any added with [`register()`]
+pub fn bodies() -> Vec<&'static (dyn CelObj + Sync)> {
+    let mut objs: Vec<&'static (dyn CelObj + Sync)> = vec![&sol::SUN, &moon::MOON];
+    objs.extend(
+        sol::PLANETS
+            .into_iter()
+            .filter(|p| p.name != sol::EARTH.name)
+            .map(|p| p as &(dyn CelObj + Sync)),
+    );
+    objs.extend(REGISTERED.read().unwrap_or_else(|e| e.into_inner()).iter());
+    objs
+}
+
+/// Adds an object to [`bodies()`], so it can be found with [`lookup()`]
+///
+/// If the name is already taken, [`lookup()`] keeps finding the earlier object.
+pub fn register(obj: &'static (dyn CelObj + Sync)) {
+    REGISTERED
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(obj);
+}
+
+/// Finds an object in [`bodies()`] by name, ignoring case
+pub fn lookup(name: &str) -> Option<&'static (dyn CelObj + Sync)> {
+    bodies()
+        .into_iter()
+        .find(|o| o.name().eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestMoon;
+    impl CelObj for TestMoon {
+        fn locationcart(&self, d: time::Date) -> Vector3 {
+            moon::MOON.locationcart(d)
+        }
+        fn magnitude(&self, d: time::Date) -> f64 {
+            moon::MOON.magnitude(d)
+        }
+        fn angdia(&self, d: time::Date) -> time::Angle {
+            moon::MOON.angdia(d)
+        }
+        fn name(&self) -> &str {
+            "Test Moon"
+        }
+    }
+
+    #[test]
+    fn test_riseset_moving() {
+        let date = time::Date::from_calendar(2025, 3, 10, time::Angle::default());
+        let obs = Observer::new(time::Angle::from_degrees(40.0), time::Angle::default(), 0.0);
+        let rs = TestMoon.riseset_moving(date, &obs, coord::H0_STAR).unwrap();
+        let expect =
+            coord::riseset_moving(|d| moon::MOON.location(d), date, &obs, coord::H0_STAR).unwrap();
+        assert!((rs.rise.julian() - expect.rise.julian()).abs() < 1.0 / 86400.0);
+        assert!((rs.set.julian() - expect.set.julian()).abs() < 1.0 / 86400.0);
+        assert_approx_eq!(TestMoon.location(date), moon::MOON.location(date));
+        assert!((TestMoon.distance(date).au() - moon::MOON.distance(date).au()).abs() < 1e-9);
+        // The moon moves around 13° a day, eastward
+        let (ra, _) = TestMoon.sky_motion(date);
+        assert!(ra.degrees_per_day() > 10.0 && ra.degrees_per_day() < 17.0);
+
+        // Planets rise and set in their position of date, which is what the sidereal time is measured against
+        let rs = sol::JUPITER
+            .riseset_moving(date, &obs, coord::H0_STAR)
+            .unwrap();
+        let expect = coord::riseset_moving(
+            |d| sol::JUPITER.location_of_date(d),
+            date,
+            &obs,
+            coord::H0_STAR,
+        )
+        .unwrap();
+        assert_eq!((rs.rise, rs.set), (expect.rise, expect.set));
+    }
+
+    #[test]
+    fn test_impls() {
+        let objs: [&dyn CelObj; 4] = [&sol::SUN, &sol::JUPITER, &sol::VENUS, &moon::MOON];
+        let names: Vec<&str> = objs.iter().map(|o| o.name()).collect();
+        assert_eq!(names, ["Sun", "Jupiter", "Venus", "Moon"]);
+
+        // Perihelion of 2025, on January 4 at 13:28 UT, 0.983327 AU
+        let d = time::Date::from_calendar(2025, 1, 4, time::Angle::from_clock(13, 28, 0.0));
+        assert!((objs[0].distance(d).au() - 0.983327).abs() < 2e-5);
+        // Jupiter was closest on 2022 September 26, 591 million kilometers away, near the equinox
+        let d = time::Date::from_calendar(2022, 9, 26, time::Angle::from_clock(20, 0, 0.0));
+        assert!((objs[1].distance(d).km() - 591e6).abs() < 1e6);
+        assert_approx_eq!(
+            objs[1].location_of_date(d),
+            Coord::from_equatorial(time::Angle::from_degrees(0.0), time::Angle::default()),
+            time::Angle::from_degrees(5.0)
+        );
+        // Astronomical Algorithms by Jean Meeus, Example 33.a: 21h04m41.454s -18°53'16.84", 0.91085 AU
+        let d = time::Date::from_julian(2448976.5);
+        assert_approx_eq!(
+            objs[2].location_of_date(d).nutate(d),
+            Coord::from_equatorial(
+                time::Angle::from_clock(21, 4, 41.454),
+                crate::angle!(-18:53:16.84)
+            ),
+            time::Angle::from_degminsec(0, 0, 30.0)
+        );
+        assert!((objs[2].distance(d).au() - 0.91085).abs() < 1e-4);
+        assert!(objs[2].magnitude(d) < -4.0);
+        // Example 47.a: 8h58m45.2s +13°46'06", 368410 km, which the simple model of the moon is a couple of degrees from
+        let d = time::Date::from_julian(2448724.5);
+        assert_approx_eq!(
+            objs[3].location_of_date(d),
+            Coord::from_equatorial(
+                time::Angle::from_clock(8, 58, 45.2),
+                time::Angle::from_degminsec(13, 46, 6.0)
+            ),
+            time::Angle::from_degrees(2.0)
+        );
+        assert!((objs[3].distance(d).km() - 368410.0).abs() < 6000.0);
+        assert_approx_eq!(
+            objs[3].angdia(d),
+            time::Angle::from_degminsec(0, 32, 0.0),
+            time::Angle::from_degminsec(0, 1, 0.0)
+        );
+        // Planets are heliocentric, unlike their location
+        assert_eq!(
+            CelObj::locationcart(&sol::JUPITER, d),
+            sol::JUPITER.locationcart(d)
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("JUPITER").unwrap().name(), "Jupiter");
+        assert_eq!(lookup("moon").unwrap().name(), "Moon");
+        assert!(lookup("earth").is_none());
+        assert!(lookup("Test Moon").is_none());
+        static TEST_MOON: TestMoon = TestMoon;
+        register(&TEST_MOON);
+        assert_eq!(lookup("test moon").unwrap().name(), "Test Moon");
+        // Other tests may register more, so the count isn't fixed, but registered objects come after the built in ones
+        assert!(bodies()[10..].iter().any(|o| o.name() == "Test Moon"));
+    }
+
+    #[test]
+    fn test_observe() {
+        let date = time::Date::from_calendar(2025, 3, 10, time::Angle::from_clock(21, 0, 0.0));
+        let obs = Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+        let plain = ObserveOptions {
+            refraction: false,
+            parallax: false,
+        };
+        let geo = moon::MOON.observe_with(&obs, date, plain);
+        assert_eq!(geo.location, moon::MOON.location(date));
+        assert_eq!(
+            (geo.azimuth, geo.altitude),
+            moon::MOON.location(date).horizon_for(&obs, date)
+        );
+        assert_eq!(geo.illumfrac, Some(moon::MOON.illumfrac(date)));
+
+        // The moon is lowered by up to a degree by parallax, and raised slightly by refraction
+        let topo = moon::MOON.observe(&obs, date);
+        let shift = geo.location.dist(topo.location).degrees();
+        assert!(shift > 0.1 && shift < 1.1, "{shift}");
+        assert!(topo.distance.au() < geo.distance.au());
+        let rs = topo.riseset.unwrap();
+        let day = time::Date::from_time(date, time::Angle::default()).julian();
+        for event in [rs.rise, rs.transit, rs.set] {
+            assert!(event.julian() >= day && event.julian() < day + 1.0);
+        }
+        assert!(rs.rise_az.degrees() < 180.0 && rs.set_az.degrees() > 180.0);
+
+        let sun = sol::SUN.observe(&obs, date);
+        assert_eq!(sun.illumfrac, None);
+        assert!(sun.altitude.to_latitude().degrees() < 0.0);
+
+        // Planets are observed of date, like the sidereal time, not in their J2000 location
+        let mars = sol::MARS.observe_with(&obs, date, plain);
+        assert_eq!(mars.location, sol::MARS.location_of_date(date));
+        assert_eq!(
+            (mars.azimuth, mars.altitude),
+            sol::MARS.location_of_date(date).horizon_for(&obs, date)
+        );
+        assert_eq!(
+            sol::MARS.sky_motion(date),
+            coord::sky_motion(|t| sol::MARS.location_of_date(t), date)
+        );
     }
 }
@@ -0,0 +1,272 @@
+/*! Comets, on elliptical, parabolic, or hyperbolic orbits about the sun
+
+* Reading a line of the MPC comet elements, `CometEls.txt`: [`Comet::from_mpc_comet_line()`]
+* Position in the orbit, from the time since perihelion: [`Comet::locationcart()`]
+* Total brightness, which depends on the distance from the sun: [`Comet::magnitude()`]
+* Direction of the tail in the sky: [`Comet::tail_pa()`]
+
+Comets are [`CelObj`]s, so they can be observed and [`register()`](crate::register)ed like anything else.
+```
+# use pracstro::{comet::Comet, time::Date};
+let hale_bopp = Comet::from_mpc_comet_line(
+    "    CJ95O010  1997 04 01.1375  0.914142  0.995068  130.5888  282.4707   89.4296  19970401  -0.8  4.0  C/1995 O1 (Hale-Bopp)",
+)
+.unwrap();
+let d = Date::from_calendar(1997, 4, 1, Default::default());
+println!("{:.0} {:.1}", hale_bopp.location(d), hale_bopp.magnitude(d));
+```
+
+Format from <https://minorplanetcenter.net/iau/info/CometOrbitFormat.html>
+*/
+
+use crate::asteroid::ParseMpcError;
+use crate::celobj::CelObj;
+use crate::coord::{Coord, Distance, Vector3};
+use crate::sol;
+use crate::time::{Angle, Date, J2000};
+
+/// The Gaussian gravitational constant, the mean motion of a body 1 AU from the sun in radians per day
+const GAUSS: f64 = 0.01720209895;
+
+/// A comet, with its orbit given by the time and distance of perihelion
+///
+/// Angles are in the ecliptic frame of J2000, as given by the MPC.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Comet {
+    /// Name and designation, such as "1P/Halley"
+    pub name: String,
+    /// Date of perihelion
+    pub perihelion: Date,
+    /// Perihelion distance (AU)
+    pub q: f64,
+    /// Eccentricity, 1 for a parabolic orbit
+    pub e: f64,
+    /// Argument of perihelion
+    pub w: Angle,
+    /// Longitude of the ascending node
+    pub o: Angle,
+    /// Inclination
+    pub i: Angle,
+    /// Absolute magnitude, the total magnitude at 1 AU from the sun and earth
+    pub h: f64,
+    /// How fast the comet brightens as it nears the sun, usually around 4
+    pub n: f64,
+}
+impl Comet {
+    /// Reads a comet from a line of the fixed width format of the Minor Planet Center
+    ///
+    /// The name is used when it is there, the packed designation at the start otherwise.
+    /// The slope parameter of the MPC is taken as `n`, and a blank absolute magnitude is an error.
+    pub fn from_mpc_comet_line(line: &str) -> Result<Comet, ParseMpcError> {
+        let text = |a: usize, b: usize| line.get(a - 1..b.min(line.len())).unwrap_or("").trim();
+        let number = |a, b, field| {
+            text(a, b)
+                .parse::<f64>()
+                .map_err(|_| ParseMpcError { field })
+        };
+        let angle = |a, b, field| number(a, b, field).map(Angle::from_degrees);
+
+        let name = match text(103, 158) {
+            "" => text(1, 12),
+            name => name,
+        };
+        if name.is_empty() {
+            return Err(ParseMpcError { field: "name" });
+        }
+        let perihelion = {
+            let bad = ParseMpcError {
+                field: "perihelion",
+            };
+            let (y, m) = (text(15, 18).parse().ok(), text(20, 21).parse().ok());
+            let day = number(23, 29, "perihelion")?;
+            let (Some(y), Some(m)) = (y, m) else {
+                return Err(bad);
+            };
+            let whole =
+                Date::try_from_calendar(y, m, day as u8, Angle::default()).map_err(|_| bad)?;
+            Date::from_jd_parts(whole.julian(), day.fract())
+        };
+        Ok(Comet {
+            name: name.to_string(),
+            perihelion,
+            q: number(31, 39, "q")?,
+            e: number(42, 49, "e")?,
+            w: angle(52, 59, "w")?,
+            o: angle(62, 69, "o")?,
+            i: angle(72, 79, "i")?,
+            h: number(92, 95, "h")?,
+            n: number(97, 100, "n")?,
+        })
+    }
+
+    /// Position in the plane of the orbit with x towards the perihelion, in AU
+    fn in_orbit(&self, d: Date) -> (f64, f64) {
+        let (q, e) = (self.q, self.e);
+        let t = d.julian() - self.perihelion.julian();
+        if (e - 1.0).abs() < 1e-9 {
+            // Barker's equation, from Astronomical Algorithms by Jean Meeus, Chapter 34
+            let w = 3.0 * GAUSS / 2.0_f64.sqrt() * t / q.powf(1.5);
+            let y = (w / 2.0 + (w * w / 4.0 + 1.0).sqrt()).cbrt();
+            let s = y - 1.0 / y;
+            (q * (1.0 - s * s), 2.0 * q * s)
+        } else if e < 1.0 {
+            let a = q / (1.0 - e);
+            let m = Angle::from_radians(GAUSS * t / a.powf(1.5))
+                .to_latitude()
+                .radians();
+            let mut ea = m + 0.85 * e * m.signum();
+            for _ in 0..50 {
+                let de = (ea - e * ea.sin() - m) / (1.0 - e * ea.cos());
+                ea -= de;
+                if de.abs() < 1e-12 {
+                    break;
+                }
+            }
+            (a * (ea.cos() - e), a * (1.0 - e * e).sqrt() * ea.sin())
+        } else {
+            let a = q / (e - 1.0);
+            let m = GAUSS * t / a.powf(1.5);
+            let mut ha = (m / e).asinh();
+            for _ in 0..50 {
+                let dh = (e * ha.sinh() - ha - m) / (e * ha.cosh() - 1.0);
+                ha -= dh;
+                if dh.abs() < 1e-12 {
+                    break;
+                }
+            }
+            (a * (e - ha.cosh()), a * (e * e - 1.0).sqrt() * ha.sinh())
+        }
+    }
+
+    /// Returns the heliocentric location as rectangular coordinates in AU, in the same frame as [`sol::Planet::locationcart()`]
+    pub fn locationcart(&self, d: Date) -> Vector3 {
+        let (x, y) = self.in_orbit(d);
+        sol::to_equatorial(sol::orbit_to_ecliptic(self.i, self.o, self.w, x, y))
+    }
+
+    /// Returns the geocentric location
+    pub fn location(&self, d: Date) -> Coord {
+        (self.locationcart(d) - sol::earth_geocenter(d)).into()
+    }
+
+    /// Returns the distance from the earth
+    pub fn distance(&self, d: Date) -> Distance {
+        Distance::from_au((self.locationcart(d) - sol::earth_geocenter(d)).norm())
+    }
+
+    /// Returns the location relative to the barycenter of the solar system, in AU, see [`sol::ssb_offset()`]
+    pub fn location_barycentric(&self, d: Date) -> Vector3 {
+        self.locationcart(d) + sol::ssb_offset(d)
+    }
+
+    /// Total visual magnitude of the comet and its coma
+    ///
+    /// The standard law for comets, `m = H + 5 log Δ + 2.5 n log r`.
+    pub fn magnitude(&self, d: Date) -> f64 {
+        let r = self.locationcart(d).norm();
+        self.h + 5.0 * self.distance(d).au().log10() + 2.5 * self.n * r.log10()
+    }
+
+    /// Position angle of the tail, measured from north through east
+    ///
+    /// This is the direction away from the sun as seen from the earth, which the gas tail follows.
+    /// The dust tail curves away from it, behind the motion of the comet.
+    pub fn tail_pa(&self, d: Date) -> Angle {
+        let (c, earth) = (self.locationcart(d), sol::earth_geocenter(d));
+        let (a1, d1) = Coord::from(c - earth).equatorial();
+        let (a2, d2) = Coord::from(c * 1.001 - earth).equatorial();
+        let ((sd1, cd1), (sd2, cd2)) = (d1.sincos(), d2.sincos());
+        let (sa, ca) = (a2 - a1).sincos();
+        Angle::atan2(sa * cd2, cd1 * sd2 - sd1 * cd2 * ca)
+    }
+}
+
+/// The origin of [`CelObj::locationcart()`] is the sun, as with [`sol::Planet`]
+impl CelObj for Comet {
+    fn locationcart(&self, d: Date) -> Vector3 {
+        Comet::locationcart(self, d)
+    }
+    fn location(&self, d: Date) -> Coord {
+        Comet::location(self, d)
+    }
+    fn location_of_date(&self, d: Date) -> Coord {
+        Comet::location(self, d).precess_rigorous(J2000, d)
+    }
+    fn distance(&self, d: Date) -> Distance {
+        Comet::distance(self, d)
+    }
+    fn magnitude(&self, d: Date) -> f64 {
+        Comet::magnitude(self, d)
+    }
+    /// The size of the coma isn't known
+    fn angdia(&self, _d: Date) -> Angle {
+        Angle::default()
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALE_BOPP: &str = "    CJ95O010  1997 04 01.1375  0.914142  0.995068  130.5888  282.4707   89.4296  19970401  -0.8  4.0  C/1995 O1 (Hale-Bopp)";
+
+    #[test]
+    fn test_from_mpc_comet_line() {
+        let c = Comet::from_mpc_comet_line(HALE_BOPP).unwrap();
+        assert_eq!(c.name, "C/1995 O1 (Hale-Bopp)");
+        assert_eq!((c.q, c.e, c.h, c.n), (0.914142, 0.995068, -0.8, 4.0));
+        assert!((c.perihelion.julian() - 2450539.6375).abs() < 1e-6);
+        assert_eq!(
+            Comet::from_mpc_comet_line(&HALE_BOPP.replacen("0.914142", "0.9x4142", 1)),
+            Err(ParseMpcError { field: "q" })
+        );
+        assert_eq!(
+            Comet::from_mpc_comet_line(&HALE_BOPP.replacen(" 04 ", " 13 ", 1)),
+            Err(ParseMpcError {
+                field: "perihelion"
+            })
+        );
+    }
+
+    #[test]
+    fn test_orbit() {
+        let c = Comet::from_mpc_comet_line(HALE_BOPP).unwrap();
+        assert!((c.locationcart(c.perihelion).norm() - c.q).abs() < 1e-9);
+        // Closest to the earth on 1997 March 22, at 1.315 AU
+        let range = Date::from_calendar(1997, 3, 1, Angle::default())
+            ..Date::from_calendar(1997, 4, 15, Angle::default());
+        let closest = crate::events::find_minimum(
+            |d| c.distance(d).au(),
+            range,
+            std::time::Duration::from_secs(60),
+        );
+        let expected = Date::from_calendar(1997, 3, 22, Angle::default());
+        assert!((closest.julian() - expected.julian()).abs() < 1.0);
+        assert!((c.distance(closest).au() - 1.315).abs() < 0.005);
+
+        // The three kinds of orbit meet at an eccentricity of 1
+        let d = Date::from_julian(c.perihelion.julian() + 40.0);
+        let at = |e| Comet { e, ..c.clone() }.locationcart(d);
+        let parabola = at(1.0);
+        for e in [1.0 - 1e-7, 1.0 + 1e-7] {
+            assert!((at(e) - parabola).norm() < 1e-5);
+        }
+        // The tail points away from the sun, so it is nearly opposite the direction of the sun in the sky
+        let sun = sol::SUN.location(d);
+        let (sa, sd) = c.location(d).equatorial();
+        let (a2, d2) = sun.equatorial();
+        let sun_pa = Angle::atan2(
+            (a2 - sa).sin() * d2.cos(),
+            sd.cos() * d2.sin() - sd.sin() * d2.cos() * (a2 - sa).cos(),
+        );
+        assert_approx_eq!(
+            c.tail_pa(d),
+            sun_pa + Angle::from_degrees(180.0),
+            Angle::from_degrees(1.0)
+        );
+    }
+}
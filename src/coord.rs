@@ -1,14 +1,20 @@
 //! Coordinate handling
 //!
-//! This module contains one type, [`Coord`]. That has methods to convert two and from several
+//! This module contains one main type, [`Coord`]. That has methods to convert two and from several
 //! different coordinate systems. Mainly:
 //! - Equatorial (Hour Angle, Declination)
 //! - Horizon (Azimuth, Altitude)
 //! - Ecliptic (Beta, Lambda)
+//! - Galactic (b, l)
 //!
-//! This type also contains algorithms for converting from Cartesian (rectangular) coordinates, rise and set times, distance between angles, etc.
+//! A [`Coord`] is equatorial unless tagged with another [`Frame`], such as `Coord<Ecliptic>`, which keeps coordinates
+//! in different systems from being mixed up.
+//!
+//! This type also contains algorithms for converting to and from Cartesian (rectangular) coordinates, as a [`Vector3`],
+//! rise and set times, distance between angles, etc.
 
 use crate::time::*;
+use crate::Error;
 
 /// Gets the mean obliquity of the ecliptic at a certain date
 pub fn mean_obliquity_ecl(d: Date) -> Angle {
@@ -18,6 +24,542 @@ pub fn mean_obliquity_ecl(d: Date) -> Angle {
     )
 }
 
+/// Gets the true obliquity of the ecliptic at a certain date, the mean obliquity corrected for nutation
+pub fn true_obliquity_ecl(d: Date) -> Angle {
+    mean_obliquity_ecl(d) + nutation(d).1
+}
+
+/// Nutation in longitude (Δψ) and in obliquity (Δε) at a certain date
+///
+/// Uses the largest terms of the IAU 1980 series, which is accurate to 0.5" in longitude and 0.1" in obliquity.
+/// Both are small signed angles, use [`Angle::to_latitude()`] to get the signed value.
+///
+/// From Astronomical Algorithms by Jean Meeus, Chapter 22
+pub fn nutation(d: Date) -> (Angle, Angle) {
+    let t = d.centuries();
+    let o = Angle::from_degrees(125.04452 - 1934.136261 * t);
+    let l = Angle::from_degrees(280.4665 + 36000.7698 * t);
+    let lp = Angle::from_degrees(218.3165 + 481267.8813 * t);
+    let dpsi = -17.20 * o.sin() - 1.32 * (l * 2.0).sin() - 0.23 * (lp * 2.0).sin()
+        + 0.21 * (o * 2.0).sin();
+    let deps =
+        9.20 * o.cos() + 0.57 * (l * 2.0).cos() + 0.10 * (lp * 2.0).cos() - 0.09 * (o * 2.0).cos();
+    (
+        Angle::from_degrees(dpsi / 3600.0),
+        Angle::from_degrees(deps / 3600.0),
+    )
+}
+
+/// Checks if an angle is between -90° and 90°, with some room for rounding
+fn is_latitude(a: Angle) -> bool {
+    a.to_latitude().radians().abs() <= std::f64::consts::FRAC_PI_2 + 1e-12
+}
+
+/// The north galactic pole in J2000 equatorial coordinates
+const GALACTIC_POLE: Coord = Coord::from_equatorial(
+    Angle::from_degrees(192.85948),
+    Angle::from_degrees(27.12825),
+);
+/// The galactic longitude of the north celestial pole
+const GALACTIC_NCP_LONGITUDE: Angle = Angle::from_degrees(122.93192);
+
+/// The rotation from the mean equator and equinox of J2000 to that of a date (IAU 2006)
+fn precession_matrix(d: Date) -> [[f64; 3]; 3] {
+    let t = d.centuries();
+    let arcsec = |x: f64| Angle::from_degrees(x / 3600.0);
+    let zeta = arcsec(
+        2.650545
+            + t * (2306.083227
+                + t * (0.2988499 + t * (0.01801828 + t * (-0.000005971 + t * -0.0000003173)))),
+    );
+    let z = arcsec(
+        -2.650545
+            + t * (2306.077181
+                + t * (1.0927348 + t * (0.01826837 + t * (-0.000028596 + t * -0.0000002904)))),
+    );
+    let theta = arcsec(
+        t * (2004.191903
+            + t * (-0.4294934 + t * (-0.04182264 + t * (-0.000007089 + t * -0.0000001274)))),
+    );
+    let ((szeta, czeta), (sz, cz), (st, ct)) = (zeta.sincos(), z.sincos(), theta.sincos());
+    [
+        [
+            czeta * cz * ct - szeta * sz,
+            -szeta * cz * ct - czeta * sz,
+            -cz * st,
+        ],
+        [
+            czeta * sz * ct + szeta * cz,
+            -szeta * sz * ct + czeta * cz,
+            -sz * st,
+        ],
+        [czeta * st, -szeta * st, ct],
+    ]
+}
+
+/// Rotation from the FK4 frame at B1950 to the FK5 frame at J2000, for positions (Standish, 1982)
+const FK4_TO_FK5: [[f64; 3]; 3] = [
+    [0.9999256782, -0.0111820611, -0.0048579477],
+    [0.0111820610, 0.9999374784, -0.0000271765],
+    [0.0048579479, -0.0000271474, 0.9999881997],
+];
+/// The E-terms of aberration, which are included in FK4 positions
+const FK4_E_TERMS: Vector3 = Vector3::new(-1.62557e-6, -0.31919e-6, -0.13843e-6);
+
+/// Rotation from ICRS to the J2000 mean equator and equinox, the frame bias (IERS Conventions 2003)
+const FRAME_BIAS: [[f64; 3]; 3] = [
+    [
+        0.9999999999999942,
+        -7.078279744199226e-08,
+        8.05614893899716e-08,
+    ],
+    [
+        7.078279477859602e-08,
+        0.999999999999997,
+        3.306041454222148e-08,
+    ],
+    [
+        -8.056149173008023e-08,
+        -3.30604088398539e-08,
+        0.9999999999999962,
+    ],
+];
+
+/// Altitude at which a star rises or sets, the horizon lowered by atmospheric refraction
+pub const H0_STAR: Angle = crate::angle!(-0:34:0);
+/// Altitude at which the sun rises or sets, refraction plus the sun's semidiameter
+pub const H0_SUN: Angle = crate::angle!(-0:50:0);
+/// Altitude of the sun at the beginning and end of civil twilight
+pub const H0_CIVIL: Angle = crate::angle!(-6);
+/// Altitude of the sun at the beginning and end of nautical twilight
+pub const H0_NAUTICAL: Angle = crate::angle!(-12);
+/// Altitude of the sun at the beginning and end of astronomical twilight
+pub const H0_ASTRONOMICAL: Angle = crate::angle!(-18);
+
+/// How much atmospheric refraction raises an object at a true altitude, at sea level in typical conditions
+///
+/// Below an altitude of -1° the refraction there is used, the formula is meaningless further down.
+///
+/// This is [`Angle::refractdelta()`], from Astronomical Algorithms by Jean Meeus, Ch. 16 (Sæmundsson)
+pub fn refraction(alt: Angle) -> Angle {
+    Angle::from_degrees(alt.to_latitude().degrees().max(-1.0)).refractdelta()
+}
+
+/// The airmass at an altitude, how much more atmosphere the light from an object passes through than from the zenith
+///
+/// Below the horizon the airmass on the horizon, around 38, is used.
+///
+/// From Kasten and Young (1989)
+pub fn airmass(alt: Angle) -> f64 {
+    let h = alt.to_latitude().degrees().max(0.0);
+    1.0 / (h.to_radians().sin() + 0.50572 * (h + 6.07995).powf(-1.6364))
+}
+
+/// How many magnitudes the atmosphere dims an object at an altitude, for a typical site at sea level
+///
+/// This takes an extinction of 0.2 magnitudes per [`airmass()`] in visible light, which varies a lot with the weather and elevation.
+pub fn extinction(alt: Angle) -> f64 {
+    0.2 * airmass(alt)
+}
+
+/**
+A 3D vector, for rectangular coordinates
+
+The axes follow whatever frame the vector came from. For vectors from [`Coord::cartesian()`], x points towards
+the origin of longitude, z towards the north pole, and the length is the distance.
+```
+# use pracstro::coord::Vector3;
+let v = Vector3::new(3.0, 4.0, 0.0);
+assert_eq!(v.norm(), 5.0);
+assert_eq!(v.cross(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(4.0, -3.0, 0.0));
+```
+*/
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    /// X component
+    pub x: f64,
+    /// Y component
+    pub y: f64,
+    /// Z component
+    pub z: f64,
+}
+impl Vector3 {
+    /// Constructs a vector from its components
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Vector3 { x, y, z }
+    }
+    /// Dot product
+    pub fn dot(self, v: Self) -> f64 {
+        self.x * v.x + self.y * v.y + self.z * v.z
+    }
+    /// Cross product
+    pub fn cross(self, v: Self) -> Self {
+        Vector3::new(
+            self.y * v.z - self.z * v.y,
+            self.z * v.x - self.x * v.z,
+            self.x * v.y - self.y * v.x,
+        )
+    }
+    /// Length of the vector
+    pub fn norm(self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+    /// The product with a 3x3 matrix, given as rows
+    fn rotate(self, m: [[f64; 3]; 3]) -> Self {
+        let [x, y, z] = m.map(|row| Vector3::new(row[0], row[1], row[2]).dot(self));
+        Vector3::new(x, y, z)
+    }
+    /// The product with the transpose of a 3x3 matrix, which is the inverse of a rotation
+    fn unrotate(self, m: [[f64; 3]; 3]) -> Self {
+        let [x, y, z] = [0, 1, 2].map(|i| Vector3::new(m[0][i], m[1][i], m[2][i]).dot(self));
+        Vector3::new(x, y, z)
+    }
+}
+impl std::ops::Add for Vector3 {
+    type Output = Vector3;
+    fn add(self, v: Self) -> Self {
+        Vector3::new(self.x + v.x, self.y + v.y, self.z + v.z)
+    }
+}
+impl std::ops::Sub for Vector3 {
+    type Output = Vector3;
+    fn sub(self, v: Self) -> Self {
+        Vector3::new(self.x - v.x, self.y - v.y, self.z - v.z)
+    }
+}
+impl std::ops::Neg for Vector3 {
+    type Output = Vector3;
+    fn neg(self) -> Self {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+impl std::ops::Mul<f64> for Vector3 {
+    type Output = Vector3;
+    /// Scaling
+    fn mul(self, k: f64) -> Self {
+        Vector3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+/// The direction of the vector, discarding its length
+impl From<Vector3> for Coord {
+    fn from(v: Vector3) -> Self {
+        Coord::from_cartesian(v.x, v.y, v.z)
+    }
+}
+
+/**
+A distance, such as from the earth to a planet
+
+| Unit                   | To Method                    | From Method                       |
+|------------------------|------------------------------|-----------------------------------|
+| Astronomical Units     | [`Distance::au()`]           | [`Distance::from_au()`]           |
+| Kilometers             | [`Distance::km()`]           | [`Distance::from_km()`]           |
+| Meters                 | [`Distance::meters()`]       | [`Distance::from_meters()`]       |
+| Light Minutes          | [`Distance::light_minutes()`]| [`Distance::from_light_minutes()`]|
+```
+# use pracstro::sol;
+let d = sol::MARS.distance(pracstro::time::Date::now());
+println!("{:.2} ({:.0} km, {:.1} light minutes)", d, d.km(), d.light_minutes());
+```
+*/
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Distance(f64);
+impl Distance {
+    /// Kilometers in an astronomical unit, exact by definition (IAU 2012)
+    pub const AU_KM: f64 = 149_597_870.7;
+    /// The speed of light in kilometers per second, exact by definition
+    pub const C_KM_S: f64 = 299_792.458;
+
+    /// Returns the distance in astronomical units
+    ///
+    /// This is the only function that should directly access the fields of the type.
+    pub const fn au(self) -> f64 {
+        self.0
+    }
+    /// Constructs a distance from astronomical units
+    pub const fn from_au(x: f64) -> Self {
+        Distance(x)
+    }
+    /// Returns the distance in kilometers
+    pub const fn km(self) -> f64 {
+        self.au() * Distance::AU_KM
+    }
+    /// Constructs a distance from kilometers
+    pub const fn from_km(x: f64) -> Self {
+        Distance::from_au(x / Distance::AU_KM)
+    }
+    /// Returns the distance in meters
+    pub const fn meters(self) -> f64 {
+        self.km() * 1000.0
+    }
+    /// Constructs a distance from meters
+    pub const fn from_meters(x: f64) -> Self {
+        Distance::from_km(x / 1000.0)
+    }
+    /// Returns the time light takes to travel the distance, in minutes
+    pub const fn light_minutes(self) -> f64 {
+        self.km() / Distance::C_KM_S / 60.0
+    }
+    /// Constructs the distance light travels in a number of minutes
+    pub const fn from_light_minutes(x: f64) -> Self {
+        Distance::from_km(x * 60.0 * Distance::C_KM_S)
+    }
+}
+/// Astronomical units, with the precision passed on to the number
+impl std::fmt::Display for Distance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} AU", p, self.au()),
+            None => write!(f, "{} AU", self.au()),
+        }
+    }
+}
+impl std::ops::Add for Distance {
+    type Output = Distance;
+    fn add(self, x: Self) -> Self {
+        Distance::from_au(self.au() + x.au())
+    }
+}
+impl std::ops::Sub for Distance {
+    type Output = Distance;
+    fn sub(self, x: Self) -> Self {
+        Distance::from_au(self.au() - x.au())
+    }
+}
+impl std::ops::Mul<f64> for Distance {
+    type Output = Distance;
+    /// Scaling
+    fn mul(self, x: f64) -> Self {
+        Distance::from_au(self.au() * x)
+    }
+}
+
+/**
+A place on the earth to observe from
+
+Bundles the arguments of methods such as [`Coord::horizon()`], which take them loose.
+```
+# use pracstro::{coord::{Coord, Observer}, sol, time::{Angle, Date}};
+let tucson = Observer::new(Angle::from_degrees(32.2), Angle::from_degrees(-110.9), 728.0);
+let (azimuth, altitude) = sol::VENUS.location(Date::now()).horizon_for(&tucson, Date::now());
+```
+*/
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observer {
+    /// Latitude, positive to the north
+    pub lat: Angle,
+    /// Longitude, positive to the east
+    pub lon: Angle,
+    /// Elevation above sea level, in meters
+    pub elevation_m: f64,
+}
+impl Observer {
+    /// Constructs an observer from latitude, longitude, and elevation in meters
+    pub const fn new(lat: Angle, lon: Angle, elevation_m: f64) -> Self {
+        Observer {
+            lat,
+            lon,
+            elevation_m,
+        }
+    }
+    /// Local mean sidereal time for the observer
+    pub fn lst(&self, d: Date) -> Angle {
+        d.lst(self.lon)
+    }
+    /// The dip of the horizon, how far the sea horizon is below the astronomical horizon
+    ///
+    /// Only observers above their horizon see this, such as from a mountain top or a ship.
+    /// The formula includes typical terrestrial refraction.
+    pub fn dip(&self) -> Angle {
+        Angle::from_degrees(0.0293 * self.elevation_m.max(0.0).sqrt())
+    }
+    /// Geocentric latitude and distance from the center of the earth in equatorial radii, see [`geodetic_to_geocentric()`]
+    pub fn geocentric(&self) -> (Angle, f64) {
+        geodetic_to_geocentric(self.lat, self.elevation_m)
+    }
+    /// ρ·sin(φ′) and ρ·cos(φ′), the position of the observer in equatorial radii, north of and away from the earths axis
+    ///
+    /// These are the terms used in correcting for parallax.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 11
+    pub fn parallax_terms(&self) -> (f64, f64) {
+        let (u, (slat, clat)) = (
+            Angle::atan2((1.0 - WGS84_F) * self.lat.sin(), self.lat.cos()),
+            self.lat.sincos(),
+        );
+        let h = self.elevation_m / WGS84_A;
+        ((1.0 - WGS84_F) * u.sin() + h * slat, u.cos() + h * clat)
+    }
+    /// Position in Earth-centered, Earth-fixed (ECEF) cartesian coordinates, in meters
+    ///
+    /// The x axis points to latitude 0° and longitude 0°, the z axis to the north pole.
+    pub fn ecef(&self) -> Vector3 {
+        let ((slat, clat), (slon, clon)) = (self.lat.sincos(), self.lon.sincos());
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let n = WGS84_A / (1.0 - e2 * slat * slat).sqrt();
+        Vector3::new(
+            (n + self.elevation_m) * clat * clon,
+            (n + self.elevation_m) * clat * slon,
+            (n * (1.0 - e2) + self.elevation_m) * slat,
+        )
+    }
+    /// Observer at a position in Earth-centered, Earth-fixed (ECEF) cartesian coordinates, in meters
+    pub fn from_ecef(v: Vector3) -> Self {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let p = v.x.hypot(v.y);
+        let (mut lat, mut h) = (Angle::atan2(v.z, p * (1.0 - e2)), 0.0);
+        for _ in 0..10 {
+            let slat = lat.sin();
+            let n = WGS84_A / (1.0 - e2 * slat * slat).sqrt();
+            h = match lat.cos().abs() > 1e-9 {
+                true => p / lat.cos() - n,
+                false => v.z.abs() - n * (1.0 - e2),
+            };
+            lat = Angle::atan2(v.z, p * (1.0 - e2 * n / (n + h)));
+        }
+        Observer::new(lat.to_latitude(), Angle::atan2(v.y, v.x), h)
+    }
+    /// Position in cartesian equatorial coordinates of date, in meters from the center of the earth
+    ///
+    /// See [`ecef_to_celestial()`]
+    pub fn celestial(&self, d: Date) -> Vector3 {
+        ecef_to_celestial(self.ecef(), d)
+    }
+}
+
+/// Rotates Earth-fixed cartesian coordinates into the equatorial frame of date, by the sidereal time
+///
+/// This uses [`Date::gmst()`], ignoring nutation and polar motion.
+pub fn ecef_to_celestial(v: Vector3, d: Date) -> Vector3 {
+    v.rotate(sidereal_rotation(d))
+}
+
+/// Rotates cartesian coordinates in the equatorial frame of date into Earth-fixed coordinates, see [`ecef_to_celestial()`]
+pub fn celestial_to_ecef(v: Vector3, d: Date) -> Vector3 {
+    v.unrotate(sidereal_rotation(d))
+}
+
+/// Rotation about the earths axis by Greenwich mean sidereal time
+fn sidereal_rotation(d: Date) -> [[f64; 3]; 3] {
+    let (s, c) = d.gmst().sincos();
+    [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]]
+}
+
+/// Equatorial radius of the earth in meters, from the WGS84 ellipsoid
+pub const WGS84_A: f64 = 6_378_137.0;
+/// Flattening of the earth, from the WGS84 ellipsoid
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Geodetic latitude and elevation in meters, to geocentric latitude and distance from the center of the earth in equatorial radii
+///
+/// Geodetic latitude, as on maps and from GPS, is the angle of the local vertical,
+/// geocentric latitude is the angle at the center of the earth, which is up to 11.5' closer to the equator.
+///
+/// From Astronomical Algorithms by Jean Meeus, Ch. 11
+pub fn geodetic_to_geocentric(lat: Angle, elevation_m: f64) -> (Angle, f64) {
+    let (rs, rc) = Observer::new(lat, Angle::default(), elevation_m).parallax_terms();
+    (
+        Angle::atan2(rs, rc).to_latitude(),
+        (rs * rs + rc * rc).sqrt(),
+    )
+}
+
+/// Geocentric latitude of a point on the surface of the ellipsoid, to geodetic latitude
+pub fn geocentric_to_geodetic(lat: Angle) -> Angle {
+    let (s, c) = lat.sincos();
+    Angle::atan2(s, c * (1.0 - WGS84_F) * (1.0 - WGS84_F)).to_latitude()
+}
+
+/// The rise, transit, and set of a coordinate on one day, see [`Coord::rise_set_transit()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RiseSet {
+    /// When the coordinate rises
+    pub rise: Date,
+    /// When the coordinate crosses the meridian
+    pub transit: Date,
+    /// When the coordinate sets
+    pub set: Date,
+    /// Azimuth of the coordinate when it rises
+    pub rise_az: Angle,
+    /// Azimuth of the coordinate when it sets
+    pub set_az: Angle,
+}
+
+/// Why a coordinate doesn't rise or set
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NoRiseSet {
+    /// The coordinate stays above the horizon all day
+    Circumpolar,
+    /// The coordinate stays below the horizon all day
+    NeverRises,
+}
+impl std::fmt::Display for NoRiseSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NoRiseSet::Circumpolar => write!(f, "circumpolar, never sets"),
+            NoRiseSet::NeverRises => write!(f, "never rises"),
+        }
+    }
+}
+impl std::error::Error for NoRiseSet {}
+
+/// How fast a moving body, whose position at a date is given by `position`, crosses the sky
+///
+/// The rates in right ascension and declination are found from the positions half an hour on either side of the date,
+/// see [`Coord::rate()`].
+pub fn sky_motion(position: impl Fn(Date) -> Coord, d: Date) -> (AngularRate, AngularRate) {
+    let (day, f) = d.jd_parts();
+    let half_hour = 1.0 / 48.0;
+    position(Date::from_jd_parts(day, f - half_hour)).rate(
+        position(Date::from_jd_parts(day, f + half_hour)),
+        std::time::Duration::from_secs(3600),
+    )
+}
+
+/// Rise, transit, and set of a moving body, whose position at a date is given by `position`
+///
+/// The rise, transit, and set are each found by re-evaluating the position at the previous estimate of that event,
+/// until it moves by less than a second. Unlike [`Coord::rise_set_transit_altitude()`], this is accurate for the moon,
+/// which moves around 13° a day. For the moon, `h0` is around `0.7275` times its [parallax](crate::moon::Moon::parallax) less 34'.
+///
+/// From Astronomical Algorithms by Jean Meeus, Ch. 15
+pub fn riseset_moving(
+    position: impl Fn(Date) -> Coord,
+    date: Date,
+    obs: &Observer,
+    h0: Angle,
+) -> Result<RiseSet, NoRiseSet> {
+    let day = Date::from_time(date, Angle::default());
+    let event = |d: Date| position(d).rise_set_transit_altitude(day, obs, h0);
+    let mut rs = event(Date::from_time(day, Angle::from_clock(12, 0, 0.0)))?;
+    for _ in 0..10 {
+        let (rise, transit, set) = (event(rs.rise)?, event(rs.transit)?, event(rs.set)?);
+        let next = RiseSet {
+            rise: rise.rise,
+            transit: transit.transit,
+            set: set.set,
+            rise_az: rise.rise_az,
+            set_az: set.set_az,
+        };
+        let converged = [
+            (next.rise, rs.rise),
+            (next.transit, rs.transit),
+            (next.set, rs.set),
+        ]
+        .iter()
+        .all(|(a, b)| (a.julian() - b.julian()).abs() < 1.0 / 86400.0);
+        rs = next;
+        if converged {
+            break;
+        }
+    }
+    Ok(rs)
+}
+
 /**
 Pair of angles, Representing "How far up" and "How far round"
 
@@ -25,75 +567,234 @@ Pair of angles, Representing "How far up" and "How far round"
 |-------------------|-------------------|---------------------|---------------------------------|------------------------|-----------------------------|
 | Equatorial        | Declination (δ)   | Right Ascension (α) |                                 | [`Coord::equatorial()`]| [`Coord::from_equatorial()`]|
 | Horizontal        | Altitude (a)      | Azimuth (A)         | Date, Time, Latitude, Longitude | [`Coord::horizon()`]   | [`Coord::from_horizon()`]   |
-| Ecliptic          | Ecl. Latitude (β) | Ecl. Longitude (λ)  | Date[^1]                        | [`Coord::ecliptic()`]  | [`Coord::from_ecliptic()`]  |
+| Hour Angle        | Declination (δ)   | Hour Angle (H)      | Date, Time, Longitude           | [`Coord::hour_angle()`]| [`Coord::from_hour_angle()`]|
+| Ecliptic          | Ecl. Latitude (β) | Ecl. Longitude (λ)  | Date[^1]                        | [`Coord::to_ecliptic()`]| [`Coord::<Ecliptic>::to_equatorial()`]|
+| Galactic          | Gal. Latitude (b) | Gal. Longitude (l)  |                                 | [`Coord::to_galactic()`]| [`Coord::<Galactic>::to_equatorial()`]|
 | Cartesian         | N/A (3D system)   | N/A (3D system)     | Distance                        | [`Coord::cartesian()`] | [`Coord::from_cartesian()`] |
 
 Additional Methods:
+* Signed declination: [`Coord::declination()`]
+* Coordinates in other frames are tagged with their [`Frame`], so they can't be mixed up, and ecliptic ones keep their equinox: [`Coord::<Ecliptic>::equinox()`]
+* Geodetic to geocentric latitude, as WGS84: [`geodetic_to_geocentric()`], [`Observer::parallax_terms()`]
+* Earth-fixed cartesian coordinates: [`Observer::ecef()`], [`Observer::from_ecef()`], [`ecef_to_celestial()`]
+* Horizontal coordinates for an [`Observer`]: [`Coord::horizon_for()`], [`Coord::from_horizon_for()`], [`Coord::riseset_for()`]
+* Atmospheric refraction of an altitude: [`refraction()`], and extinction: [`airmass()`] and [`extinction()`]
+* Converting many coordinates at once: [`Coord::horizon_batch()`], [`Coord::ecliptic_batch()`]
 * Distance between coordinates: [`Coord::dist()`]
-* Rise and set times of a coordinate in the sky [`Coord::riseset()`]
-* Precession [`Coord::precess()`]
+* The constellation a coordinate is in: [`Coord::constellation()`]
+* Where on the earth a coordinate is overhead: [`Coord::sub_point()`]
+* Points along the great circle between coordinates: [`Coord::slerp()`], [`Coord::waypoints()`]
+* Motion between two positions: [`Coord::rate()`], and of a moving body: [`sky_motion()`]
+* Comparison within a tolerance: [`Coord::approx_eq()`], `==` is exact
+* Rise and set times of a coordinate in the sky [`Coord::riseset()`], [`Coord::riseset_altitude()`]
+* Rise, transit, and set as dates, along with azimuths [`Coord::rise_set_transit()`], and for moving bodies [`riseset_moving()`]
+* Precession [`Coord::precess()`], or [`Coord::precess_rigorous()`] for accuracy near the poles and over long spans
+* Nutation [`Coord::nutate()`], see also [`nutation()`] and [`true_obliquity_ecl()`]
+* Frame change between B1950 (FK4) and J2000 (FK5): [`Coord::b1950_to_j2000()`] and [`Coord::j2000_to_b1950()`]
+* Frame bias between J2000 (FK5) and the ICRS: [`Coord::to_icrs()`] and [`Coord::from_icrs()`]
+* Formatting as text: [`Coord::fmt_equatorial()`], also available through [`std::fmt::Display`]
 
 [^1]: The plane of the ecliptic varies slightly with perturbations in the orbit and inclination of the earth.
 */
-#[derive(Debug, PartialEq, Clone, Copy, Default)]
-pub struct Coord(Angle, Angle);
+#[derive(PartialEq, Clone, Copy, Default)]
+pub struct Coord<F: Frame = Equatorial>(Angle, Latitude, F);
 impl Coord {
     /// Right Ascension and Declination
+    ///
+    /// The declination is an [`Angle`], so -25° is 335°, see [`Coord::declination()`] for it signed.
     pub const fn equatorial(self) -> (Angle, Angle) {
-        (self.0, self.1)
+        (self.0, self.1.angle())
+    }
+    /// Declination as a signed [`Latitude`], which can't be mistaken for an angle past 90°
+    pub const fn declination(self) -> Latitude {
+        self.1
+    }
+    /// Formats the right ascension and declination as `14h16m50.0s +19°02'50"`
+    ///
+    /// The right ascension gets one more decimal place than `precision`, since one second of time is 15 arcseconds.
+    pub fn fmt_equatorial(self, precision: usize) -> String {
+        let (ra, de) = (self.0, self.declination());
+        format!(
+            "{} {}{:.*}",
+            ra.fmt_hms(precision + 1),
+            if de.degrees() < 0.0 { "" } else { "+" },
+            precision,
+            de
+        )
     }
     /// Right Ascension and Declination
     pub const fn from_equatorial(x: Angle, y: Angle) -> Self {
-        Coord(x, y)
+        Coord(x, Latitude::from_radians(y.radians()), Equatorial)
     }
 
     /// Azimuth and Altitude, dependent on location and time
     ///
+    /// The altitude is an [`Angle`], so -25° is 335°, see [`Coord::altitude_for()`] for it signed.
+    ///
     /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
     pub fn horizon(self, date: Date, lati: Angle, longi: Angle) -> (Angle, Angle) {
+        self.horizon_lst(date.time().gst(date) + longi, lati.sincos())
+    }
+    /// Azimuth and Altitude of many coordinates at once, for an [`Observer`]
+    ///
+    /// The same as calling [`Coord::horizon_for()`] on each, but the sidereal time and latitude terms are only computed once.
+    pub fn horizon_batch(coords: &[Coord], date: Date, obs: &Observer) -> Vec<(Angle, Angle)> {
+        let (lst, lat) = (date.time().gst(date) + obs.lon, obs.lat.sincos());
+        coords.iter().map(|c| c.horizon_lst(lst, lat)).collect()
+    }
+    /// Azimuth and Altitude from the local sidereal time, and the sine and cosine of the latitude
+    fn horizon_lst(self, lst: Angle, (slat, clat): (f64, f64)) -> (Angle, Angle) {
         let (ra, de) = self.equatorial();
-        let ha = date.time().gst(date) + longi - ra;
-        let alt = Angle::asin(de.sin() * lati.sin() + de.cos() * lati.cos() * ha.cos());
-        let azip = Angle::acos((de.sin() - lati.sin() * alt.sin()) / (lati.cos() * alt.cos()));
-        let azi = match ha.sin() < 0.0 {
-            true => azip,
-            false => Angle::from_degrees(360.0 - azip.degrees()),
-        };
+        let ha = lst - ra;
+        let ((sde, cde), (sha, cha)) = (de.sincos(), ha.sincos());
+        // Rounding can take the sine past ±1 at the zenith, and the azimuth is undefined there, so it is taken as north
+        let alt = Angle::asin((sde * slat + cde * clat * cha).clamp(-1.0, 1.0));
+        let azi = Angle::atan2(-sha * cde, sde * clat - cde * slat * cha);
         (azi, alt)
     }
+    /// Azimuth and Altitude, checking that the arguments are in the domain of the conversion
+    ///
+    /// [`Coord::horizon()`] gives NaN for a non-finite argument, and a meaningless result for a latitude past ±90°,
+    /// this returns [`Error::OutOfDomain`] instead.
+    pub fn try_horizon(
+        self,
+        date: Date,
+        lati: Angle,
+        longi: Angle,
+    ) -> Result<(Angle, Angle), Error> {
+        let finite = [self.0, self.1.angle(), lati, longi]
+            .iter()
+            .all(|a| a.radians().is_finite());
+        if !finite || !date.julian().is_finite() || !is_latitude(lati) {
+            return Err(Error::OutOfDomain);
+        }
+        Ok(self.horizon(date, lati, longi))
+    }
+    /// Azimuth and Altitude for an [`Observer`]
+    pub fn horizon_for(self, obs: &Observer, date: Date) -> (Angle, Angle) {
+        self.horizon(date, obs.lat, obs.lon)
+    }
+    /// Altitude for an [`Observer`] as a signed [`Latitude`], negative below the horizon
+    /// ```
+    /// # use pracstro::{coord::{Coord, Observer}, time::{Angle, Date}};
+    /// let obs = Observer::new(Angle::from_degrees(51.5), Angle::default(), 0.0);
+    /// let polaris = Coord::from_equatorial(Angle::from_clock(2, 31, 49.1), Angle::from_degrees(89.26));
+    /// let sigma_octantis = Coord::from_equatorial(Angle::from_clock(21, 8, 46.9), Angle::from_degrees(-88.96));
+    /// assert!(polaris.altitude_for(&obs, Date::now()).degrees() > 0.0);
+    /// assert!(sigma_octantis.altitude_for(&obs, Date::now()).degrees() < 0.0);
+    /// ```
+    pub fn altitude_for(self, obs: &Observer, date: Date) -> Latitude {
+        self.horizon_for(obs, date).1.into()
+    }
     /// Azimuth and Altitude, dependent on location, and time
     ///
     /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
     pub fn from_horizon(azi: Angle, alt: Angle, date: Date, lati: Angle, longi: Angle) -> Self {
-        let de = Angle::asin(alt.sin() * lati.sin() + alt.cos() * lati.cos() * azi.cos());
-        let hap = Angle::acos((alt.sin() - lati.sin() * de.sin()) / (lati.cos() * de.cos()));
-        let ha = match azi.sin() < 0.0 {
-            true => hap,
-            false => Angle::from_degrees(360.0 - hap.degrees()),
-        };
+        let ((salt, calt), (slat, clat), (sazi, cazi)) =
+            (alt.sincos(), lati.sincos(), azi.sincos());
+        let de = Angle::asin((salt * slat + calt * clat * cazi).clamp(-1.0, 1.0));
+        let ha = Angle::atan2(-sazi * calt, salt * clat - calt * slat * cazi);
         Coord::from_equatorial(date.time().gst(date) + longi - ha, de)
     }
+    /// Azimuth and Altitude, checking that the arguments are in the domain of the conversion, see [`Coord::try_horizon()`]
+    pub fn try_from_horizon(
+        azi: Angle,
+        alt: Angle,
+        date: Date,
+        lati: Angle,
+        longi: Angle,
+    ) -> Result<Self, Error> {
+        let finite = [azi, alt, lati, longi]
+            .iter()
+            .all(|a| a.radians().is_finite());
+        if !finite || !date.julian().is_finite() || !is_latitude(lati) || !is_latitude(alt) {
+            return Err(Error::OutOfDomain);
+        }
+        Ok(Coord::from_horizon(azi, alt, date, lati, longi))
+    }
+    /// Azimuth and Altitude for an [`Observer`]
+    pub fn from_horizon_for(azi: Angle, alt: Angle, obs: &Observer, date: Date) -> Self {
+        Coord::from_horizon(azi, alt, date, obs.lat, obs.lon)
+    }
 
-    /// Used in solar calculations, based on the plane of the orbit of the earth
+    /// Hour angle, how far west of the meridian the coordinate is, dependent on longitude and time
     ///
-    /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
-    pub fn ecliptic(self, d: Date) -> (Angle, Angle) {
-        let (ra, de) = self.equatorial();
-        let e = mean_obliquity_ecl(d);
-        let beta = Angle::asin(de.sin() * e.cos() - de.cos() * e.sin() * ra.sin());
-        let y = ra.sin() * e.cos() + de.tan() * e.sin();
-        let x = ra.cos();
-        let lambda = Angle::atan2(y, x);
-        (lambda, beta)
+    /// Together with the declination, this is the position in the sky for an equatorial mount.
+    /// The local sidereal time is from [`Date::lst()`].
+    pub fn hour_angle(self, date: Date, longi: Angle) -> Angle {
+        date.lst(longi) - self.0
     }
-    /// Used in solar calculations, based on the plane of the orbit of the earth
+    /// Hour angle and Declination, dependent on longitude and time
+    pub fn from_hour_angle(ha: Angle, de: Angle, date: Date, longi: Angle) -> Self {
+        Coord::from_equatorial(date.lst(longi) - ha, de)
+    }
+
+    /// Ecliptic longitude and latitude, referred to the mean ecliptic and equinox of `d`
+    ///
+    /// Used in solar calculations, based on the plane of the orbit of the earth.
     ///
     /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
+    pub fn to_ecliptic(self, d: Date) -> Coord<Ecliptic> {
+        self.ecliptic_obliquity(d, mean_obliquity_ecl(d).sincos())
+    }
+    /// Ecliptic longitude and latitude of many coordinates at once
+    ///
+    /// The same as calling [`Coord::to_ecliptic()`] on each, but the obliquity of the ecliptic is only computed once.
+    pub fn ecliptic_batch(coords: &[Coord], d: Date) -> Vec<Coord<Ecliptic>> {
+        let e = mean_obliquity_ecl(d).sincos();
+        coords.iter().map(|c| c.ecliptic_obliquity(d, e)).collect()
+    }
+    /// Ecliptic longitude and latitude from the sine and cosine of the obliquity of the ecliptic at `d`
+    fn ecliptic_obliquity(self, d: Date, (se, ce): (f64, f64)) -> Coord<Ecliptic> {
+        let (ra, de) = self.equatorial();
+        let ((sra, cra), (sde, cde)) = (ra.sincos(), de.sincos());
+        let beta = Angle::asin(sde * ce - cde * se * sra);
+        let y = sra * ce + de.tan() * se;
+        let lambda = Angle::atan2(y, cra);
+        Coord::new_ecliptic(lambda, beta, d)
+    }
+    /// Ecliptic longitude and latitude, see [`Coord::to_ecliptic()`]
+    #[deprecated(
+        note = "use `Coord::to_ecliptic()`, which can't be mistaken for equatorial coordinates"
+    )]
+    pub fn ecliptic(self, d: Date) -> (Angle, Angle) {
+        let c = self.to_ecliptic(d);
+        (c.lon(), c.lat().angle())
+    }
+    /// Ecliptic longitude and latitude, see [`Coord::new_ecliptic()`] and [`Coord::<Ecliptic>::to_equatorial()`]
+    #[deprecated(note = "use `Coord::new_ecliptic(lambda, beta, d).to_equatorial()`")]
     pub fn from_ecliptic(lambda: Angle, beta: Angle, d: Date) -> Self {
-        let e = mean_obliquity_ecl(d);
-        let de = Angle::asin(beta.sin() * e.cos() + beta.cos() * e.sin() * lambda.sin());
-        let ra = Angle::atan2(lambda.sin() * e.cos() - beta.tan() * e.sin(), lambda.cos());
-        Coord::from_equatorial(ra, de)
+        Coord::new_ecliptic(lambda, beta, d).to_equatorial()
+    }
+
+    /// Galactic longitude and latitude, from J2000 equatorial coordinates
+    ///
+    /// Uses the IAU definition of the north galactic pole, and the galactic longitude of the north celestial pole.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Chapter 13
+    pub fn to_galactic(self) -> Coord<Galactic> {
+        let (ra, de) = self.equatorial();
+        let ((sde, cde), (sg, cg), (sh, ch)) = (
+            de.sincos(),
+            GALACTIC_POLE.1.angle().sincos(),
+            (ra - GALACTIC_POLE.0).sincos(),
+        );
+        let b = Angle::asin(sde * sg + cde * cg * ch);
+        let l = GALACTIC_NCP_LONGITUDE - Angle::atan2(cde * sh, sde * cg - cde * sg * ch);
+        Coord::new(l, b)
+    }
+    /// Galactic longitude and latitude, see [`Coord::to_galactic()`]
+    #[deprecated(
+        note = "use `Coord::to_galactic()`, which can't be mistaken for equatorial coordinates"
+    )]
+    pub fn galactic(self) -> (Angle, Angle) {
+        let c = self.to_galactic();
+        (c.lon(), c.lat().angle())
+    }
+    /// Galactic longitude and latitude, see [`Coord::<Galactic>::to_equatorial()`]
+    #[deprecated(note = "use `Coord::<Galactic>::new(l, b).to_equatorial()`")]
+    pub fn from_galactic(l: Angle, b: Angle) -> Self {
+        Coord::<Galactic>::new(l, b).to_equatorial()
     }
 
     /// Convert 3D Rectangular Coordinates to 2D Polar Coordinates
@@ -109,34 +810,119 @@ impl Coord {
     }
 
     /// Convert 2D Polar into 3D rectangular, depends on distance
-    pub fn cartesian(self, dist: f64) -> (f64, f64, f64) {
-        let (lat, long) = self.equatorial();
+    pub fn cartesian(self, dist: f64) -> Vector3 {
+        let (long, lat) = self.equatorial();
         let x = dist * lat.cos() * long.cos();
         let y = dist * lat.cos() * long.sin();
         let z = dist * lat.sin();
 
-        (x, y, z)
+        Vector3::new(x, y, z)
     }
 
-    /// Returns the angle between two objects
-    pub fn dist(self, from: Self) -> Angle {
-        let ((a1, d1), (a2, d2)) = (self.equatorial(), from.equatorial());
-        Angle::acos(d1.sin() * d2.sin() + d1.cos() * d2.cos() * (a1 - a2).cos())
-    }
     /// Returns (Rise, Set) UT, This function will fail for locations in the sky that never appear over the horizon
     ///
     /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
+    ///
+    /// This is the geometric rise and set, see [`Coord::riseset_altitude()`] to account for refraction
     pub fn riseset(self, date: Date, lati: Angle, longi: Angle) -> Option<(Angle, Angle)> {
+        self.riseset_altitude(date, lati, longi, Angle::default())
+    }
+    /// Returns (Rise, Set) UT, for when the coordinate crosses the altitude `h0`
+    ///
+    /// Common values of `h0` are [`H0_STAR`], [`H0_SUN`], and the twilights [`H0_CIVIL`], [`H0_NAUTICAL`], and [`H0_ASTRONOMICAL`].
+    /// This function will fail for locations in the sky that never cross that altitude.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 15
+    pub fn riseset_altitude(
+        self,
+        date: Date,
+        lati: Angle,
+        longi: Angle,
+        h0: Angle,
+    ) -> Option<(Angle, Angle)> {
         let (ra, de) = self.equatorial();
-        let ar = Angle::acos(de.sin() / lati.cos());
-        let h = Angle::acos(-lati.tan() * de.tan());
-        if h.radians().is_nan() || ar.radians().is_nan() {
+        let h = Angle::acos((h0.sin() - lati.sin() * de.sin()) / (lati.cos() * de.cos()));
+        if h.radians().is_nan() {
             return None;
         }
         let lsts = (ra - h - longi).ungst(date);
         let lstr = (ra + h - longi).ungst(date);
         Some((lsts, lstr))
     }
+    /// Returns (Rise, Set) UT for an [`Observer`], see [`Coord::riseset()`]
+    pub fn riseset_for(self, obs: &Observer, date: Date) -> Option<(Angle, Angle)> {
+        self.riseset(date, obs.lat, obs.lon)
+    }
+    /// Rise, transit, and set on the UT day of `date`, with the azimuths of rising and setting
+    ///
+    /// The horizon is lowered by refraction, [`H0_STAR`], and by the dip of the horizon from the observers elevation.
+    /// Since the coordinate is fixed, this is exact for stars, but not for moving bodies such as the moon.
+    /// ```
+    /// # use pracstro::{coord::{Coord, NoRiseSet, Observer}, time::{Angle, Date}};
+    /// let obs = Observer::new(Angle::from_degrees(50.0), Angle::default(), 0.0);
+    /// let polaris = Coord::from_equatorial(Angle::from_clock(2, 31, 49.0), Angle::from_degrees(89.26));
+    /// assert_eq!(polaris.rise_set_transit(Date::now(), &obs), Err(NoRiseSet::Circumpolar));
+    /// ```
+    pub fn rise_set_transit(self, date: Date, obs: &Observer) -> Result<RiseSet, NoRiseSet> {
+        self.rise_set_transit_altitude(date, obs, H0_STAR - obs.dip())
+    }
+    /// Rise, transit, and set on the UT day of `date`, for when the coordinate crosses the altitude `h0`
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 15
+    pub fn rise_set_transit_altitude(
+        self,
+        date: Date,
+        obs: &Observer,
+        h0: Angle,
+    ) -> Result<RiseSet, NoRiseSet> {
+        let (ra, de) = self.equatorial();
+        let ((sde, cde), (slat, clat), sh0) = (de.sincos(), obs.lat.sincos(), h0.sin());
+        let cos_h = (sh0 - slat * sde) / (clat * cde);
+        if cos_h < -1.0 {
+            return Err(NoRiseSet::Circumpolar);
+        } else if cos_h > 1.0 || cos_h.is_nan() {
+            return Err(NoRiseSet::NeverRises);
+        }
+        let h = Angle::acos(cos_h);
+        let az = Angle::acos((sde - slat * sh0) / (clat * h0.cos()));
+        let day = Date::from_time(date, Angle::default());
+        let at = |lst: Angle| Date::from_time(day, (lst - obs.lon).ungst(day));
+        Ok(RiseSet {
+            rise: at(ra - h),
+            transit: at(ra),
+            set: at(ra + h),
+            rise_az: az,
+            set_az: -az,
+        })
+    }
+
+    /// The geographic latitude and longitude where this coordinate is at the zenith
+    ///
+    /// For the sun or moon, this is the subsolar or sublunar point. The latitude is geocentric,
+    /// [`geocentric_to_geodetic()`] gives the latitude of a map. The longitude is signed as from
+    /// [`Angle::to_latitude()`], positive to the east.
+    /// ```
+    /// # use pracstro::{sol, time::Date};
+    /// let now = Date::now();
+    /// let (lat, lon) = sol::SUN.location(now).sub_point(now);
+    /// assert!(-90.0 <= lat.degrees() && lat.degrees() <= 90.0);
+    /// assert!(-180.0 < lon.degrees() && lon.degrees() <= 180.0);
+    /// ```
+    pub fn sub_point(self, date: Date) -> (Latitude, Angle) {
+        (self.1, (self.0 - date.gst()).to_latitude())
+    }
+
+    /// The constellation containing this coordinate, for coordinates of the equinox of `epoch`
+    ///
+    /// ```
+    /// # use pracstro::{coord::Coord, constellation::Constellation, time::{self, Angle}};
+    /// let sirius = Coord::from_equatorial(Angle::from_clock(6, 45, 9.0), Angle::from_degminsec(-16, 42, 58.0));
+    /// assert_eq!(sirius.constellation(time::J2000), Constellation::CanisMajor);
+    /// ```
+    pub fn constellation(self, epoch: Date) -> crate::constellation::Constellation {
+        let c = self.precess_rigorous(epoch, Date::from_besselian(1875.0));
+        crate::constellation::Constellation::from_b1875(c.0.decimal(), c.declination().degrees())
+    }
 
     /// (Roughly) Accounts for precession in coordinates.
     pub fn precess(self, epoch: Date, d: Date) -> Self {
@@ -153,6 +939,303 @@ impl Coord {
             de + Angle::from_degrees(deltade * diff),
         )
     }
+
+    /// Accounts for precession between two epochs with rotation matrices
+    ///
+    /// Unlike [`Coord::precess()`], this is accurate near the poles and over spans of centuries.
+    /// The position is rotated from the mean equator and equinox of `from` to J2000, then to that of `to`.
+    ///
+    /// Uses the IAU 2006 (P03) precession angles, from Capitaine et al. 2003
+    pub fn precess_rigorous(self, from: Date, to: Date) -> Self {
+        let v = self.cartesian(1.0);
+        v.unrotate(precession_matrix(from))
+            .rotate(precession_matrix(to))
+            .into()
+    }
+
+    /// Corrects a mean position of date for nutation, giving the apparent position without aberration
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Chapter 23
+    pub fn nutate(self, d: Date) -> Self {
+        let (ra, de) = self.equatorial();
+        let (dpsi, deps) = nutation(d);
+        let (dpsi, deps) = (dpsi.to_latitude().radians(), deps.to_latitude().radians());
+        let ((sra, cra), (se, ce)) = (ra.sincos(), true_obliquity_ecl(d).sincos());
+        let dra = (ce + se * sra * de.tan()) * dpsi - cra * de.tan() * deps;
+        let dde = se * cra * dpsi + sra * deps;
+        Coord::from_equatorial(ra + Angle::from_radians(dra), de + Angle::from_radians(dde))
+    }
+
+    /// Converts a mean position in the B1950 (FK4) frame to the J2000 (FK5) frame
+    ///
+    /// This removes the E-terms of aberration and rotates between the frames, neglecting proper motion.
+    /// Older catalogs are in this frame, and precession alone is not enough to move between them.
+    ///
+    /// From the Explanatory Supplement to the Astronomical Almanac
+    pub fn b1950_to_j2000(self) -> Self {
+        let r = self.cartesian(1.0);
+        let r = r - FK4_E_TERMS + r * r.dot(FK4_E_TERMS);
+        r.rotate(FK4_TO_FK5).into()
+    }
+    /// Converts a mean position in the J2000 (FK5) frame to the B1950 (FK4) frame
+    ///
+    /// The inverse of [`Coord::b1950_to_j2000()`], adding back the E-terms of aberration.
+    pub fn j2000_to_b1950(self) -> Self {
+        let r = self.cartesian(1.0).unrotate(FK4_TO_FK5);
+        (r + FK4_E_TERMS - r * r.dot(FK4_E_TERMS)).into()
+    }
+    /// Converts a position in the J2000 (FK5) frame to the ICRS
+    ///
+    /// The frames differ by a constant rotation of a few tens of milliarcseconds, which only matters when
+    /// comparing to catalogs in the ICRS, such as Gaia.
+    pub fn to_icrs(self) -> Self {
+        self.cartesian(1.0).unrotate(FRAME_BIAS).into()
+    }
+    /// Converts a position in the ICRS to the J2000 (FK5) frame, the inverse of [`Coord::to_icrs()`]
+    pub fn from_icrs(self) -> Self {
+        self.cartesian(1.0).rotate(FRAME_BIAS).into()
+    }
+}
+
+impl<F: Frame> Coord<F> {
+    /// Longitude in the frame of the coordinate, such as the right ascension or ecliptic longitude
+    pub const fn lon(self) -> Angle {
+        self.0
+    }
+    /// Latitude in the frame of the coordinate, such as the declination or ecliptic latitude
+    pub const fn lat(self) -> Latitude {
+        self.1
+    }
+
+    /// Checks if both angles of two coordinates are within a tolerance of each other
+    pub fn approx_eq(self, other: Self, tolerance: Angle) -> bool {
+        self.0.approx_eq(other.0, tolerance) && self.1.approx_eq(other.1, tolerance)
+    }
+
+    /// Returns the angle between two objects, which must be in the same frame
+    ///
+    /// Ecliptic coordinates should also be referred to the same equinox, see [`Coord::<Ecliptic>::equinox()`].
+    ///
+    /// Uses the Vincenty formula, which unlike the law of cosines keeps its precision for separations of
+    /// milliarcseconds, as well as for nearly opposite points.
+    pub fn dist(self, from: Self) -> Angle {
+        let ((sd1, cd1), (sd2, cd2), (sda, cda)) = (
+            self.1.angle().sincos(),
+            from.1.angle().sincos(),
+            (self.0 - from.0).sincos(),
+        );
+        let (x, y) = (cd2 * sda, cd1 * sd2 - sd1 * cd2 * cda);
+        Angle::atan2(x.hypot(y), sd1 * sd2 + cd1 * cd2 * cda)
+    }
+
+    /// The rate of change in longitude and latitude, moving from `self` to `later` over `dt`
+    ///
+    /// The rate in longitude is along the coordinate, and isn't scaled by the cosine of the latitude.
+    /// ```
+    /// # use pracstro::{coord::Coord, time::Angle};
+    /// let (a, b) = (
+    ///     Coord::from_equatorial(Angle::from_degrees(10.0), Angle::from_degrees(5.0)),
+    ///     Coord::from_equatorial(Angle::from_degrees(10.5), Angle::from_degrees(4.9)),
+    /// );
+    /// let (ra, de) = a.rate(b, std::time::Duration::from_secs(3600));
+    /// assert!((ra.degrees_per_day() - 12.0).abs() < 1e-9);
+    /// assert!((de.degrees_per_day() + 2.4).abs() < 1e-9);
+    /// ```
+    pub fn rate(self, later: Self, dt: std::time::Duration) -> (AngularRate, AngularRate) {
+        let secs = dt.as_secs_f64();
+        (
+            AngularRate::from_radians_per_second(later.0.signed_diff(self.0).radians() / secs),
+            AngularRate::from_radians_per_second((later.1.radians() - self.1.radians()) / secs),
+        )
+    }
+
+    /// Interpolates along the great circle between two coordinates, `t` of the way from `self` to `other`
+    ///
+    /// The path between opposite points isn't defined, so the result is `self`.
+    pub fn slerp(self, other: Self, t: f64) -> Self {
+        let (a, b, omega) = (self.unit(), other.unit(), self.dist(other).radians());
+        let so = omega.sin();
+        if so.abs() < 1e-12 {
+            return self;
+        }
+        let v = a * (((1.0 - t) * omega).sin() / so) + b * ((t * omega).sin() / so);
+        let lat = Angle::asin(v.z / v.norm());
+        Coord(
+            Angle::atan2(v.y, v.x),
+            Latitude::from_radians(lat.radians()),
+            self.2,
+        )
+    }
+    /// `n` evenly spaced points along the great circle from `self` to `other`, including both ends
+    ///
+    /// Useful for drawing the line between two stars, see [`Coord::slerp()`]
+    pub fn waypoints(self, other: Self, n: usize) -> Vec<Self> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self],
+            _ => (0..n)
+                .map(|i| self.slerp(other, i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+    /// Unit vector in the frame of the coordinate
+    fn unit(self) -> Vector3 {
+        let ((slon, clon), (slat, clat)) = (self.0.sincos(), self.1.angle().sincos());
+        Vector3::new(clat * clon, clat * slon, slat)
+    }
+}
+impl Coord<Ecliptic> {
+    /// Ecliptic longitude and latitude, referred to the mean ecliptic and equinox of `equinox`
+    pub fn new_ecliptic(lon: Angle, lat: Angle, equinox: Date) -> Self {
+        Coord(
+            lon,
+            Latitude::from_radians(lat.radians()),
+            Ecliptic(equinox),
+        )
+    }
+    /// The date of the ecliptic and equinox the coordinate is referred to
+    pub fn equinox(self) -> Date {
+        self.2 .0
+    }
+    /// Converts back to equatorial coordinates, of the same equinox
+    ///
+    /// From Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
+    pub fn to_equatorial(self) -> Coord {
+        let (lambda, beta, e) = (self.0, self.1.angle(), mean_obliquity_ecl(self.equinox()));
+        let de = Angle::asin(beta.sin() * e.cos() + beta.cos() * e.sin() * lambda.sin());
+        let ra = Angle::atan2(lambda.sin() * e.cos() - beta.tan() * e.sin(), lambda.cos());
+        Coord::from_equatorial(ra, de)
+    }
+}
+impl Coord<Galactic> {
+    /// Galactic longitude and latitude
+    pub fn new(l: Angle, b: Angle) -> Self {
+        Coord(l, Latitude::from_radians(b.radians()), Galactic)
+    }
+    /// Converts back to J2000 equatorial coordinates
+    pub fn to_equatorial(self) -> Coord {
+        let (l, b) = (self.0, self.1.angle());
+        let ((sb, cb), (sg, cg), (sh, ch)) = (
+            b.sincos(),
+            GALACTIC_POLE.1.angle().sincos(),
+            (GALACTIC_NCP_LONGITUDE - l).sincos(),
+        );
+        let de = Angle::asin(sb * sg + cb * cg * ch);
+        let ra = GALACTIC_POLE.0 + Angle::atan2(cb * sh, sb * cg - cb * sg * ch);
+        Coord::from_equatorial(ra, de)
+    }
+}
+impl<F: Frame> std::fmt::Debug for Coord<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_tuple("Coord")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+/// The coordinate system a [`Coord`] is in
+///
+/// A coordinate can only be converted to another frame through its methods,
+/// so comparing or measuring between coordinates in different frames fails to compile:
+/// ```compile_fail
+/// # use pracstro::{coord::Coord, time::{self, Angle}};
+/// let c = Coord::from_equatorial(Angle::from_degrees(10.0), Angle::from_degrees(20.0));
+/// c.dist(c.to_ecliptic(time::J2000));
+/// ```
+pub trait Frame: Copy + Default + PartialEq + std::fmt::Debug {}
+/// Right ascension and declination, the default frame of [`Coord`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Equatorial;
+/// Ecliptic longitude and latitude, referred to the ecliptic and equinox of a date
+///
+/// Ecliptic coordinates are made with [`Coord::new_ecliptic()`] or [`Coord::to_ecliptic()`], which both take the equinox.
+/// **The default, as in `Coord::<Ecliptic>::default()`, is the equinox of J2000.**
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ecliptic(Date);
+impl Default for Ecliptic {
+    fn default() -> Self {
+        Ecliptic(J2000)
+    }
+}
+/// Galactic longitude and latitude
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Galactic;
+impl Frame for Equatorial {}
+impl Frame for Ecliptic {}
+impl Frame for Galactic {}
+
+/// Equatorial coordinates, the precision of declination defaults to 1 decimal place
+impl std::fmt::Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.fmt_equatorial(f.precision().unwrap_or(1)))
+    }
+}
+
+/// Serialized as the pair of the right ascension and declination, in radians
+#[cfg(feature = "serde")]
+impl serde::Serialize for Coord {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.0, self.1), s)
+    }
+}
+/// Deserialized from a pair of the right ascension and declination, in radians
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Coord {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        <(Angle, Latitude)>::deserialize(d).map(|(ra, de)| Coord(ra, de, Equatorial))
+    }
+}
+/// Serialized as the longitude and latitude in radians, and the equinox
+#[cfg(feature = "serde")]
+impl serde::Serialize for Coord<Ecliptic> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.0, self.1, self.equinox()), s)
+    }
+}
+/// Deserialized from the longitude and latitude in radians, and the equinox
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Coord<Ecliptic> {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        <(Angle, Latitude, Date)>::deserialize(d).map(|(l, b, e)| Coord(l, b, Ecliptic(e)))
+    }
+}
+/// Serialized as the pair of the galactic longitude and latitude, in radians
+#[cfg(feature = "serde")]
+impl serde::Serialize for Coord<Galactic> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&(self.0, self.1), s)
+    }
+}
+/// Deserialized from a pair of the galactic longitude and latitude, in radians
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Coord<Galactic> {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        <(Angle, Latitude)>::deserialize(d).map(|(l, b)| Coord(l, b, Galactic))
+    }
+}
+
+/// Compares both angles of the coordinates, with the epsilon in radians
+#[cfg(feature = "approx")]
+impl<F: Frame> approx::AbsDiffEq for Coord<F> {
+    type Epsilon = f64;
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+            && self.1.angle().abs_diff_eq(&other.1.angle(), epsilon)
+    }
+}
+#[cfg(feature = "approx")]
+impl<F: Frame> approx::RelativeEq for Coord<F> {
+    fn default_max_relative() -> f64 {
+        f64::EPSILON
+    }
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+            && (self.1.angle()).relative_eq(&other.1.angle(), epsilon, max_relative)
+    }
 }
 
 #[cfg(test)]
@@ -173,40 +1256,28 @@ mod tests {
             Angle::from_clock(6, 46, 13.1),
             Angle::from_degminsec(-16, 45, 06.8),
         );
-        assert_eq!(
-            arcturus.horizon(
-                Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0)),
-                Angle::from_degrees(55.47885),
-                Angle::from_degrees(133.94531)
-            ),
-            (
-                Angle::from_degminsec(220, 39, 16.2),
-                Angle::from_degminsec(48, 6, 46.1)
-            )
+        let (azi, alt) = arcturus.horizon(
+            Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0)),
+            Angle::from_degrees(55.47885),
+            Angle::from_degrees(133.94531),
         );
-        assert_eq!(
-            sirius.horizon(
-                Date::from_calendar(2025, 3, 7, Angle::from_clock(23, 36, 52.0)),
-                Angle::from_degrees(5.0),
-                Angle::from_degrees(-1.0)
-            ),
-            (
-                Angle::from_degminsec(249, 37, 18.2),
-                Angle::from_degminsec(28, 34, 54.8)
-            )
+        assert_approx_eq!(azi, Angle::from_degminsec(220, 39, 16.2));
+        assert_approx_eq!(alt, Angle::from_degminsec(48, 6, 46.1));
+        let (azi, alt) = sirius.horizon(
+            Date::from_calendar(2025, 3, 7, Angle::from_clock(23, 36, 52.0)),
+            Angle::from_degrees(5.0),
+            Angle::from_degrees(-1.0),
         );
-        assert_eq!(
-            sirius.horizon(
-                Date::from_calendar(2025, 3, 11, Angle::from_clock(2, 0, 0.0)),
-                Angle::from_degrees(44.8714),
-                Angle::from_degrees(-93.20801)
-            ),
-            (
-                Angle::from_degminsec(184, 47, 2.3),
-                Angle::from_degminsec(29, 45, 27.2)
-            )
+        assert_approx_eq!(azi, Angle::from_degminsec(249, 37, 18.2));
+        assert_approx_eq!(alt, Angle::from_degminsec(28, 34, 54.8));
+        let (azi, alt) = sirius.horizon(
+            Date::from_calendar(2025, 3, 11, Angle::from_clock(2, 0, 0.0)),
+            Angle::from_degrees(44.8714),
+            Angle::from_degrees(-93.20801),
         );
-        assert_eq!(
+        assert_approx_eq!(azi, Angle::from_degminsec(184, 47, 2.3));
+        assert_approx_eq!(alt, Angle::from_degminsec(29, 45, 27.2));
+        assert_approx_eq!(
             Coord::from_horizon(
                 Angle::from_degminsec(184, 47, 2.3),
                 Angle::from_degminsec(29, 45, 27.2),
@@ -216,7 +1287,313 @@ mod tests {
             ),
             sirius
         );
-        assert_eq!(sirius.dist(arcturus), Angle::from_degminsec(115, 55, 5.17));
+        assert_approx_eq!(sirius.dist(arcturus), Angle::from_degminsec(115, 55, 5.17));
+    }
+
+    #[test]
+    fn test_declination() {
+        let sirius =
+            Coord::from_equatorial(Angle::from_clock(6, 46, 13.1), Angle::from_degrees(-16.75));
+        assert_approx_eq!(sirius.declination(), Latitude::from_degrees(-16.75));
+        assert!(sirius.declination().degrees() < 0.0);
+        assert_approx_eq!(sirius.equatorial().1, Angle::from_degrees(343.25));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let sirius =
+            Coord::from_equatorial(Angle::from_clock(6, 46, 13.1), Angle::from_degrees(-16.75));
+        let json = serde_json::to_string(&sirius).unwrap();
+        assert_eq!(serde_json::from_str::<Coord>(&json).unwrap(), sirius);
+        // The other frames too, with the equinox of ecliptic coordinates, to the precision serde_json reads floats to
+        let tol = crate::angle!(0:0:0.000001);
+        let ecl = sirius.to_ecliptic(crate::time::B1950);
+        let back: Coord<Ecliptic> =
+            serde_json::from_str(&serde_json::to_string(&ecl).unwrap()).unwrap();
+        assert_approx_eq!(back, ecl, tol);
+        assert_eq!(back.equinox(), crate::time::B1950);
+        assert!(
+            serde_json::from_str::<Coord<Ecliptic>>(&serde_json::to_string(&sirius).unwrap())
+                .is_err()
+        );
+        let gal = sirius.to_galactic();
+        let back: Coord<Galactic> =
+            serde_json::from_str(&serde_json::to_string(&gal).unwrap()).unwrap();
+        assert_approx_eq!(back, gal, tol);
+    }
+
+    #[test]
+    fn test_format() {
+        let sirius =
+            Coord::from_equatorial(Angle::from_clock(6, 46, 13.1), Angle::from_degrees(-16.75));
+        assert_eq!(sirius.fmt_equatorial(0), "6h46m13.1s -16°45'00\"");
+        assert_eq!(
+            format!(
+                "{}",
+                Coord::from_equatorial(
+                    Angle::from_clock(14, 16, 50.0),
+                    Angle::from_degminsec(19, 2, 50.1)
+                )
+            ),
+            "14h16m50.00s +19°02'50.1\""
+        );
+    }
+
+    #[test]
+    fn test_extinction() {
+        assert!((airmass(crate::angle!(90)) - 1.0).abs() < 1e-3);
+        assert!((airmass(crate::angle!(30)) - 2.0).abs() < 0.01);
+        assert!((airmass(Angle::default()) - 38.0).abs() < 0.1);
+        assert_eq!(airmass(crate::angle!(-5)), airmass(Angle::default()));
+        assert!((extinction(crate::angle!(90)) - 0.2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_refraction() {
+        // Meeus 16.a, a true altitude of 0°33'14.76" is raised 24'37"
+        let r = refraction(Angle::from_degminsec(0, 33, 14.76));
+        assert_approx_eq!(r, Angle::from_degminsec(0, 24, 37.0), crate::angle!(0:0:30));
+        assert_approx_eq!(
+            refraction(crate::angle!(90)),
+            Angle::default(),
+            crate::angle!(0:0:1)
+        );
+        assert_approx_eq!(
+            refraction(crate::angle!(-30)),
+            refraction(crate::angle!(-1)),
+            crate::angle!(0:0:0.01)
+        );
+        // The same formula as Angle::refractdelta(), which now takes negative altitudes as such
+        for h in [
+            crate::angle!(-0:30:0),
+            crate::angle!(0:33:14.76),
+            crate::angle!(45),
+        ] {
+            assert_eq!(refraction(h), h.refractdelta());
+        }
+    }
+
+    #[test]
+    fn test_observer() {
+        let obs = Observer::new(
+            Angle::from_degrees(55.47885),
+            Angle::from_degrees(133.94531),
+            100.0,
+        );
+        let date = Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0));
+        let arcturus = Coord::from_equatorial(
+            Angle::from_clock(14, 16, 50.0),
+            Angle::from_degminsec(19, 2, 50.1),
+        );
+        let (azi, alt) = arcturus.horizon_for(&obs, date);
+        assert_eq!((azi, alt), arcturus.horizon(date, obs.lat, obs.lon));
+        assert_approx_eq!(
+            Coord::from_horizon_for(azi, alt, &obs, date),
+            arcturus,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+        assert_eq!(
+            arcturus.riseset_for(&obs, date),
+            arcturus.riseset(date, obs.lat, obs.lon)
+        );
+        assert_approx_eq!(obs.dip(), Angle::from_degminsec(0, 17, 35.0));
+        assert_eq!(obs.lst(date), date.lst(obs.lon));
+    }
+
+    #[test]
+    fn test_geocentric() {
+        // Palomar, Meeus example 11.a
+        let palomar = Observer::new(
+            Angle::from_degminsec(33, 21, 22.0),
+            Angle::from_degminsec(-116, 51, 47.0),
+            1706.0,
+        );
+        let (rs, rc) = palomar.parallax_terms();
+        assert!((rs - 0.546861).abs() < 1e-5);
+        assert!((rc - 0.836339).abs() < 1e-5);
+        let (lat, rho) = palomar.geocentric();
+        assert_approx_eq!(
+            lat,
+            Angle::from_degminsec(33, 10, 47.0),
+            crate::angle!(0:0:2)
+        );
+        assert!((rho - 0.999_1).abs() < 1e-3);
+        // On the surface, the conversion goes back and forth
+        let (lat, _) = geodetic_to_geocentric(Angle::from_degrees(-45.0), 0.0);
+        assert_approx_eq!(
+            geocentric_to_geodetic(lat),
+            Angle::from_degrees(-45.0),
+            crate::angle!(0:0:0.001)
+        );
+        assert_approx_eq!(
+            geodetic_to_geocentric(crate::angle!(90), 0.0).0,
+            crate::angle!(90)
+        );
+        assert_approx_eq!(
+            geodetic_to_geocentric(crate::angle!(0), 0.0).0,
+            crate::angle!(0)
+        );
+    }
+
+    #[test]
+    fn test_ecef() {
+        let obs = Observer::new(crate::angle!(45), crate::angle!(-75), 100.0);
+        let v = obs.ecef();
+        assert!((v.norm() - 6_367_590.0).abs() < 100.0);
+        let back = Observer::from_ecef(v);
+        assert_approx_eq!(back.lat, obs.lat, crate::angle!(0:0:0.0001));
+        assert_approx_eq!(back.lon, obs.lon, crate::angle!(0:0:0.0001));
+        assert!((back.elevation_m - 100.0).abs() < 1e-3);
+        let pole =
+            Observer::from_ecef(Observer::new(crate::angle!(-90), crate::angle!(0), 10.0).ecef());
+        assert_approx_eq!(pole.lat, crate::angle!(-90));
+        assert!((pole.elevation_m - 10.0).abs() < 1e-3);
+        // Equator at Greenwich points at the sidereal time
+        let d = Date::from_calendar(2025, 3, 10, Angle::from_clock(5, 0, 0.0));
+        let c: Coord = Observer::new(crate::angle!(0), crate::angle!(0), 0.0)
+            .celestial(d)
+            .into();
+        assert_approx_eq!(c.equatorial().0, d.gmst(), crate::angle!(0:0:0.001));
+        assert_approx_eq!(
+            Coord::from(celestial_to_ecef(obs.celestial(d), d)),
+            Coord::from(v),
+            crate::angle!(0:0:0.001)
+        );
+    }
+
+    #[test]
+    fn test_sub_point() {
+        let d = Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0));
+        let arcturus = Coord::from_equatorial(
+            Angle::from_clock(14, 16, 50.0),
+            Angle::from_degminsec(19, 2, 50.1),
+        );
+        let (lat, lon) = arcturus.sub_point(d);
+        assert_approx_eq!(lat, Latitude::from(Angle::from_degminsec(19, 2, 50.1)));
+        let obs = Observer::new(lat.angle(), lon, 0.0);
+        assert_approx_eq!(
+            obs.lst(d),
+            arcturus.equatorial().0,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+        // The sun is overhead at the tropic of cancer on the june solstice
+        let solstice = Date::from_calendar(2025, 6, 21, Angle::from_clock(2, 42, 0.0));
+        let (lat, _) = crate::sol::SUN.location(solstice).sub_point(solstice);
+        assert_approx_eq!(
+            lat,
+            Latitude::from_degrees(23.44),
+            Angle::from_degrees(0.05)
+        );
+        // West of Greenwich is negative, south of the equator is too
+        let canopus = Coord::from_equatorial(
+            Angle::from_clock(6, 23, 57.1),
+            -Angle::from_degminsec(52, 41, 44.4),
+        );
+        let d = Date::from_calendar(2025, 1, 1, Angle::default());
+        let (lat, lon) = canopus.sub_point(d);
+        assert!(lat.degrees() < -52.0);
+        assert_approx_eq!(lon, (canopus.equatorial().0 - d.gst()).to_latitude());
+        assert!(lon.degrees() < 0.0 && lon.degrees() > -180.0);
+    }
+
+    #[test]
+    fn test_batch() {
+        let date = Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0));
+        let obs = Observer::new(
+            Angle::from_degrees(55.47885),
+            Angle::from_degrees(133.94531),
+            0.0,
+        );
+        let coords: Vec<Coord> = (0..24)
+            .map(|i| {
+                Coord::from_equatorial(
+                    Angle::from_clock(i, 30, 0.0),
+                    Angle::from_degrees(i as f64 * 7.0 - 80.0),
+                )
+            })
+            .collect();
+        let horiz = Coord::horizon_batch(&coords, date, &obs);
+        let ecl = Coord::ecliptic_batch(&coords, date);
+        for (i, c) in coords.iter().enumerate() {
+            assert_eq!(horiz[i], c.horizon_for(&obs, date));
+            assert_eq!(ecl[i], c.to_ecliptic(date));
+        }
+        assert!(Coord::horizon_batch(&[], date, &obs).is_empty());
+    }
+
+    #[test]
+    fn test_hour_angle() {
+        let date = Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0));
+        let longi = Angle::from_degrees(133.94531);
+        let arcturus = Coord::from_equatorial(
+            Angle::from_clock(14, 16, 50.0),
+            Angle::from_degminsec(19, 2, 50.1),
+        );
+        let ha = arcturus.hour_angle(date, longi);
+        assert_approx_eq!(
+            Coord::from_hour_angle(ha, arcturus.equatorial().1, date, longi),
+            arcturus,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+        // On the meridian, an hour later it is 15° to the west
+        let transit = Coord::from_hour_angle(Angle::default(), crate::angle!(10), date, longi);
+        let later = Date::from_calendar(2025, 3, 10, Angle::from_clock(20, 52, 25.0));
+        assert_approx_eq!(transit.hour_angle(later, longi), Angle::from_degrees(15.04));
+        assert_approx_eq!(
+            Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0)).lst(longi),
+            transit.equatorial().0
+        );
+    }
+
+    #[test]
+    fn test_horizon_domain() {
+        let date = Date::from_calendar(2025, 3, 10, Angle::from_clock(19, 52, 25.0));
+        let (lati, longi) = (
+            Angle::from_degrees(55.47885),
+            Angle::from_degrees(133.94531),
+        );
+        // At the zenith and at the pole, where rounding used to give NaN
+        let zenith = Coord::from_horizon(Angle::default(), crate::angle!(90), date, lati, longi);
+        let (azi, alt) = zenith.horizon(date, lati, longi);
+        assert!(azi.radians().is_finite());
+        assert_approx_eq!(alt, crate::angle!(90), crate::angle!(0:0:0.01));
+        let (azi, alt) = zenith.horizon(date, crate::angle!(90), longi);
+        assert!(azi.radians().is_finite() && alt.radians().is_finite());
+        let c = Coord::from_horizon(
+            crate::angle!(123),
+            crate::angle!(45),
+            date,
+            crate::angle!(-90),
+            longi,
+        );
+        assert!(c.equatorial().0.radians().is_finite());
+        assert_approx_eq!(
+            c.equatorial().1,
+            crate::angle!(-45),
+            crate::angle!(0:0:0.01)
+        );
+
+        assert_eq!(
+            zenith.try_horizon(date, lati, longi),
+            Ok(zenith.horizon(date, lati, longi))
+        );
+        assert_eq!(
+            zenith.try_horizon(date, crate::angle!(100), longi),
+            Err(Error::OutOfDomain)
+        );
+        assert_eq!(
+            zenith.try_horizon(date, Angle::from_radians(f64::NAN), longi),
+            Err(Error::OutOfDomain)
+        );
+        assert_eq!(
+            Coord::try_from_horizon(crate::angle!(10), crate::angle!(120), date, lati, longi),
+            Err(Error::OutOfDomain)
+        );
+        assert!(
+            Coord::try_from_horizon(crate::angle!(10), crate::angle!(-20), date, lati, longi)
+                .is_ok()
+        );
     }
 
     #[test]
@@ -225,14 +1602,15 @@ mod tests {
             Angle::from_clock(23, 39, 20.0),
             Angle::from_degminsec(21, 42, 00.0),
         );
-        assert_eq!(
-            c.riseset(
+        let (rise, set) = c
+            .riseset(
                 Date::from_calendar(1980, 8, 24, Angle::default()),
                 Angle::from_degrees(30.0),
-                Angle::from_degrees(64.0)
-            ),
-            Some((Angle::from_clock(14, 18, 9.0), Angle::from_clock(4, 6, 5.0)))
-        );
+                Angle::from_degrees(64.0),
+            )
+            .unwrap();
+        assert_approx_eq!(rise, Angle::from_clock(14, 18, 9.0));
+        assert_approx_eq!(set, Angle::from_clock(4, 6, 5.0));
         assert_eq!(
             c.riseset(
                 Date::from_calendar(1980, 8, 24, Angle::default()),
@@ -244,19 +1622,392 @@ mod tests {
     }
 
     #[test]
-    fn test_ecliptic() {
-        let star1 = Coord::from_equatorial(
-            Angle::from_clock(9, 34, 53.6),
-            Angle::from_degminsec(19, 32, 14.2),
+    fn test_riseset_altitude() {
+        let date = Date::from_calendar(1980, 8, 24, Angle::default());
+        let (lati, longi) = (Angle::from_degrees(30.0), Angle::from_degrees(64.0));
+        let c = Coord::from_equatorial(
+            Angle::from_clock(23, 39, 20.0),
+            Angle::from_degminsec(21, 42, 00.0),
         );
         assert_eq!(
-            star1.ecliptic(Date::from_calendar(1950, 0, 1, Angle::default())),
-            (
-                Angle::from_degminsec(139, 41, 10.0),
-                Angle::from_degminsec(4, 52, 31.0)
+            c.riseset_altitude(date, lati, longi, Angle::default()),
+            c.riseset(date, lati, longi)
+        );
+        let (rise, set) = c.riseset(date, lati, longi).unwrap();
+        let (rise_r, set_r) = c.riseset_altitude(date, lati, longi, H0_STAR).unwrap();
+        assert!(rise_r.signed_diff(rise).radians() < 0.0);
+        assert!(set_r.signed_diff(set).radians() > 0.0);
+        let (rise_t, set_t) = c.riseset_altitude(date, lati, longi, H0_CIVIL).unwrap();
+        assert!(rise_t.signed_diff(rise_r).radians() < 0.0);
+        assert!(set_t.signed_diff(set_r).radians() > 0.0);
+        // Culminates at 81.7° from this latitude
+        assert_eq!(
+            c.riseset_altitude(date, lati, longi, Angle::from_degrees(85.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rise_set_transit() {
+        let date = Date::from_calendar(1980, 8, 24, Angle::from_clock(15, 0, 0.0));
+        let obs = Observer::new(Angle::from_degrees(30.0), Angle::from_degrees(64.0), 0.0);
+        let c = Coord::from_equatorial(
+            Angle::from_clock(23, 39, 20.0),
+            Angle::from_degminsec(21, 42, 00.0),
+        );
+        let rs = c.rise_set_transit(date, &obs).unwrap();
+        let midnight = Date::from_time(date, Angle::default());
+        let (rise, set) = c
+            .riseset_altitude(midnight, obs.lat, obs.lon, H0_STAR)
+            .unwrap();
+        assert_approx_eq!(rs.rise.time(), rise, Angle::from_clock(0, 0, 0.1));
+        assert_approx_eq!(rs.set.time(), set, Angle::from_clock(0, 0, 0.1));
+        assert_eq!(rs.rise.calendar().2, 24);
+        assert_eq!(rs.set.calendar().2, 24);
+        // On the meridian at transit
+        assert_approx_eq!(obs.lst(rs.transit), c.equatorial().0);
+        // Rises in the north east and sets in the north west
+        assert_approx_eq!(rs.rise_az + rs.set_az, Angle::default());
+        assert!(rs.rise_az.degrees() > 45.0 && rs.rise_az.degrees() < 90.0);
+        assert_eq!(
+            c.rise_set_transit(
+                date,
+                &Observer::new(crate::angle!(80), Angle::default(), 0.0)
+            ),
+            Err(NoRiseSet::Circumpolar)
+        );
+        assert_eq!(
+            c.rise_set_transit(
+                date,
+                &Observer::new(crate::angle!(-80), Angle::default(), 0.0)
+            ),
+            Err(NoRiseSet::NeverRises)
+        );
+    }
+
+    #[test]
+    fn test_riseset_moving() {
+        let date = Date::from_calendar(2025, 3, 10, Angle::default());
+        let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+        let moon = crate::moon::MOON;
+        let rs = riseset_moving(|d| moon.location(d), date, &obs, H0_STAR).unwrap();
+        let altitude = |d: Date| {
+            let (ra, de) = moon.location(d).equatorial();
+            Angle::asin(
+                obs.lat.sin() * de.sin() + obs.lat.cos() * de.cos() * (obs.lst(d) - ra).cos(),
             )
+        };
+        assert_approx_eq!(altitude(rs.rise), H0_STAR);
+        assert_approx_eq!(altitude(rs.set), H0_STAR);
+        assert_approx_eq!(
+            obs.lst(rs.transit),
+            moon.location(rs.transit).equatorial().0
+        );
+        // The moon moves enough in a day that using its position at one time is minutes off
+        let fixed = moon
+            .location(date)
+            .rise_set_transit_altitude(date, &obs, H0_STAR)
+            .unwrap();
+        assert!((fixed.rise.julian() - rs.rise.julian()).abs() > 5.0 / 1440.0);
+        // Stationary coordinates are the same as the fixed solution
+        let c = Coord::from_equatorial(Angle::from_clock(5, 0, 0.0), Angle::from_degrees(10.0));
+        let still = riseset_moving(|_| c, date, &obs, H0_STAR).unwrap();
+        assert_eq!(
+            still,
+            c.rise_set_transit_altitude(date, &obs, H0_STAR).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let d = Date::from_calendar(2025, 3, 10, Angle::default());
+        let (a, b) = (
+            Coord::from_equatorial(Angle::from_clock(14, 16, 50.0), crate::angle!(19:2:50.1)),
+            Coord::from_equatorial(Angle::from_clock(13, 25, 12.0), crate::angle!(-11:9:41.0)),
+        );
+        let (ea, eb) = (a.to_ecliptic(d), b.to_ecliptic(d));
+        assert_eq!(ea.equinox(), d);
+        assert_approx_eq!(ea.to_equatorial(), a, crate::angle!(0:0:0.001));
+        assert_approx_eq!(ea.dist(eb), a.dist(b), crate::angle!(0:0:0.001));
+        // The equinox is kept, rather than taken from the date of the conversion back
+        let e2000 = a.to_ecliptic(J2000);
+        assert_ne!(
+            e2000,
+            Coord::new_ecliptic(e2000.lon(), e2000.lat().angle(), d)
+        );
+        assert_approx_eq!(e2000.to_equatorial(), a, crate::angle!(0:0:0.001));
+        assert_eq!(Coord::<Ecliptic>::default().equinox(), J2000);
+        let ga = a.to_galactic();
+        assert_approx_eq!(ga.to_equatorial(), a, crate::angle!(0:0:0.001));
+        // The tuples of the older methods are the same
+        #[allow(deprecated)]
+        {
+            assert_eq!((ea.lon(), ea.lat().angle()), a.ecliptic(d));
+            assert_eq!(
+                Coord::from_ecliptic(ea.lon(), ea.lat().angle(), d),
+                ea.to_equatorial()
+            );
+            assert_eq!((ga.lon(), ga.lat().angle()), a.galactic());
+            assert_eq!(
+                Coord::from_galactic(ga.lon(), ga.lat().angle()),
+                ga.to_equatorial()
+            );
+        }
+        assert_approx_eq!(ga.to_equatorial(), a, crate::angle!(0:0:0.001));
+        assert_approx_eq!(
+            Coord::<Galactic>::new(crate::angle!(10), crate::angle!(-5)).lat(),
+            Latitude::from_degrees(-5.0)
+        );
+    }
+
+    #[test]
+    fn test_dist() {
+        let a = Coord::from_equatorial(Angle::from_degrees(123.0), Angle::from_degrees(45.0));
+        for arcsec in [0.001, 1.0, 3600.0] {
+            let b = Coord::from_equatorial(
+                Angle::from_degrees(123.0),
+                Angle::from_degrees(45.0 + arcsec / 3600.0),
+            );
+            let d = a.dist(b).degrees() * 3600.0;
+            assert!((d - arcsec).abs() < arcsec * 1e-6, "{d} {arcsec}");
+        }
+        // Small separations in right ascension shrink with the cosine of declination
+        let b = Coord::from_equatorial(
+            Angle::from_degrees(123.0 + 0.01 / 3600.0),
+            Angle::from_degrees(45.0),
+        );
+        assert!((a.dist(b).degrees() * 3600.0 - 0.01 * 0.5_f64.sqrt()).abs() < 1e-8);
+        let opposite =
+            Coord::from_equatorial(Angle::from_degrees(303.0), Angle::from_degrees(-45.0));
+        assert_approx_eq!(
+            a.dist(opposite),
+            Angle::from_degrees(180.0),
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+    }
+
+    #[test]
+    fn test_rate() {
+        let hour = std::time::Duration::from_secs(3600);
+        let (a, b) = (
+            Coord::from_equatorial(Angle::from_degrees(359.9), Angle::from_degrees(5.0)),
+            Coord::from_equatorial(Angle::from_degrees(0.4), Angle::from_degrees(4.9)),
+        );
+        let (ra, de) = a.rate(b, hour);
+        assert!((ra.degrees_per_day() - 12.0).abs() < 1e-9);
+        assert!((de.degrees_per_day() + 2.4).abs() < 1e-9);
+        let (ra, _) = b.rate(a, hour);
+        assert!((ra.degrees_per_day() + 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let (a, b) = (
+            Coord::from_equatorial(Angle::from_degrees(350.0), Angle::from_degrees(10.0)),
+            Coord::from_equatorial(Angle::from_degrees(20.0), Angle::from_degrees(40.0)),
+        );
+        assert_approx_eq!(a.slerp(b, 0.0), a, Angle::from_degminsec(0, 0, 0.001));
+        assert_approx_eq!(a.slerp(b, 1.0), b, Angle::from_degminsec(0, 0, 0.001));
+        let mid = a.slerp(b, 0.5);
+        assert_approx_eq!(a.dist(mid), b.dist(mid), Angle::from_degminsec(0, 0, 0.001));
+        // Along the great circle, not the straight line in right ascension and declination
+        assert!(mid.declination().degrees() > 25.0);
+        let path = a.waypoints(b, 5);
+        assert_eq!(path.len(), 5);
+        for w in path.windows(2) {
+            assert_approx_eq!(
+                w[0].dist(w[1]),
+                a.dist(b) / 4.0,
+                Angle::from_degminsec(0, 0, 0.001)
+            );
+        }
+        assert_eq!(a.waypoints(b, 1), vec![a]);
+        assert!(a.waypoints(b, 0).is_empty());
+        // Works in any frame
+        let d = Date::from_calendar(2025, 3, 10, Angle::default());
+        assert_approx_eq!(
+            a.to_ecliptic(d)
+                .slerp(b.to_ecliptic(d), 0.5)
+                .to_equatorial(),
+            mid,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+    }
+
+    #[test]
+    fn test_icrs() {
+        let mas = crate::angle!(0:0:0.001);
+        // The origin of the ICRS in the J2000 frame
+        let c = Coord::from_equatorial(Angle::default(), Angle::default()).from_icrs();
+        assert_approx_eq!(c.0, Angle::from_degrees(0.0146 / 3600.0), mas * 0.01);
+        assert_approx_eq!(
+            c.1.angle(),
+            Angle::from_degrees(-0.016617 / 3600.0),
+            mas * 0.01
+        );
+        let vega = Coord::from_equatorial(crate::angle!(279.23473), crate::angle!(38.78369));
+        assert_approx_eq!(vega.to_icrs().from_icrs(), vega, mas * 0.001);
+        assert!(vega.dist(vega.to_icrs()).degrees() * 3.6e6 < 30.0);
+    }
+
+    #[test]
+    fn test_fk4_fk5() {
+        let arcsec = Angle::from_degminsec(0, 0, 1.0);
+        // The north galactic pole is defined in both frames
+        let ngp = Coord::from_equatorial(Angle::from_degrees(192.25), Angle::from_degrees(27.4));
+        assert_approx_eq!(
+            ngp.b1950_to_j2000(),
+            Coord::from_equatorial(
+                Angle::from_degrees(192.85948),
+                Angle::from_degrees(27.12825)
+            ),
+            arcsec
         );
+        let sirius =
+            Coord::from_equatorial(Angle::from_clock(6, 45, 8.9), Angle::from_degrees(-16.7161));
+        assert_approx_eq!(
+            sirius.j2000_to_b1950().b1950_to_j2000(),
+            sirius,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+    }
+
+    #[test]
+    fn test_cartesian() {
+        let c = Coord::from_equatorial(Angle::from_degrees(30.0), Angle::from_degrees(-45.0));
+        let v = c.cartesian(2.0);
+        assert!((v.norm() - 2.0).abs() < 1e-12);
+        assert!(v.z < 0.0);
+        assert_approx_eq!(Coord::from(v), c, Angle::from_degminsec(0, 0, 1e-6));
+        let (x, y) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(x.cross(y), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(x.dot(y), 0.0);
+        assert_eq!(-(x - y) * 2.0 + x, Vector3::new(-1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_distance() {
+        let d = Distance::from_au(1.0);
+        assert_eq!(d.km(), 149_597_870.7);
+        assert_eq!(d.meters(), 149_597_870_700.0);
+        assert!((d.light_minutes() - 8.316_746_4).abs() < 1e-6);
+        assert!((Distance::from_light_minutes(d.light_minutes()).au() - 1.0).abs() < 1e-12);
         assert_eq!(
+            Distance::from_km(384_400.0) * 2.0,
+            Distance::from_km(768_800.0)
+        );
+        assert_eq!(format!("{:.2}", d + Distance::from_au(0.5)), "1.50 AU");
+    }
+
+    #[test]
+    fn test_precess_rigorous() {
+        // Astronomical Algorithms, Example 21.b, Theta Persei with proper motion applied
+        let c = Coord::from_equatorial(
+            Angle::from_degrees(41.054063),
+            Angle::from_degrees(49.227750),
+        );
+        assert_approx_eq!(
+            c.precess_rigorous(J2000, Date::from_julian(2462088.69)),
+            Coord::from_equatorial(
+                Angle::from_degrees(41.547214),
+                Angle::from_degrees(49.348483)
+            ),
+            Angle::from_degminsec(0, 0, 0.1)
+        );
+        // Polaris, which is where the rough formula fails
+        let polaris = Coord::from_equatorial(
+            Angle::from_clock(2, 31, 49.09),
+            Angle::from_degminsec(89, 15, 50.8),
+        );
+        let a2100 = Date::from_calendar(2100, 1, 1, Angle::default());
+        let there = polaris.precess_rigorous(J2000, a2100);
+        // Closest to the pole around 2100, at about +89°32'
+        assert!(there.declination().degrees() > 89.5);
+        assert_approx_eq!(
+            there.precess_rigorous(a2100, J2000),
+            polaris,
+            Angle::from_degminsec(0, 0, 0.001)
+        );
+    }
+
+    #[test]
+    fn test_nutation() {
+        // Astronomical Algorithms, Example 22.a
+        let d = Date::from_calendar(1987, 4, 10, Angle::default());
+        let (dpsi, deps) = nutation(d);
+        assert_approx_eq!(
+            dpsi,
+            Angle::from_degminsec(0, 0, -3.788),
+            Angle::from_degminsec(0, 0, 0.5)
+        );
+        assert_approx_eq!(
+            deps,
+            Angle::from_degminsec(0, 0, 9.443),
+            Angle::from_degminsec(0, 0, 0.1)
+        );
+        assert_approx_eq!(
+            true_obliquity_ecl(d),
+            Angle::from_degminsec(23, 26, 36.850),
+            Angle::from_degminsec(0, 0, 0.1)
+        );
+        // Example 23.a, the nutation of Theta Persei: +15.843" and +6.218"
+        let d = Date::from_julian(2462088.69);
+        let c = Coord::from_equatorial(
+            Angle::from_degrees(41.5555635),
+            Angle::from_degrees(49.3503415),
+        );
+        let (ra, de) = c.nutate(d).equatorial();
+        assert_approx_eq!(
+            ra - c.equatorial().0,
+            Angle::from_degminsec(0, 0, 15.843),
+            Angle::from_degminsec(0, 0, 0.5)
+        );
+        assert_approx_eq!(
+            de - c.equatorial().1,
+            Angle::from_degminsec(0, 0, 6.218),
+            Angle::from_degminsec(0, 0, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_galactic() {
+        let arcsec = Angle::from_degminsec(0, 0, 1.0);
+        // The galactic center
+        let center = Coord::<Galactic>::new(Angle::default(), Angle::default()).to_equatorial();
+        assert_approx_eq!(
+            center,
+            Coord::from_equatorial(
+                Angle::from_degrees(266.40499),
+                Angle::from_degrees(-28.93617)
+            ),
+            arcsec
+        );
+        assert_approx_eq!(
+            GALACTIC_POLE.to_galactic().lat(),
+            Latitude::from_degrees(90.0),
+            arcsec
+        );
+        let deneb = Coord::from_equatorial(
+            Angle::from_clock(20, 41, 25.9),
+            Angle::from_degminsec(45, 16, 49.0),
+        );
+        let g = deneb.to_galactic();
+        assert_approx_eq!(g.lon(), Angle::from_degrees(84.2847));
+        assert_approx_eq!(g.lat(), Latitude::from_degrees(1.9975));
+        assert_approx_eq!(g.to_equatorial(), deneb, Angle::from_degminsec(0, 0, 0.001));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_ecliptic() {
+        let star1 = Coord::from_equatorial(
+            Angle::from_clock(9, 34, 53.6),
+            Angle::from_degminsec(19, 32, 14.2),
+        );
+        let (lambda, beta) = star1.ecliptic(Date::from_calendar(1950, 0, 1, Angle::default()));
+        assert_approx_eq!(lambda, Angle::from_degminsec(139, 41, 10.0));
+        assert_approx_eq!(beta, Angle::from_degminsec(4, 52, 31.0));
+        assert_approx_eq!(
             Coord::from_ecliptic(
                 Angle::from_degminsec(139, 41, 10.0),
                 Angle::from_degminsec(4, 52, 31.0),
@@ -265,4 +2016,24 @@ mod tests {
             star1
         );
     }
+
+    #[test]
+    fn test_to_ecliptic() {
+        let star1 = Coord::from_equatorial(
+            Angle::from_clock(9, 34, 53.6),
+            Angle::from_degminsec(19, 32, 14.2),
+        );
+        let ecl = star1.to_ecliptic(Date::from_calendar(1950, 0, 1, Angle::default()));
+        assert_approx_eq!(ecl.lon(), Angle::from_degminsec(139, 41, 10.0));
+        assert_approx_eq!(ecl.lat().angle(), Angle::from_degminsec(4, 52, 31.0));
+        assert_approx_eq!(
+            Coord::new_ecliptic(
+                Angle::from_degminsec(139, 41, 10.0),
+                Angle::from_degminsec(4, 52, 31.0),
+                Date::from_calendar(1950, 0, 1, Angle::default())
+            )
+            .to_equatorial(),
+            star1
+        );
+    }
 }
@@ -0,0 +1,104 @@
+//! The error type shared across the crate
+
+use crate::{asteroid, coord, sol, time};
+
+/// Any error from this crate
+///
+/// Each function returns the most specific error type it can, which converts into this one with `?`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// A calendar date that doesn't exist, see [`time::InvalidDate`]
+    InvalidDate,
+    /// Text that couldn't be parsed as an angle, see [`time::ParseAngleError`]
+    ParseAngle,
+    /// The clock is unavailable, see [`time::ClockError`]
+    Clock,
+    /// A date that another time type can't represent, see [`time::DateOutOfRange`]
+    DateOutOfRange,
+    /// A coordinate that doesn't rise or set, see [`coord::NoRiseSet`]
+    NoRiseSet(coord::NoRiseSet),
+    /// An argument outside of the domain of a function, such as a latitude past ±90°, or a non-finite number
+    OutOfDomain,
+    /// A planet with an orbit that can't be used, see [`sol::InvalidPlanet`]
+    InvalidPlanet {
+        /// The field of [`sol::Planet`] with the bad value
+        field: &'static str,
+    },
+    /// A line of MPC orbital elements that couldn't be read, see [`asteroid::ParseMpcError`]
+    ParseMpc {
+        /// The field with the bad value
+        field: &'static str,
+    },
+    /// A line of a catalog file that couldn't be read, see `catalog::CsvError`
+    Catalog {
+        /// The line number, starting from 1
+        line: usize,
+        /// The kind of error if the line couldn't be read at all
+        io: Option<std::io::ErrorKind>,
+    },
+}
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidDate => time::InvalidDate.fmt(f),
+            Error::ParseAngle => time::ParseAngleError.fmt(f),
+            Error::Clock => time::ClockError.fmt(f),
+            Error::DateOutOfRange => time::DateOutOfRange.fmt(f),
+            Error::NoRiseSet(e) => e.fmt(f),
+            Error::OutOfDomain => write!(f, "argument out of domain"),
+            Error::InvalidPlanet { field } => sol::InvalidPlanet { field }.fmt(f),
+            Error::ParseMpc { field } => asteroid::ParseMpcError { field }.fmt(f),
+            Error::Catalog {
+                line,
+                io: Some(kind),
+            } => write!(f, "couldn't read line {line} of the catalog: {kind}"),
+            Error::Catalog { line, io: None } => write!(f, "invalid catalog entry on line {line}"),
+        }
+    }
+}
+impl std::error::Error for Error {}
+
+impl From<time::InvalidDate> for Error {
+    fn from(_: time::InvalidDate) -> Self {
+        Error::InvalidDate
+    }
+}
+impl From<time::ParseAngleError> for Error {
+    fn from(_: time::ParseAngleError) -> Self {
+        Error::ParseAngle
+    }
+}
+impl From<time::ClockError> for Error {
+    fn from(_: time::ClockError) -> Self {
+        Error::Clock
+    }
+}
+impl From<time::DateOutOfRange> for Error {
+    fn from(_: time::DateOutOfRange) -> Self {
+        Error::DateOutOfRange
+    }
+}
+impl From<coord::NoRiseSet> for Error {
+    fn from(e: coord::NoRiseSet) -> Self {
+        Error::NoRiseSet(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error() {
+        fn parse(s: &str) -> Result<time::Date, Error> {
+            let t = time::parse_angle(s)?;
+            Ok(time::Date::try_from_calendar(2025, 2, 30, t)?)
+        }
+        assert_eq!(parse("nope"), Err(Error::ParseAngle));
+        assert_eq!(parse("12h00m00s"), Err(Error::InvalidDate));
+        assert_eq!(
+            Error::from(coord::NoRiseSet::Circumpolar).to_string(),
+            coord::NoRiseSet::Circumpolar.to_string()
+        );
+    }
+}
@@ -0,0 +1,913 @@
+/*! Searching for events in time
+
+* When a function of time crosses zero, such as the altitude of a planet less 30°: [`find_crossing()`]
+* When a function of time is smallest: [`find_minimum()`]
+* Closest approach of two objects, such as a conjunction: [`closest_approach()`]
+* First and last sight of a star near the sun: [`heliacal_rising()`] and [`heliacal_setting()`]
+* When an object can be observed during a night: [`visibility()`]
+* When the moon covers a planet or star: [`occultations()`]
+* Conjunctions, oppositions, and greatest elongations of a planet: [`planetary_events()`]
+* When the rings of Saturn are edge-on to the earth or sun: [`ring_plane_crossings()`]
+* Transits, shadow transits, occultations, and eclipses of the moons of Jupiter: [`galilean_events()`]
+
+Searches work over a [`Range`] of dates, sampling it every three hours, then refining the samples around the event.
+Events closer together than that may be missed.
+```
+# use pracstro::{coord::Observer, events, sol, time::{Angle, Date}};
+# use std::time::Duration;
+// When Mars is 30° high
+let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+let start = Date::from_calendar(2025, 3, 10, Angle::default());
+let end = Date::from_calendar(2025, 3, 11, Angle::default());
+let when = events::find_crossing(
+    |d| (sol::MARS.location(d).horizon_for(&obs, d).1 - Angle::from_degrees(30.0)).to_latitude().radians(),
+    start..end,
+    Duration::from_secs(1),
+);
+```
+*/
+
+use crate::celobj::CelObj;
+use crate::coord::{self, Observer};
+use crate::moon::MOON;
+use crate::sol::{self, Elongation, GalileanMoon, Planet, SUN};
+use crate::time::{Angle, Date, J2000};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Step between samples, in days, short enough to find the approach of the moon to anything
+const STEP: f64 = 0.125;
+
+/// Most steps taken refining a bracket, enough to reach the precision of a date from a day,
+/// so a tolerance of zero or below that precision still ends
+const MAX_STEPS: usize = 100;
+
+/// Date at some number of days after a date, keeping the precision of the two-part Julian date
+fn offset(d: Date, days: f64) -> Date {
+    let (day, f) = d.jd_parts();
+    Date::from_jd_parts(day, f + days)
+}
+
+/// The minimum of a function between two bounds, to within `tol`, by golden-section search
+///
+/// The function has to have only one minimum between the bounds.
+fn golden_min(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64, tol: f64) -> f64 {
+    let r = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut c, mut d) = (b - r * (b - a), a + r * (b - a));
+    let (mut fc, mut fd) = (f(c), f(d));
+    for _ in 0..MAX_STEPS {
+        if b - a <= tol {
+            break;
+        }
+        if fc < fd {
+            (b, d, fd) = (d, c, fc);
+            c = b - r * (b - a);
+            fc = f(c);
+        } else {
+            (a, c, fc) = (c, d, fd);
+            d = a + r * (b - a);
+            fd = f(d);
+        }
+    }
+    (a + b) / 2.0
+}
+
+/// Dates sampling a range, along with the offset of each from the start in days
+fn samples(range: &Range<Date>) -> impl Iterator<Item = f64> {
+    let len = range.end.julian() - range.start.julian();
+    let n = (len / STEP).ceil().max(1.0) as usize;
+    (0..=n).map(move |i| len * i as f64 / n as f64)
+}
+
+/// The first date in a range at which a function crosses zero, to within `tol`
+///
+/// A crossing is wherever the function changes sign, a jump from positive to negative counts too,
+/// so angles should be compared as [`Angle::to_latitude()`] of their difference away from ±180°.
+/// Returns `None` if the function doesn't cross zero. The ends of the range are sampled as they are,
+/// so a function of different signs at the two ends always gives a crossing. A tolerance of zero gives
+/// the crossing as precisely as the date can hold it.
+pub fn find_crossing(f: impl Fn(Date) -> f64, range: Range<Date>, tol: Duration) -> Option<Date> {
+    let len = range.end.julian() - range.start.julian();
+    let at = |t: f64| match t {
+        0.0 => range.start,
+        t if t == len => range.end,
+        t => offset(range.start, t),
+    };
+    let g = |t: f64| f(at(t));
+    let tol = tol.as_secs_f64() / 86400.0;
+    let mut prev: Option<(f64, f64)> = None;
+    for t in samples(&range) {
+        let y = g(t);
+        if y == 0.0 {
+            return Some(at(t));
+        }
+        if let Some((pt, py)) = prev.filter(|&(_, py)| (py < 0.0) != (y < 0.0)) {
+            let (mut a, mut b, ya) = (pt, t, py);
+            for _ in 0..MAX_STEPS {
+                if b - a <= tol {
+                    break;
+                }
+                let m = (a + b) / 2.0;
+                match (g(m) < 0.0) == (ya < 0.0) {
+                    true => a = m,
+                    false => b = m,
+                }
+            }
+            return Some(at((a + b) / 2.0));
+        }
+        prev = Some((t, y));
+    }
+    None
+}
+
+/// The date in a range at which a function is smallest, to within `tol`
+///
+/// The minimum may be at either end of the range, if the function only rises or falls.
+pub fn find_minimum(f: impl Fn(Date) -> f64, range: Range<Date>, tol: Duration) -> Date {
+    let g = |t: f64| f(offset(range.start, t));
+    let ts: Vec<f64> = samples(&range).collect();
+    let best = (0..ts.len())
+        .map(|i| (i, g(ts[i])))
+        .min_by(|x, y| x.1.total_cmp(&y.1))
+        .map_or(0, |(i, _)| i);
+    let (a, b) = (ts[best.saturating_sub(1)], ts[(best + 1).min(ts.len() - 1)]);
+    offset(
+        range.start,
+        golden_min(g, a, b, tol.as_secs_f64() / 86400.0),
+    )
+}
+
+/// When two objects are closest in the sky between two dates, and how far apart they are then
+///
+/// The closest approach found may be at either end of the range, if the objects are approaching or leaving each other.
+/// ```
+/// # use pracstro::{events, sol, time::Date};
+/// // The conjunction of Venus and Jupiter in March 2023
+/// let range = Date::from_calendar(2023, 2, 15, Default::default())..Date::from_calendar(2023, 3, 15, Default::default());
+/// let (when, sep) = events::closest_approach(&sol::VENUS, &sol::JUPITER, range);
+/// assert!(sep.degrees() < 1.0);
+/// ```
+pub fn closest_approach(
+    a: &(impl CelObj + ?Sized),
+    b: &(impl CelObj + ?Sized),
+    range: Range<Date>,
+) -> (Date, Angle) {
+    let sep = |d: Date| a.location_of_date(d).dist(b.location_of_date(d)).radians();
+    let when = find_minimum(sep, range, Duration::from_secs(1));
+    (when, Angle::from_radians(sep(when)))
+}
+
+/// Altitude of an object for an observer, in radians
+///
+/// This goes through [`coord::Coord::hour_angle()`], which uses the sidereal time for the instant.
+fn altitude(obj: &(impl CelObj + ?Sized), obs: &Observer, d: Date) -> f64 {
+    let c = obj.location_of_date(d);
+    let ((slat, clat), (sde, cde)) = (obs.lat.sincos(), c.declination().angle().sincos());
+    (slat * sde + clat * cde * c.hour_angle(d, obs.lon).cos())
+        .clamp(-1.0, 1.0)
+        .asin()
+}
+
+/// How far below the horizon the sun has to be to see a star of a magnitude on the horizon, the arcus visionis
+///
+/// A straight line through the traditional values, 7.5° for Sirius and 11° for a star of the first magnitude.
+fn arcus_visionis(mag: f64) -> f64 {
+    (9.6 + 1.4 * mag).to_radians()
+}
+
+/// The first or last day in a year a star is seen near the sun, see [`heliacal_rising()`]
+fn heliacal(
+    star: &(impl CelObj + ?Sized),
+    obs: &Observer,
+    year: i64,
+    rising: bool,
+) -> Option<Date> {
+    let start = Date::from_calendar(year, 1, 1, Angle::default());
+    let av = arcus_visionis(star.magnitude(start));
+    let seen = |day: f64| {
+        let rs = star
+            .riseset_moving(offset(start, day), obs, coord::H0_STAR)
+            .ok()?;
+        let event = if rising { rs.rise } else { rs.set };
+        Some((event, altitude(&SUN, obs, event) <= -av))
+    };
+    let mut prev = None;
+    for day in -1..366 {
+        let Some((event, visible)) = seen(day as f64) else {
+            continue;
+        };
+        if event.calendar().0 > year {
+            break;
+        }
+        match (prev, rising) {
+            (Some((_, false)), true) if visible => return Some(event),
+            (Some((last, true)), false) if !visible && day > 0 => return Some(last),
+            _ => {}
+        }
+        prev = Some((event, visible));
+    }
+    None
+}
+
+/// The heliacal rising of a star, the first morning in a year it can be seen rising before the sun
+///
+/// This uses a simplified arcus visionis, where the sun has to be further below the horizon for fainter stars
+/// to be seen, which is accurate to a few days. The rise is taken with the usual refraction, see [`coord::H0_STAR`].
+/// Returns `None` if the star never rises or sets, or if it isn't first seen in this year.
+/// ```
+/// # use pracstro::{coord::Observer, events, star::Star, time::Angle};
+/// // The rising of Sirius at Memphis, which began the Egyptian year
+/// let sirius = Star::new("Sirius", Angle::from_degrees(101.287), Angle::from_degrees(-16.716), -1.46);
+/// let memphis = Observer::new(Angle::from_degrees(29.85), Angle::from_degrees(31.25), 0.0);
+/// let rising = events::heliacal_rising(&sirius, &memphis, 2025); // Around the 1st of August
+/// ```
+pub fn heliacal_rising(star: &(impl CelObj + ?Sized), obs: &Observer, year: i64) -> Option<Date> {
+    heliacal(star, obs, year, true)
+}
+
+/// The heliacal setting of a star, the last evening in a year it can be seen setting after the sun
+///
+/// See [`heliacal_rising()`] for the method.
+pub fn heliacal_setting(star: &(impl CelObj + ?Sized), obs: &Observer, year: i64) -> Option<Date> {
+    heliacal(star, obs, year, false)
+}
+
+/// When an object can be observed during a night, from [`visibility()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct VisibilityWindow {
+    /// When the object can first be observed
+    pub start: Date,
+    /// When the object can last be observed
+    pub end: Date,
+    /// When the object is highest during the window
+    pub best: Date,
+    /// The altitude of the object at its best
+    pub best_altitude: Angle,
+    /// Distance between the object and the moon at its best
+    pub moon_separation: Angle,
+    /// Illuminated fraction of the moon at its best, or zero if the moon is below the horizon
+    pub moon_illumfrac: f64,
+}
+
+/// When an object can be observed during the night starting on the date, at an observers local noon
+///
+/// The sky has to be dark, with the sun 12° below the horizon at the end of nautical twilight,
+/// and the object at least 10° high. If there are several windows, such as for an object that sets
+/// and rises again, the longest is given. The moon is included so the window can be judged for faint objects.
+/// ```
+/// # use pracstro::{coord::Observer, events, sol, time::{Angle, Date}};
+/// let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+/// let night = Date::from_calendar(2025, 3, 10, Angle::default());
+/// if let Some(w) = events::visibility(&sol::JUPITER, &obs, night) {
+///     println!("{:?} to {:?}, best at {:?}", w.start.calendar(), w.end.calendar(), w.best.calendar());
+/// }
+/// ```
+pub fn visibility(
+    obj: &(impl CelObj + ?Sized),
+    obs: &Observer,
+    night: Date,
+) -> Option<VisibilityWindow> {
+    let (dark, high) = (
+        coord::H0_NAUTICAL.to_latitude().radians(),
+        10.0_f64.to_radians(),
+    );
+    let observable = |d: Date| (dark - altitude(&SUN, obs, d)).min(altitude(obj, obs, d) - high);
+    let noon = Date::from_time(night, Angle::from_clock(12, 0, 0.0) - obs.lon);
+    let step = 5.0 / 1440.0;
+    let (mut best, mut run): (Option<(f64, f64)>, Option<f64>) = (None, None);
+    for i in 0..=288 {
+        let t = i as f64 * step;
+        match (observable(offset(noon, t)) > 0.0, run) {
+            (true, None) => run = Some(t),
+            (false, Some(s)) => {
+                if best.is_none_or(|(a, b)| t - s > b - a) {
+                    best = Some((s, t));
+                }
+                run = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = run.filter(|&s| best.is_none_or(|(a, b)| 1.0 - s > b - a)) {
+        best = Some((s, 1.0));
+    }
+    let (a, b) = best?;
+
+    // Refine the edges inside the first and last sample
+    let edge = |t: f64| {
+        find_crossing(
+            observable,
+            offset(noon, (t - step).max(0.0))..offset(noon, (t + step).min(1.0)),
+            Duration::from_secs(1),
+        )
+    };
+    let start = if a > 0.0 { edge(a) } else { None }.unwrap_or(offset(noon, a));
+    let end = if b < 1.0 { edge(b - step) } else { None }.unwrap_or(offset(noon, b));
+    let best = find_minimum(
+        |d| -altitude(obj, obs, d),
+        start..end,
+        Duration::from_secs(1),
+    );
+
+    let moon = MOON.location_of_date(best);
+    Some(VisibilityWindow {
+        start,
+        end,
+        best,
+        best_altitude: Angle::from_radians(altitude(obj, obs, best)),
+        moon_separation: moon.dist(obj.location_of_date(best)),
+        moon_illumfrac: match altitude(&MOON, obs, best) > 0.0 {
+            true => MOON.illumfrac(best),
+            false => 0.0,
+        },
+    })
+}
+
+/// The moon covering an object, from [`occultations()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Occultation {
+    /// When the object disappears behind the limb of the moon
+    pub disappearance: Date,
+    /// When the object reappears from behind the other limb
+    pub reappearance: Date,
+}
+
+/// Location of an object as seen by an observer, of date like the moon, corrected for parallax if it has a distance
+fn topocentric(obj: &(impl CelObj + ?Sized), obs: &Observer, d: Date) -> coord::Coord {
+    let (location, dist) = (obj.location_of_date(d), obj.distance(d).au());
+    match dist.is_finite() {
+        true => (location.cartesian(dist)
+            - obs.celestial(d) * coord::Distance::from_meters(1.0).au())
+        .into(),
+        false => location,
+    }
+}
+
+/// When the moon covers an object for an observer, such as a planet or a bright star
+///
+/// The times are when the center of the object crosses the limb of the moon, using [`moon::Moon::info_precise()`]
+/// as seen by the observer, and the object precessed to the date with [`CelObj::location_of_date()`].
+/// The moon may be below the horizon, check its altitude to see if the occultation is visible.
+/// If the object is covered at the start or end of the range, that end of the range is given.
+/// ```
+/// # use pracstro::{coord::Observer, events, sol, time::{Angle, Date}};
+/// let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+/// let year = Date::from_calendar(2025, 1, 1, Angle::default())..Date::from_calendar(2026, 1, 1, Angle::default());
+/// for o in events::occultations(&sol::MARS, &obs, year) {
+///     println!("{:?} to {:?}", o.disappearance.calendar(), o.reappearance.calendar());
+/// }
+/// ```
+///
+/// [`moon::Moon::info_precise()`]: crate::moon::Moon::info_precise
+pub fn occultations(
+    target: &(impl CelObj + ?Sized),
+    obs: &Observer,
+    range: Range<Date>,
+) -> Vec<Occultation> {
+    // Distance from the limb of the moon to the object, negative when it is covered
+    let gap = |d: Date| {
+        let moon = MOON.info_precise(d);
+        moon.location_topo(obs)
+            .dist(topocentric(target, obs, d))
+            .radians()
+            - moon.angdia_topo(obs).radians() / 2.0
+    };
+    // Further than the moon can move between samples
+    let near = 2.0_f64.to_radians();
+    let ts: Vec<f64> = samples(&range).collect();
+    let close: Vec<bool> = ts
+        .iter()
+        .map(|&t| gap(offset(range.start, t)) < near)
+        .collect();
+
+    let tol = Duration::from_secs(1);
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < ts.len() {
+        if !close[i] {
+            i += 1;
+            continue;
+        }
+        let first = i;
+        while i < ts.len() && close[i] {
+            i += 1;
+        }
+        let (a, b) = (
+            offset(range.start, ts[first.saturating_sub(1)]),
+            offset(range.start, ts[i.min(ts.len() - 1)]),
+        );
+        let mid = find_minimum(gap, a..b, tol);
+        if gap(mid) < 0.0 {
+            found.push(Occultation {
+                disappearance: find_crossing(gap, a..mid, tol).unwrap_or(a),
+                reappearance: find_crossing(gap, mid..b, tol).unwrap_or(b),
+            });
+        }
+    }
+    found
+}
+
+/// What happens in a [`PlanetEvent`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PlanetEventKind {
+    /// An inner planet passing between the earth and the sun
+    InferiorConjunction,
+    /// An inner planet passing behind the sun
+    SuperiorConjunction,
+    /// An outer planet passing behind the sun
+    Conjunction,
+    /// An outer planet opposite the sun, when it is closest and brightest
+    Opposition,
+    /// An inner planet furthest from the sun, on one side of it
+    GreatestElongation(Elongation),
+}
+
+/// A conjunction, opposition, or greatest elongation of a planet, from [`planetary_events()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PlanetEvent {
+    /// What happens
+    pub kind: PlanetEventKind,
+    /// When it happens
+    pub date: Date,
+    /// The angle between the planet and the sun then
+    pub elongation: Angle,
+}
+
+/// The conjunctions, oppositions, and greatest elongations of a planet during a year, in order
+///
+/// Conjunctions and oppositions are when the ecliptic longitude of the planet is the same as or opposite to
+/// that of the sun. Only the inner planets have greatest elongations, and only the outer planets have oppositions.
+/// ```
+/// # use pracstro::{events, sol};
+/// for e in events::planetary_events(&sol::VENUS, 2025) {
+///     println!("{:?} on {:?}, {:.1}° from the sun", e.kind, e.date.calendar(), e.elongation.degrees());
+/// }
+/// ```
+pub fn planetary_events(planet: &Planet, year: i64) -> Vec<PlanetEvent> {
+    let start = Date::from_calendar(year, 1, 1, Angle::default());
+    let end = Date::from_calendar(year + 1, 1, 1, Angle::default());
+    let inner = planet.a < 1.0;
+    let tol = Duration::from_secs(60);
+    // Sine of the difference in ecliptic longitude, zero at conjunction and opposition
+    let side = |d: Date| {
+        (planet.location(d).to_ecliptic(J2000).lon() - SUN.location(d).to_ecliptic(J2000).lon())
+            .sin()
+    };
+    let elongation = |d: Date| planet.elongation(d).0.radians();
+
+    // Daily samples, with one more on either side to find extremes at the ends of the year
+    let days = (end.julian() - start.julian()).round() as i64;
+    let ts: Vec<Date> = (-1..=days + 1).map(|i| offset(start, i as f64)).collect();
+    let (sides, elongs): (Vec<f64>, Vec<f64>) =
+        ts.iter().map(|&d| (side(d), elongation(d))).unzip();
+
+    let mut found = Vec::new();
+    for i in 1..ts.len() - 1 {
+        if (sides[i] < 0.0) != (sides[i + 1] < 0.0) {
+            if let Some(date) = find_crossing(side, ts[i]..ts[i + 1], tol) {
+                let kind = match (inner, elongation(date) > std::f64::consts::FRAC_PI_2) {
+                    (false, true) => PlanetEventKind::Opposition,
+                    (false, false) => PlanetEventKind::Conjunction,
+                    (true, _) if planet.distance(date).au() < SUN.distance(date).au() => {
+                        PlanetEventKind::InferiorConjunction
+                    }
+                    (true, _) => PlanetEventKind::SuperiorConjunction,
+                };
+                found.push((kind, date));
+            }
+        }
+        if inner && elongs[i] >= elongs[i - 1] && elongs[i] > elongs[i + 1] {
+            let date = find_minimum(|d| -elongation(d), ts[i - 1]..ts[i + 1], tol);
+            found.push((
+                PlanetEventKind::GreatestElongation(planet.elongation(date).1),
+                date,
+            ));
+        }
+    }
+
+    let mut events: Vec<PlanetEvent> = found
+        .into_iter()
+        .filter(|&(_, date)| date >= start && date < end)
+        .map(|(kind, date)| PlanetEvent {
+            kind,
+            date,
+            elongation: planet.elongation(date).0,
+        })
+        .collect();
+    events.sort_by_key(|e| e.date);
+    events
+}
+
+/// Who sees the rings of Saturn edge-on in a ring plane crossing, from [`ring_plane_crossings()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RingPlaneCrossing {
+    /// The earth passes through the plane of the rings, and they nearly vanish
+    Earth,
+    /// The sun passes through the plane of the rings, the equinox of Saturn
+    Sun,
+}
+
+/// When the earth and sun pass through the plane of the rings of Saturn between two dates, in order
+///
+/// These come every 13 to 16 years, with the earth often crossing three times around each crossing of the sun.
+/// ```
+/// # use pracstro::{events, time::Date};
+/// let range = Date::from_calendar(2025, 1, 1, Default::default())..Date::from_calendar(2026, 1, 1, Default::default());
+/// for (kind, date) in events::ring_plane_crossings(range) {
+///     println!("{:?} {:?}", kind, date.calendar());
+/// }
+/// ```
+pub fn ring_plane_crossings(range: Range<Date>) -> Vec<(RingPlaneCrossing, Date)> {
+    let earth = |d| sol::saturn_ring(d).b_earth.to_latitude().radians();
+    let sun = |d| sol::saturn_ring(d).b_sun.to_latitude().radians();
+    let days = (range.end.julian() - range.start.julian()).ceil().max(1.0) as i64;
+    let ts: Vec<Date> = (0..=days)
+        .map(|i| offset(range.start, i as f64).min(range.end))
+        .collect();
+
+    let mut found = Vec::new();
+    for (kind, f) in [
+        (RingPlaneCrossing::Earth, &earth as &dyn Fn(Date) -> f64),
+        (RingPlaneCrossing::Sun, &sun),
+    ] {
+        let ys: Vec<f64> = ts.iter().map(|&d| f(d)).collect();
+        for i in 0..ts.len() - 1 {
+            if (ys[i] < 0.0) != (ys[i + 1] < 0.0) {
+                if let Some(date) = find_crossing(f, ts[i]..ts[i + 1], Duration::from_secs(60)) {
+                    found.push((kind, date));
+                }
+            }
+        }
+    }
+    found.sort_by_key(|&(_, date)| date);
+    found
+}
+
+/// What happens in a [`GalileanEvent`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GalileanEventKind {
+    /// The moon crosses in front of the disk of Jupiter
+    Transit,
+    /// The shadow of the moon crosses the disk of Jupiter
+    ShadowTransit,
+    /// The moon is hidden behind the disk of Jupiter
+    Occultation,
+    /// The moon is in the shadow of Jupiter
+    Eclipse,
+}
+
+/// A phenomenon of one of the moons of Jupiter, from [`galilean_events()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GalileanEvent {
+    /// Which moon
+    pub moon: GalileanMoon,
+    /// What happens
+    pub kind: GalileanEventKind,
+    /// When it begins
+    pub start: Date,
+    /// When it ends
+    pub end: Date,
+}
+
+/// The transits, shadow transits, occultations, and eclipses of the Galilean moons that begin between two dates, in order
+///
+/// Positions are from [`sol::jupiter_moons()`], and the shadow of Jupiter is taken as a cylinder,
+/// so times are good to a few minutes. Each event is found on its own, an eclipse that begins while the
+/// moon is occulted can't be seen until the moon reappears. Grazing events shorter than 15 minutes may be missed.
+/// ```
+/// # use pracstro::{events, time::Date};
+/// let range = Date::from_calendar(2025, 1, 1, Default::default())..Date::from_calendar(2025, 1, 8, Default::default());
+/// for e in events::galilean_events(range) {
+///     println!("{:?} {:?} {:?}", e.moon, e.kind, e.start.calendar());
+/// }
+/// ```
+pub fn galilean_events(range: Range<Date>) -> Vec<GalileanEvent> {
+    use GalileanEventKind::*;
+    // How far inside the disk of Jupiter the moon or its shadow is, positive during the event
+    let depth = |moon: usize, kind: GalileanEventKind, d: Date| {
+        let p = sol::galilean_offsets(d, matches!(kind, ShadowTransit | Eclipse))[moon];
+        match (p.z > 0.0) == matches!(kind, Transit | ShadowTransit) {
+            true => 1.0 - p.x * p.x - (p.y / (1.0 - sol::JUPITER_FLATTENING)).powi(2),
+            false => -1.0,
+        }
+    };
+    // Events that begin near the end of the range end within half a day
+    let step = 1.0 / 96.0;
+    let n = ((range.end.julian() - range.start.julian() + 0.5) / step).ceil() as usize;
+    let ts: Vec<Date> = (0..=n)
+        .map(|i| offset(range.start, i as f64 * step))
+        .collect();
+    let tol = Duration::from_secs(1);
+
+    let mut found = Vec::new();
+    for (i, &moon) in GalileanMoon::ALL.iter().enumerate() {
+        for kind in [Transit, ShadowTransit, Occultation, Eclipse] {
+            let f = |d| depth(i, kind, d);
+            let ys: Vec<f64> = ts.iter().map(|&d| f(d)).collect();
+            let mut start = None;
+            for j in 0..ts.len() - 1 {
+                if (ys[j] > 0.0) == (ys[j + 1] > 0.0) {
+                    continue;
+                }
+                let date = find_crossing(f, ts[j]..ts[j + 1], tol)
+                    .expect("the depth has different signs at the two ends");
+                match start.take() {
+                    None if ys[j] <= 0.0 => start = Some(date),
+                    Some(start) if start < range.end => found.push(GalileanEvent {
+                        moon,
+                        kind,
+                        start,
+                        end: date,
+                    }),
+                    _ => (),
+                }
+            }
+        }
+    }
+    found.sort_by_key(|e| e.start);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{moon, sol};
+
+    #[test]
+    fn test_find_crossing() {
+        let start = Date::from_julian(2451545.0);
+        let end = offset(start, 10.0);
+        let tol = Duration::from_secs(1);
+        // A straight line through zero at 3.3 days
+        let line = |d: Date| d.julian() - 2451548.3;
+        let when = find_crossing(line, start..end, tol).unwrap();
+        assert!((when.julian() - 2451548.3).abs() < 1.0 / 86400.0);
+        // Falling crossings are found too, along with the first of many
+        let when = find_crossing(|d| (d.julian() - 2451545.0).cos(), start..end, tol).unwrap();
+        assert!((when.julian() - 2451545.0 - std::f64::consts::FRAC_PI_2).abs() < 1.0 / 86400.0);
+        assert!(find_crossing(|d| d.julian(), start..end, tol).is_none());
+        // A sign change between the ends is always found, even when it's right at the end
+        let odd_end = Date::from_julian(2451547.123456789);
+        let step = |d: Date| if d < odd_end { -1.0 } else { 1.0 };
+        let when = find_crossing(step, start..odd_end, tol).unwrap();
+        assert!((odd_end.julian() - when.julian()).abs() < 1.0 / 86400.0);
+
+        let when = find_minimum(|d| (d.julian() - 2451547.7).powi(2), start..end, tol);
+        assert!((when.julian() - 2451547.7).abs() < 1.0 / 86400.0);
+        let when = find_minimum(|d| d.julian(), start..end, tol);
+        assert!(when.julian() - start.julian() < 1.0 / 86400.0);
+
+        // A tolerance finer than a date can hold still ends
+        let when = find_crossing(line, start..end, Duration::ZERO).unwrap();
+        assert!((when.julian() - 2451548.3).abs() < 1e-6);
+        let when = find_minimum(
+            |d| (d.julian() - 2451547.7).powi(2),
+            start..end,
+            Duration::ZERO,
+        );
+        assert!((when.julian() - 2451547.7).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_closest_approach() {
+        let month = Date::from_calendar(2025, 3, 1, Angle::default())
+            ..Date::from_calendar(2025, 4, 1, Angle::default());
+        let (when, sep) = closest_approach(&moon::MOON, &sol::MARS, month.clone());
+        assert!(when > month.start && when < month.end);
+        // Nothing nearby is closer, and the minimum is within the tolerance of the search
+        for dt in [-0.01, 0.01, -1.0, 1.0] {
+            let d = offset(when, dt);
+            assert!(
+                moon::MOON
+                    .location_of_date(d)
+                    .dist(sol::MARS.location_of_date(d))
+                    .radians()
+                    >= sep.radians() - 1e-12
+            );
+        }
+
+        // Venus and Jupiter were half a degree apart around the 1st and 2nd of March 2023
+        let (when, sep) = closest_approach(
+            &sol::VENUS,
+            &sol::JUPITER,
+            Date::from_calendar(2023, 2, 1, Angle::default())
+                ..Date::from_calendar(2023, 4, 1, Angle::default()),
+        );
+        let (_, month, day, _) = when.calendar();
+        assert!(month == 3 && (1..=2).contains(&day));
+        assert!(sep.degrees() < 0.8, "{}", sep.degrees());
+
+        // The moon passed 0.356° from Mars at 03:52 UT on the 14th of January 2025
+        let (when, sep) = closest_approach(
+            &moon::MOON,
+            &sol::MARS,
+            Date::from_calendar(2025, 1, 13, Angle::default())
+                ..Date::from_calendar(2025, 1, 15, Angle::default()),
+        );
+        let expected = Date::from_calendar(2025, 1, 14, Angle::from_clock(3, 52, 0.0));
+        assert!((when.julian() - expected.julian()).abs() * 1440.0 < 2.0);
+        assert!((sep.degrees() - 0.356).abs() < 0.005, "{}", sep.degrees());
+    }
+
+    #[test]
+    fn test_heliacal() {
+        let sirius = crate::star::Star::new(
+            "Sirius",
+            Angle::from_degrees(101.28716),
+            Angle::from_degrees(-16.71612),
+            -1.46,
+        );
+        let memphis = Observer::new(Angle::from_degrees(29.85), Angle::from_degrees(31.25), 0.0);
+        let rising = heliacal_rising(&sirius, &memphis, 2025).unwrap();
+        let setting = heliacal_setting(&sirius, &memphis, 2025).unwrap();
+        // Sirius is first seen at the start of August, and last seen at the end of May
+        let (_, m, d, _) = rising.calendar();
+        assert!((m, d) >= (7, 25) && (m, d) <= (8, 8), "{m} {d}");
+        let (_, m, d, _) = setting.calendar();
+        assert!((m, d) >= (5, 20) && (m, d) <= (6, 8), "{m} {d}");
+        let polaris = crate::star::Star::new(
+            "Polaris",
+            Angle::from_degrees(37.95),
+            Angle::from_degrees(89.26),
+            1.98,
+        );
+        assert_eq!(heliacal_rising(&polaris, &memphis, 2025), None);
+    }
+
+    #[test]
+    fn test_occultations() {
+        let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+        // A star right behind the center of the moon
+        let when = Date::from_calendar(2000, 1, 12, Angle::from_clock(3, 0, 0.0));
+        let (ra, de) = moon::MOON
+            .info_precise(when)
+            .location_topo(&obs)
+            .equatorial();
+        let star = crate::star::Star::new("Behind the Moon", ra, de, 1.0);
+        let week = offset(when, -3.0)..offset(when, 4.0);
+        let found = occultations(&star, &obs, week.clone());
+        assert_eq!(found.len(), 1);
+        let o = found[0];
+        assert!(o.disappearance < when && when < o.reappearance);
+        // The moon takes about an hour to cross a star
+        let hours = (o.reappearance.julian() - o.disappearance.julian()) * 24.0;
+        assert!((0.8..1.6).contains(&hours), "{hours}");
+        // Those are the times the star is on the limb
+        let limb = |d: Date| {
+            let m = moon::MOON.info_precise(d);
+            m.location_topo(&obs).dist(star.location(d)).degrees()
+                - m.angdia_topo(&obs).degrees() / 2.0
+        };
+        assert!(limb(o.disappearance).abs() < 1e-3 && limb(o.reappearance).abs() < 1e-3);
+
+        // Somewhere else, the star is missed
+        let far = Observer::new(Angle::from_degrees(-40.0), Angle::from_degrees(105.0), 0.0);
+        assert!(occultations(&star, &far, week).is_empty());
+
+        // The full moon covered Mars on the evening of 2025 January 13 for North America,
+        // from Washington, D.C. Mars disappeared around 9:16 p.m. EST and reappeared around 10:31 p.m.
+        let washington = Observer::new(Angle::from_degrees(38.9), Angle::from_degrees(-77.04), 0.0);
+        let night = Date::from_calendar(2025, 1, 13, Angle::default())
+            ..Date::from_calendar(2025, 1, 15, Angle::default());
+        let found = occultations(&sol::MARS, &washington, night);
+        assert_eq!(found.len(), 1);
+        let minutes = |d: Date, h: u8, m: u8| {
+            let expect = Date::from_calendar(2025, 1, 14, Angle::from_clock(h, m, 0.0));
+            (d.julian() - expect.julian()).abs() * 1440.0
+        };
+        assert!(minutes(found[0].disappearance, 2, 16) < 5.0);
+        assert!(minutes(found[0].reappearance, 3, 31) < 5.0);
+    }
+
+    #[test]
+    fn test_planetary_events() {
+        let on = |e: &PlanetEvent| {
+            let (_, m, d, _) = e.date.calendar();
+            (m, d)
+        };
+        let near = |e: &PlanetEvent, (m, d): (u8, u8)| {
+            let actual = Date::from_calendar(2025, m, d, Angle::from_clock(12, 0, 0.0));
+            (e.date.julian() - actual.julian()).abs() < 2.0
+        };
+
+        let venus = planetary_events(&sol::VENUS, 2025);
+        let kinds: Vec<PlanetEventKind> = venus.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                PlanetEventKind::GreatestElongation(Elongation::East),
+                PlanetEventKind::InferiorConjunction,
+                PlanetEventKind::GreatestElongation(Elongation::West),
+            ]
+        );
+        assert!(near(&venus[0], (1, 10)) && near(&venus[1], (3, 23)) && near(&venus[2], (6, 1)));
+        assert!(venus[1].elongation.degrees() < 10.0);
+
+        // Saturn is behind the sun on March 12, and opposite it on September 21
+        let saturn = planetary_events(&sol::SATURN, 2025);
+        assert_eq!(
+            saturn.len(),
+            2,
+            "{:?}",
+            saturn.iter().map(on).collect::<Vec<_>>()
+        );
+        assert_eq!(saturn[0].kind, PlanetEventKind::Conjunction);
+        assert!(near(&saturn[0], (3, 12)));
+        assert_eq!(saturn[1].kind, PlanetEventKind::Opposition);
+        assert!(near(&saturn[1], (9, 21)));
+        assert!(saturn[1].elongation.degrees() > 170.0);
+
+        // Mercury has several of each
+        let mercury = planetary_events(&sol::MERCURY, 2025);
+        assert!(mercury.len() >= 12);
+        assert!(mercury.windows(2).all(|w| w[0].date < w[1].date));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let obs = Observer::new(Angle::from_degrees(40.0), Angle::from_degrees(-75.0), 0.0);
+        let night = Date::from_calendar(2025, 3, 10, Angle::default());
+        let w = visibility(&sol::JUPITER, &obs, night).unwrap();
+        assert!(w.start < w.best && w.best < w.end);
+        // Jupiter is in the evening sky, visible from the end of twilight
+        assert!((altitude(&sol::SUN, &obs, w.start).to_degrees() + 12.0).abs() < 0.01);
+        assert!((altitude(&sol::JUPITER, &obs, w.end).to_degrees() - 10.0).abs() < 0.01);
+        assert!(w.best_altitude.to_latitude().degrees() > 10.0);
+        assert!(w.moon_illumfrac > 0.5);
+        // The same altitude as observing it, both of date, within the difference of their sidereal times
+        let plain = crate::celobj::ObserveOptions {
+            refraction: false,
+            parallax: false,
+        };
+        let seen = sol::JUPITER.observe_with(&obs, w.best, plain).altitude;
+        let diff = altitude(&sol::JUPITER, &obs, w.best) - seen.to_latitude().radians();
+        assert!(diff.abs() < 5e-6, "{diff}");
+        // The moon and Jupiter are compared in the same frame
+        let sep = MOON
+            .location_of_date(w.best)
+            .dist(sol::JUPITER.location_of_date(w.best));
+        assert_eq!(w.moon_separation, sep);
+
+        // The sun is never observable at night
+        assert_eq!(visibility(&sol::SUN, &obs, night), None);
+    }
+
+    #[test]
+    fn test_ring_plane_crossings() {
+        use RingPlaneCrossing::{Earth, Sun};
+        let year = |y| Date::from_calendar(y, 1, 1, Angle::default());
+        // 2025 March 23 and May 6, within the accuracy of the orbit of Saturn
+        let found = ring_plane_crossings(year(2025)..year(2026));
+        let kinds: Vec<RingPlaneCrossing> = found.iter().map(|e| e.0).collect();
+        assert_eq!(kinds, [Earth, Sun]);
+        for ((_, date), (m, d)) in found.iter().zip([(3, 23), (5, 6)]) {
+            let expected = Date::from_calendar(2025, m, d, Angle::default());
+            assert!((date.julian() - expected.julian()).abs() < 3.0);
+        }
+        // The earth crossed three times in 1995-1996
+        let found = ring_plane_crossings(year(1995)..year(1997));
+        let kinds: Vec<RingPlaneCrossing> = found.iter().map(|e| e.0).collect();
+        assert_eq!(kinds, [Earth, Earth, Sun, Earth]);
+    }
+
+    #[test]
+    fn test_galilean_events() {
+        let start = Date::from_calendar(2025, 1, 1, Angle::default());
+        let found = galilean_events(start..offset(start, 10.0));
+        assert!(found.windows(2).all(|w| w[0].start <= w[1].start));
+        let io: Vec<&GalileanEvent> = found
+            .iter()
+            .filter(|e| e.moon == GalileanMoon::Io && e.kind == GalileanEventKind::Transit)
+            .collect();
+        // Io goes around every 1.77 days, crossing the disk in a little over two hours
+        assert!(io.len() == 5 || io.len() == 6);
+        for w in io.windows(2) {
+            assert!((w[1].start.julian() - w[0].start.julian() - 1.769).abs() < 0.01);
+        }
+        for e in found {
+            let mid = Date::from_julian((e.start.julian() + e.end.julian()) / 2.0);
+            let p = sol::jupiter_moons(mid)[e.moon as usize];
+            if e.kind == GalileanEventKind::Transit {
+                assert!(p.z > 0.0 && p.x.abs() < 1.0);
+            }
+            let hours = (e.end.julian() - e.start.julian()) * 24.0;
+            assert!(hours > 0.0 && hours < 6.0);
+        }
+
+        // The triple shadow transit of 2015 January 24, when the shadows of Io, Europa, and Callisto were all on
+        // the disk from 06:28 UT, when the shadow of Europa came on, to 06:52 UT, when the shadow of Io left
+        let day = Date::from_calendar(2015, 1, 24, Angle::default());
+        let found = galilean_events(day..offset(day, 0.5));
+        let shadow = |moon| {
+            found
+                .iter()
+                .find(|e| e.moon == moon && e.kind == GalileanEventKind::ShadowTransit)
+                .unwrap()
+        };
+        let at = |h, m| Date::from_calendar(2015, 1, 24, Angle::from_clock(h, m, 0.0)).julian();
+        let minutes = 3.0 / 1440.0;
+        assert!((shadow(GalileanMoon::Europa).start.julian() - at(6, 28)).abs() < minutes);
+        assert!((shadow(GalileanMoon::Io).end.julian() - at(6, 52)).abs() < minutes);
+        assert!(shadow(GalileanMoon::Callisto).end.julian() > at(6, 52));
+    }
+}
@@ -34,23 +34,71 @@ Average of many tests, n = 40,000:
 | Full ephemeris | 3,406ns (3.4µs)      | 1,208,833ns (1.2ms) |
 
 # Structure
-This library contains 4 primary modules, which build upon the ones before them:
+This library contains 5 primary modules, which build upon the ones before them:
 1. [`time`] for the conversion and representation of times, dates, and angles.
 2. [`coord`] for the conversion and representation of coordinates.
 3. [`sol`] for the calculation of properties of planets and the sun.
 4. [`moon`] for the calculation of properties of the moon.
+5. [`star`] for stars, with proper motion and parallax, and with the `catalog` feature, `catalog` for bright stars and deep-sky objects.
+
+Along with [`misc`] for numerical helpers such as interpolation and blue moons, [`celobj`] for code generic over celestial objects,
+[`constellation`] for the constellation a coordinate is in, [`asteroid`] for minor planets from MPC orbital elements,
+[`comet`] for comets on any conic orbit, and [`events`] for searching for events in time.
 
 Each of these have one or two types that represent a certain kind of data:
 - [`Date`](time::Date) - An instant in continuous time.
 - [`Angle`](time::Angle) - An angle automatically corrected to be between \[0°, 360°\]. Which can also represent a time of day.
-- [`Coord`](coord::Coord) - A pair of angles, representing latitude/longitude on a sphere.
+- [`Latitude`](time::Latitude) - A signed angle between \[-90°, 90°\], such as a declination or altitude.
+- [`AngularRate`](time::AngularRate) - How fast an angle changes, such as a mean motion or proper motion.
+- [`Coord`](coord::Coord) - A pair of angles, representing latitude/longitude on a sphere, tagged with its [`Frame`](coord::Frame).
+- [`Observer`](coord::Observer) - A place on the earth, latitude, longitude, and elevation.
+- [`Distance`](coord::Distance) - A distance, convertible between astronomical units, kilometers, and light time.
+- [`Vector3`](coord::Vector3) - Rectangular coordinates, such as the position of a planet relative to the sun.
 - [`Planet`](sol::Planet) - A planets orbital properties, along with data required for orbital correction.
+- [`Satellite`](sol::Satellite) - The orbit of a moon of another planet around that planet.
 - [`Moon`](moon::Moon) - The moons orbital properties.
+- [`Star`](star::Star) - A stars position, proper motion, and parallax.
+- [`MinorPlanet`](asteroid::MinorPlanet) - An asteroids orbit, and its brightness in the H, G system.
+- [`Comet`](comet::Comet) - A comets orbit from its perihelion, and its brightness as it nears the sun.
 
 These types have methods to get the properties of this data. Primarily in pairs of methods that convert to/from a certain
 representation of that data. Although lone methods that get certain data for a type do exist.
+
+Constant angles and dates can be written with the [`angle!`] and [`date!`] macros.
+
+Fallible functions return their own error types, which all convert into the crate wide [`Error`].
+
+# Optional Features
+The library has no dependencies by default. Interoperability with other crates is opt-in:
+- `chrono` - Conversions between [`Date`](time::Date) and `chrono::DateTime<Utc>`/`chrono::NaiveDate`.
+- `time` - Conversions between [`Date`](time::Date) and `time::OffsetDateTime`.
+- `serde` - Serialization of [`Date`](time::Date), [`Angle`](time::Angle), [`AngularRate`](time::AngularRate), and [`Coord`](coord::Coord) in each frame,
+  [`MinorPlanet`](asteroid::MinorPlanet), [`Comet`](comet::Comet), along with serialization (but not deserialization) of [`Planet`](sol::Planet) and [`Satellite`](sol::Satellite).
+- `approx` - [`approx`](https://crates.io/crates/approx) comparisons of [`Angle`](time::Angle) and [`Coord`](coord::Coord).
+- `catalog` - The `catalog` module, with tables of bright stars and deep-sky objects.
+- `probe` - The experimental `probe` module, for spacecraft and other bodies from osculating elements.
 */
 
+#[cfg(test)]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        assert_approx_eq!(
+            $left,
+            $right,
+            $crate::time::Angle::from_degminsec(0, 1, 0.0)
+        )
+    };
+    ($left:expr, $right:expr, $tolerance:expr) => {{
+        let (left, right) = ($left, $right);
+        assert!(
+            left.approx_eq(right, $tolerance),
+            "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}",
+            left,
+            right
+        );
+    }};
+}
+
 pub mod time;
 
 pub mod coord;
@@ -59,6 +107,27 @@ pub mod sol;
 
 pub mod moon;
 
-// Since the Probe Module is experimental and will be in development until a method of getting comet positions is worked out, it is not shipped with the main library
-//pub mod probe;
-//pub mod celobj;
+pub mod star;
+
+pub mod asteroid;
+
+pub mod comet;
+
+#[cfg(feature = "catalog")]
+pub mod catalog;
+
+pub mod misc;
+
+pub mod celobj;
+pub use celobj::{bodies, lookup, register, CelObj, Observation};
+
+pub mod constellation;
+
+pub mod events;
+
+pub mod error;
+pub use error::Error;
+
+// Since the Probe Module is experimental and will be in development until a method of getting comet positions is worked out, it is only built with the `probe` feature
+#[cfg(feature = "probe")]
+pub mod probe;
@@ -0,0 +1,151 @@
+//! Miscellaneous numerical helpers
+//!
+//! Interpolation of tabulated angles, such as right ascensions from an almanac. Differences between the
+//! tabulated values are taken the shortest way around the circle, so a table that crosses 0h/360° is
+//! handled without any special care.
+//!
+//! ```
+//! # use pracstro::{misc, time::Angle};
+//! let ra = [
+//!     Angle::from_clock(23, 58, 0.0),
+//!     Angle::from_clock(0, 0, 0.0),
+//!     Angle::from_clock(0, 2, 0.0),
+//! ];
+//! misc::interpolate3(ra, 0.5).clock(); // (0, 1, 0.0)
+//! ```
+//!
+//! From Astronomical Algorithms by Jean Meeus, Chapter 3
+//!
+//! Calendar helpers for the phases of the moon, on top of [`Moon::phases_in()`](crate::moon::Moon::phases_in):
+//! - The full moons in a month: [`full_moons_in_month()`]
+//! - A second full moon in a month, a blue moon: [`is_blue_moon_month()`]
+//! - A month without a new moon, a black moon: [`is_black_moon_month()`]
+//!
+//! Months are in UTC, a phase near midnight on the first or last day of a month may fall in
+//! another month in local time.
+
+use crate::moon::{Phase, MOON};
+use crate::time::{Angle, Date};
+
+/// The difference `b - a` as signed radians, the shortest way around the circle
+fn diff(a: Angle, b: Angle) -> f64 {
+    b.signed_diff(a).radians()
+}
+
+/// Interpolates from three equally spaced values
+///
+/// `n` is the interpolating factor, 0 at the central value, -1 at the first, and 1 at the last.
+/// It should be kept within \[-0.5, 0.5\] for the best results, picking the central value closest to the wanted time.
+pub fn interpolate3(y: [Angle; 3], n: f64) -> Angle {
+    let (a, b) = (diff(y[0], y[1]), diff(y[1], y[2]));
+    let c = b - a;
+    y[1] + Angle::from_radians(n / 2.0 * (a + b + n * c))
+}
+
+/// Interpolates from five equally spaced values
+///
+/// `n` is the interpolating factor, 0 at the central value, -2 at the first, and 2 at the last.
+/// This is accurate for quickly changing values, like the position of the moon.
+pub fn interpolate5(y: [Angle; 5], n: f64) -> Angle {
+    let (a, b, c, d) = (
+        diff(y[0], y[1]),
+        diff(y[1], y[2]),
+        diff(y[2], y[3]),
+        diff(y[3], y[4]),
+    );
+    let (e, f, g) = (b - a, c - b, d - c);
+    let (h, j) = (f - e, g - f);
+    let k = j - h;
+    let n2 = n * n;
+    y[2] + Angle::from_radians(
+        n / 2.0 * (b + c)
+            + n2 / 2.0 * f
+            + n * (n2 - 1.0) / 12.0 * (h + j)
+            + n2 * (n2 - 1.0) / 24.0 * k,
+    )
+}
+
+/// The instants of the full moons in a month of the gregorian calendar, there may be none, one, or two
+/// ```
+/// # use pracstro::misc;
+/// assert_eq!(misc::full_moons_in_month(2023, 8).len(), 2);
+/// ```
+pub fn full_moons_in_month(year: i64, month: u8) -> Vec<Date> {
+    phases_in_month(year, month, Phase::Full)
+}
+
+/// If a month of the gregorian calendar has two full moons, the second being a blue moon
+pub fn is_blue_moon_month(year: i64, month: u8) -> bool {
+    full_moons_in_month(year, month).len() > 1
+}
+
+/// If a month of the gregorian calendar has no new moon, which can only happen in February
+///
+/// This is one of the meanings of a black moon, others are the second new moon in a month,
+/// or the third new moon in a season with four.
+pub fn is_black_moon_month(year: i64, month: u8) -> bool {
+    phases_in_month(year, month, Phase::New).is_empty()
+}
+
+fn phases_in_month(year: i64, month: u8, phase: Phase) -> Vec<Date> {
+    MOON.phases_in(year, month)
+        .into_iter()
+        .filter(|&(p, _)| p == phase)
+        .map(|(_, d)| d)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate3() {
+        let ra = [
+            Angle::from_clock(23, 58, 0.0),
+            Angle::from_clock(0, 0, 0.0),
+            Angle::from_clock(0, 2, 0.0),
+        ];
+        assert_approx_eq!(interpolate3(ra, 0.5), Angle::from_clock(0, 1, 0.0));
+        assert_approx_eq!(interpolate3(ra, -0.5), Angle::from_clock(23, 59, 0.0));
+        assert_approx_eq!(
+            Angle::lerp(ra[0], ra[2], 0.25),
+            Angle::from_clock(23, 59, 0.0),
+            Angle::from_degminsec(0, 0, 1e-6)
+        );
+        let (a, b) = (Angle::from_degrees(10.0), Angle::from_degrees(20.0));
+        assert_approx_eq!(a.lerp(b, -2.0), Angle::from_degrees(350.0));
+        assert!((0.0..360.0).contains(&a.lerp(b, -2.0).degrees()));
+        // Astronomical Algorithms, Example 3.a, the distance of the moon in units of 10,000 km
+        let dist = [0.884_226, 0.877_366, 0.870_531].map(Angle::from_radians);
+        assert!((interpolate3(dist, 0.18125).radians() - 0.876_125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_interpolate5() {
+        // A quartic crossing 360°, which five points reproduce exactly
+        let f = |x: f64| {
+            Angle::from_degrees(359.0 + x + 0.3 * x * x - 0.05 * x.powi(3) + 0.01 * x.powi(4))
+        };
+        let y = [-2.0, -1.0, 0.0, 1.0, 2.0].map(f);
+        for n in [-0.7, 0.25, 0.5, 1.3] {
+            assert_approx_eq!(interpolate5(y, n), f(n), Angle::from_degminsec(0, 0, 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_moon_months() {
+        // The full moons of 2023 August 1 and 31
+        let full = full_moons_in_month(2023, 8);
+        assert_eq!(
+            full.iter().map(|d| d.calendar().2).collect::<Vec<_>>(),
+            [1, 31]
+        );
+        assert!(is_blue_moon_month(2023, 8));
+        assert!(!is_blue_moon_month(2023, 9));
+        // February 2018 had no full moon, and February 2014 no new moon
+        assert!(full_moons_in_month(2018, 2).is_empty());
+        assert!(is_black_moon_month(2014, 2));
+        assert!(!is_black_moon_month(2014, 3));
+    }
+}
@@ -2,18 +2,59 @@
 
 Contains one main type, `Moon`, with methods for:
 
-* Location
-* Distance
+* Location, and how fast it moves [`Moon::velocity()`]
+* Distance, along with the dates of perigee and apogee [`Moon::next_apsis()`]
 * Illuminated Fraction
 * Phase Angle
+* Elongation from the sun [`Moon::elongation()`]
+* Phase, all at once, with its name
+* The instants of new moon, the quarters, and full moon [`Moon::next_phase()`], [`Moon::phases_in()`]
+* Lunation numbers and saros series [`Moon::lunation()`], [`Moon::saros()`]
+* The node of its orbit [`Moon::node()`], crossing it [`Moon::next_node()`], and standstills [`Moon::next_standstill()`]
+* Libration
+* All of the above from one calculation, see [`Moon::info()`]
+* A precise position, see [`Moon::info_precise()`]
 * Angular Diameter
-* Magnitude
+* Magnitude, also dimmed by the atmosphere [`Moon::magnitude_at()`]
 * Parallax
+* Topocentric location and angular diameter, as seen by an observer
+
+The mean elements of [`MOON`] are at the epoch 1980.0, see [`Moon::for_epoch()`] for others.
 */
-use crate::{
-    coord::{self, Coord},
-    sol, time,
-};
+use crate::{celobj::CelObj, coord, events, sol, time};
+use std::time::Duration;
+
+/// The mean length of a lunation, from one new moon to the next, in days
+const SYNODIC_MONTH: f64 = 29.53058868;
+
+/// The mean longitude, elongation, the suns mean anomaly, mean anomaly, and argument of latitude of the moon,
+/// at julian centuries since J2000
+///
+/// From Astronomical Algorithms by Jean Meeus, Ch. 47
+fn fundamental_arguments(t: f64) -> [time::Angle; 5] {
+    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+    [
+        218.3164477 + 481267.88123421 * t - 0.0015786 * t2 + t3 / 538841.0 - t4 / 65194000.0,
+        297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0,
+        357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0,
+        134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0,
+        93.2720950 + 483202.0175233 * t - 0.0036539 * t2 - t3 / 3526000.0 + t4 / 863310000.0,
+    ]
+    .map(time::Angle::from_degrees)
+}
+
+/// The mean longitude of the ascending node of the moon, at julian centuries since J2000
+///
+/// From Astronomical Algorithms by Jean Meeus, Ch. 47
+fn mean_node(t: f64) -> time::Angle {
+    time::Angle::from_degrees(
+        125.0445479 - 1934.1362891 * t + 0.0020754 * t * t + t * t * t / 467441.0
+            - t * t * t * t / 60616000.0,
+    )
+}
+
+/// The new moon of 2000 January 6, as a julian day
+const NEW_MOON_2000: f64 = 2451550.09766;
 
 /// Structure for the moons orbital properties at an epoch.
 ///
@@ -21,7 +62,7 @@ use crate::{
 /// The data contained in this is floating point instead of the Time/Coordinate types provided because static
 /// Time/Angle/Coordinate data using those types is unpleasant to construct. And because the code I've written before
 /// works with floats.
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct Moon {
     /// The epoch on which this data is based off
     pub epoch: f64,
@@ -53,21 +94,197 @@ pub const MOON: Moon = Moon {
     pi0: time::Angle::from_degrees(0.0024428825934),
 };
 
+/// Periodic terms for the longitude (Σl, 0.000001°) and distance (Σr, 0.001 km) of the moon
+///
+/// The multiples of D, M, M', and F, then the coefficients of the sine (longitude) and cosine (distance).
+///
+/// From Astronomical Algorithms by Jean Meeus, Table 47.A
+#[rustfmt::skip]
+const TERMS_LR: [(i8, i8, i8, i8, i32, i32); 60] = [
+    (0, 0, 1, 0, 6288774, -20905355),
+    (2, 0, -1, 0, 1274027, -3699111),
+    (2, 0, 0, 0, 658314, -2955968),
+    (0, 0, 2, 0, 213618, -569925),
+    (0, 1, 0, 0, -185116, 48888),
+    (0, 0, 0, 2, -114332, -3149),
+    (2, 0, -2, 0, 58793, 246158),
+    (2, -1, -1, 0, 57066, -152138),
+    (2, 0, 1, 0, 53322, -170733),
+    (2, -1, 0, 0, 45758, -204586),
+    (0, 1, -1, 0, -40923, -129620),
+    (1, 0, 0, 0, -34720, 108743),
+    (0, 1, 1, 0, -30383, 104755),
+    (2, 0, 0, -2, 15327, 10321),
+    (0, 0, 1, 2, -12528, 0),
+    (0, 0, 1, -2, 10980, 79661),
+    (4, 0, -1, 0, 10675, -34782),
+    (0, 0, 3, 0, 10034, -23210),
+    (4, 0, -2, 0, 8548, -21636),
+    (2, 1, -1, 0, -7888, 24208),
+    (2, 1, 0, 0, -6766, 30824),
+    (1, 0, -1, 0, -5163, -8379),
+    (1, 1, 0, 0, 4987, -16675),
+    (2, -1, 1, 0, 4036, -12831),
+    (2, 0, 2, 0, 3994, -10445),
+    (4, 0, 0, 0, 3861, -11650),
+    (2, 0, -3, 0, 3665, 14403),
+    (0, 1, -2, 0, -2689, -7003),
+    (2, 0, -1, 2, -2602, 0),
+    (2, -1, -2, 0, 2390, 10056),
+    (1, 0, 1, 0, -2348, 6322),
+    (2, -2, 0, 0, 2236, -9884),
+    (0, 1, 2, 0, -2120, 5751),
+    (0, 2, 0, 0, -2069, 0),
+    (2, -2, -1, 0, 2048, -4950),
+    (2, 0, 1, -2, -1773, 4130),
+    (2, 0, 0, 2, -1595, 0),
+    (4, -1, -1, 0, 1215, -3958),
+    (0, 0, 2, 2, -1110, 0),
+    (3, 0, -1, 0, -892, 3258),
+    (2, 1, 1, 0, -810, 2616),
+    (4, -1, -2, 0, 759, -1897),
+    (0, 2, -1, 0, -713, -2117),
+    (2, 2, -1, 0, -700, 2354),
+    (2, 1, -2, 0, 691, 0),
+    (2, -1, 0, -2, 596, 0),
+    (4, 0, 1, 0, 549, -1423),
+    (0, 0, 4, 0, 537, -1117),
+    (4, -1, 0, 0, 520, -1571),
+    (1, 0, -2, 0, -487, -1739),
+    (2, 1, 0, -2, -399, 0),
+    (0, 0, 2, -2, -381, -4421),
+    (1, 1, 1, 0, 351, 0),
+    (3, 0, -2, 0, -340, 0),
+    (4, 0, -3, 0, 330, 0),
+    (2, -1, 2, 0, 327, 0),
+    (0, 2, 1, 0, -323, 1165),
+    (1, 1, -1, 0, 299, 0),
+    (2, 0, 3, 0, 294, 0),
+    (2, 0, -1, -2, 0, 8752),
+];
+
+/// Periodic terms for the latitude (Σb, 0.000001°) of the moon
+///
+/// From Astronomical Algorithms by Jean Meeus, Table 47.B
+#[rustfmt::skip]
+const TERMS_B: [(i8, i8, i8, i8, i32); 60] = [
+    (0, 0, 0, 1, 5128122),
+    (0, 0, 1, 1, 280602),
+    (0, 0, 1, -1, 277693),
+    (2, 0, 0, -1, 173237),
+    (2, 0, -1, 1, 55413),
+    (2, 0, -1, -1, 46271),
+    (2, 0, 0, 1, 32573),
+    (0, 0, 2, 1, 17198),
+    (2, 0, 1, -1, 9266),
+    (0, 0, 2, -1, 8822),
+    (2, -1, 0, -1, 8216),
+    (2, 0, -2, -1, 4324),
+    (2, 0, 1, 1, 4200),
+    (2, 1, 0, -1, -3359),
+    (2, -1, -1, 1, 2463),
+    (2, -1, 0, 1, 2211),
+    (2, -1, -1, -1, 2065),
+    (0, 1, -1, -1, -1870),
+    (4, 0, -1, -1, 1828),
+    (0, 1, 0, 1, -1794),
+    (0, 0, 0, 3, -1749),
+    (0, 1, -1, 1, -1565),
+    (1, 0, 0, 1, -1491),
+    (0, 1, 1, 1, -1475),
+    (0, 1, 1, -1, -1410),
+    (0, 1, 0, -1, -1344),
+    (1, 0, 0, -1, -1335),
+    (0, 0, 3, 1, 1107),
+    (4, 0, 0, -1, 1021),
+    (4, 0, -1, 1, 833),
+    (0, 0, 1, -3, 777),
+    (4, 0, -2, 1, 671),
+    (2, 0, 0, -3, 607),
+    (2, 0, 2, -1, 596),
+    (2, -1, 1, -1, 491),
+    (2, 0, -2, 1, -451),
+    (0, 0, 3, -1, 439),
+    (2, 0, 2, 1, 422),
+    (2, 0, -3, -1, 421),
+    (2, 1, -1, 1, -366),
+    (2, 1, 0, 1, -351),
+    (4, 0, 0, 1, 331),
+    (2, -1, 1, 1, 315),
+    (2, -2, 0, -1, 302),
+    (0, 0, 1, 3, -283),
+    (2, 1, 1, -1, -229),
+    (1, 1, 0, -1, 223),
+    (1, 1, 0, 1, 223),
+    (0, 1, -2, -1, -220),
+    (2, 1, -1, -1, -220),
+    (1, 0, 1, 1, -185),
+    (2, -1, -2, -1, 181),
+    (0, 1, 2, 1, -177),
+    (4, 0, -2, -1, 176),
+    (4, -1, -1, -1, 166),
+    (1, 0, 1, -1, -164),
+    (4, 0, 1, -1, 132),
+    (1, 0, -1, -1, -119),
+    (4, -1, 0, -1, 115),
+    (2, -2, 0, 1, 107),
+];
+
 impl Moon {
-    /// Gets a ton of information about the moon that is used by other functions
+    /// The orbital properties of the moon at another epoch
+    ///
+    /// [`MOON`] is at the epoch 1980.0, and its mean motions are constant, so positions drift far from it.
+    /// The mean elements at the epoch are found from the polynomials in time used by [`Moon::info_precise()`].
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let d = time::Date::from_calendar(1066, 4, 24, time::Angle::default());
+    /// let location = moon::Moon::for_epoch(d).location(d);
+    /// ```
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 47
+    pub fn for_epoch(d: time::Date) -> Moon {
+        let t = d.centuries();
+        let lp = fundamental_arguments(t)[0];
+        Moon {
+            epoch: d.julian(),
+            l0: lp.degrees(),
+            n0: mean_node(t).degrees(),
+            ..MOON
+        }
+    }
+
+    /// The mean longitude of the perigee of the moon and the mean anomaly of the sun at the epoch, in degrees
+    ///
+    /// These come from the same polynomials as [`Moon::for_epoch()`] for every epoch, [`MOON`] included.
+    fn perigee_and_sun(self) -> (f64, f64) {
+        let [lp, _, m, mp, _] =
+            fundamental_arguments(time::Date::from_julian(self.epoch).centuries());
+        ((lp - mp).degrees(), m.degrees())
+    }
+
+    /// Gets a ton of information about the moon at a date, which the other methods read from
+    ///
+    /// Every other method finds the position of the moon again, when many properties are needed
+    /// at once, getting them from this is cheaper.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let info = moon::MOON.info(time::Date::from_calendar(2025, 4, 9, time::Angle::default()));
+    /// let (location, illumfrac, magnitude) = (info.location(), info.illumfrac(), info.magnitude());
+    /// ```
     ///
     /// From moontool.c by John Walker
-    fn mooninfo(self, d: time::Date) -> (time::Angle, coord::Coord, f64) {
+    pub fn info(self, d: time::Date) -> MoonInfo {
         /* Calculation of the Sun's position */
         let day = d.julian() - self.epoch; /* Date within epoch */
-        let m = time::Angle::from_degrees(((360.0 / 365.2422) * day) + 278.833540 - 282.596403); /* Convert from perigee co-ordinates to epoch 1980.0 */
-        let lambdasun = sol::SUN.location(d).ecliptic(d).0;
+        let (p0, m0) = self.perigee_and_sun();
+        let m = time::Angle::from_degrees(((360.0 / 365.2422) * day) + m0);
+        let lambdasun = sol::SUN.location(d).to_ecliptic(d).lon();
 
         // Moon's mean longitude
         let ml = time::Angle::from_degrees(13.1763966 * day + self.l0);
 
         // Moon's mean anomaly
-        let mm = time::Angle::from_degrees(ml.degrees() - 0.1114041 * day - 349.383063); /* 349:  Mean longitude of the perigee at the epoch */
+        let mm = time::Angle::from_degrees(ml.degrees() - 0.1114041 * day - p0);
 
         // Evection
         let ev = 1.2739 * ((ml - lambdasun) * 2.0 - mm).sin();
@@ -101,61 +318,710 @@ impl Moon {
 
         let dist = (self.a * (1.0 - self.e * self.e)) / (1.0 + self.e * (mmp + mec).cos());
 
-        (
+        MoonInfo {
+            moon: self,
             // Age of the Moon in degrees
-            lpp - lambdasun,
-            // Coordinates of the moon
-            coord::Coord::from_ecliptic(lambdamoon, betamoon, d),
-            // Distance from earth
+            age: lpp - lambdasun,
+            lambda: lambdamoon,
+            beta: betamoon,
+            node: np,
+            mean_longitude: ml,
+            date: d,
             dist,
-        )
+        }
+    }
+
+    /// The same as [`Moon::info()`], but with a far more accurate position of the moon
+    ///
+    /// This has an accuracy of around 10" in longitude and 4" in latitude, good enough for
+    /// occultations and timing conjunctions, but it is more expensive. The position is referred to the mean
+    /// equator and equinox of date, [`coord::Coord::nutate()`] gives the apparent one. The date is taken as
+    /// Terrestrial Time.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let info = moon::MOON.info_precise(time::Date::from_calendar(1992, 4, 12, time::Angle::default()));
+    /// assert!((info.distance().km() - 368409.7).abs() < 0.1);
+    /// ```
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 47
+    pub fn info_precise(self, d: time::Date) -> MoonInfo {
+        let t = d.centuries();
+        let lp = fundamental_arguments(t)[0];
+        let node = mean_node(t);
+        let (lambda, beta, dist) = self.ecliptic_precise(d);
+
+        // The sun is in the frame of J2000, and its apparent position trails by the aberration
+        let lambdasun = sol::SUN
+            .location(d)
+            .precess_rigorous(time::J2000, d)
+            .to_ecliptic(d)
+            .lon()
+            - time::Angle::from_degminsec(0, 0, 20.496);
+
+        MoonInfo {
+            moon: self,
+            age: lambda - lambdasun,
+            lambda,
+            beta,
+            node,
+            mean_longitude: lp,
+            date: d,
+            dist,
+        }
+    }
+
+    /// The ecliptic longitude and latitude of date, and distance in AU, of the moon, see [`Moon::info_precise()`]
+    ///
+    /// This doesn't depend on the position of the sun, so the sun can depend on it, see [`sol::earth_geocenter()`].
+    pub(crate) fn ecliptic_precise(self, d: time::Date) -> (time::Angle, time::Angle, f64) {
+        let t = d.centuries();
+        let t2 = t * t;
+        let [lp, dd, m, mp, f] = fundamental_arguments(t);
+
+        // Venus, Jupiter, and the flattening of the earth
+        let a1 = time::Angle::from_degrees(119.75 + 131.849 * t);
+        let a2 = time::Angle::from_degrees(53.09 + 479264.290 * t);
+        let a3 = time::Angle::from_degrees(313.45 + 481266.484 * t);
+        // The eccentricity of the earths orbit is decreasing
+        let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+
+        let arg = |d: i8, ms: i8, mps: i8, fs: i8| {
+            let ef = e.powi(ms.unsigned_abs() as i32);
+            let a = dd * d as f64 + m * ms as f64 + mp * mps as f64 + f * fs as f64;
+            (a, ef)
+        };
+        let (mut sl, mut sr) = (0.0, 0.0);
+        for &(d, ms, mps, fs, l, r) in TERMS_LR.iter() {
+            let (a, ef) = arg(d, ms, mps, fs);
+            let (sa, ca) = a.sincos();
+            sl += l as f64 * ef * sa;
+            sr += r as f64 * ef * ca;
+        }
+        let mut sb = 0.0;
+        for &(d, ms, mps, fs, b) in TERMS_B.iter() {
+            let (a, ef) = arg(d, ms, mps, fs);
+            sb += b as f64 * ef * a.sin();
+        }
+        sl += 3958.0 * a1.sin() + 1962.0 * (lp - f).sin() + 318.0 * a2.sin();
+        sb += -2235.0 * lp.sin()
+            + 382.0 * a3.sin()
+            + 175.0 * (a1 - f).sin()
+            + 175.0 * (a1 + f).sin()
+            + 127.0 * (lp - mp).sin()
+            - 115.0 * (lp + mp).sin();
+
+        let lambda = lp + time::Angle::from_degrees(sl / 1e6);
+        let beta = time::Angle::from_degrees(sb / 1e6);
+        let dist = coord::Distance::from_km(385000.56 + sr / 1000.0).au();
+        (lambda, beta, dist)
+    }
+
+    /// The coordinates of the moon, accurate to around 10", see [`Moon::info_precise()`]
+    pub fn location_precise(self, d: time::Date) -> coord::Coord {
+        self.info_precise(d).location()
+    }
+
+    /// The distance to the moon, accurate to a few kilometers, see [`Moon::info_precise()`]
+    pub fn distance_precise(self, d: time::Date) -> coord::Distance {
+        self.info_precise(d).distance()
     }
 
     /// Gets the cartesian coordinates of the moon in AU
-    pub fn locationcart(self, d: time::Date) -> (f64, f64, f64) {
-        let (_, crd, dist) = self.mooninfo(d);
-        Coord::cartesian(crd, dist)
+    pub fn locationcart(self, d: time::Date) -> coord::Vector3 {
+        self.info(d).locationcart()
     }
 
     /// Returns age of phase in Days
     pub fn phaseage(self, d: time::Date) -> f64 {
-        29.53058868 * self.mooninfo(d).0.turns()
+        self.info(d).phaseage()
     }
 
     /// Returns the illuminated fraction of the Moons surface
     pub fn illumfrac(self, d: time::Date) -> f64 {
-        (1.0 - self.mooninfo(d).0.cos()) / 2.0
+        self.info(d).illumfrac()
     }
 
     /// Returns the phase angle of the moon
     pub fn phaseangle(self, d: time::Date) -> time::Angle {
-        self.mooninfo(d).0
+        self.info(d).phaseangle()
+    }
+
+    /// Returns the phase of the moon, its illuminated fraction, age, phase angle, and name
+    ///
+    /// This is the same as calling [`Moon::illumfrac()`], [`Moon::phaseage()`], and [`Moon::phaseangle()`],
+    /// but the position of the moon is only found once.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let phase = moon::MOON.phase(time::Date::from_calendar(2025, 4, 13, time::Angle::default()));
+    /// assert_eq!(phase.name, moon::PhaseName::Full);
+    /// ```
+    pub fn phase(self, d: time::Date) -> MoonPhase {
+        self.info(d).phase()
+    }
+
+    /// The next instant of a principal phase of the moon after a date, to within a second
+    ///
+    /// This searches the phase angle from [`Moon::info_precise()`], so it is good to around a minute.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let d = time::Date::from_calendar(2025, 4, 1, time::Angle::default());
+    /// let full = moon::MOON.next_phase(d, moon::Phase::Full);
+    /// assert_eq!(full.calendar().2, 13);
+    /// ```
+    pub fn next_phase(self, d: time::Date, phase: Phase) -> time::Date {
+        let target = phase.angle();
+        // Where the phase would be if the moon moved at its mean rate, the true and mean phases
+        // are never more than a day apart, so the window of two days either side always holds it
+        let ahead = (target - self.info_precise(d).phaseangle()).turns() * SYNODIC_MONTH;
+        let (day, f) = d.jd_parts();
+        let start = time::Date::from_jd_parts(day, f + (ahead - 2.0).max(0.0));
+        let end = time::Date::from_jd_parts(day, f + ahead + 2.0);
+        events::find_crossing(
+            |d| {
+                (self.info_precise(d).phaseangle() - target)
+                    .to_latitude()
+                    .radians()
+            },
+            start..end,
+            Duration::from_secs(1),
+        )
+        .expect("the phase is within two days of where the mean motion of the moon puts it")
+    }
+
+    /// All principal phases of the moon in a month of the gregorian calendar, in order
+    /// ```
+    /// # use pracstro::moon;
+    /// for (phase, date) in moon::MOON.phases_in(2025, 4) {
+    ///     println!("{phase}: {:?}", date.calendar());
+    /// }
+    /// ```
+    pub fn phases_in(self, year: i64, month: u8) -> Vec<(Phase, time::Date)> {
+        let start = time::Date::from_calendar(year, month, 1, time::Angle::default());
+        let end = match month {
+            12 => time::Date::from_calendar(year + 1, 1, 1, time::Angle::default()),
+            _ => time::Date::from_calendar(year, month + 1, 1, time::Angle::default()),
+        };
+        let mut phases: Vec<(Phase, time::Date)> = Phase::ALL
+            .iter()
+            .flat_map(|&p| {
+                std::iter::successors(Some(self.next_phase(start, p)), move |&d| {
+                    let (day, f) = d.jd_parts();
+                    Some(self.next_phase(time::Date::from_jd_parts(day, f + 1.0), p))
+                })
+                .take_while(move |&d| d < end)
+                .map(move |d| (p, d))
+            })
+            .collect();
+        phases.sort_by_key(|&(_, d)| d);
+        phases
+    }
+
+    /// The Brown lunation number of the lunation a date is in
+    ///
+    /// Lunations are counted from one new moon to the next, lunation 1 began with the new moon of 1923 January 17.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// assert_eq!(moon::MOON.lunation(time::Date::from_calendar(2025, 4, 1, time::Angle::default())), 1265);
+    /// ```
+    pub fn lunation(self, d: time::Date) -> i32 {
+        let next = self.next_phase(d, Phase::New);
+        // Lunation 953 began with the new moon of 2000 January 6
+        ((next.julian() - NEW_MOON_2000) / SYNODIC_MONTH).round() as i32 + 952
     }
+
+    /// The number of the saros series of an eclipse, given a date during it
+    ///
+    /// An eclipse near new moon is taken to be solar, and near full moon lunar, they are numbered separately.
+    /// The number is meaningless if there is no eclipse at the date.
+    ///
+    /// Eclipses one saros (223 lunations) apart are in the same series, and one inex (358 lunations) apart
+    /// are in consecutive series. The series are numbered from the total solar eclipse of 2017 August 21 in
+    /// saros 145, and the total lunar eclipse of 2022 November 8 in saros 136.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let eclipse = time::Date::from_calendar(2024, 4, 8, time::Angle::from_clock(18, 17, 0.0));
+    /// assert_eq!(moon::MOON.saros(eclipse), 139);
+    /// ```
+    pub fn saros(self, eclipse: time::Date) -> i32 {
+        let k = (eclipse.julian() - NEW_MOON_2000) / SYNODIC_MONTH;
+        let (n, n0, s0) = match self.info_precise(eclipse).phaseangle().cos() > 0.0 {
+            true => (k.round() as i64, 218, 145),
+            false => ((k - 0.5).round() as i64, 282, 136),
+        };
+        // 38 is the inverse of 358 modulo 223, the number of inexes between the eclipses
+        let inex = ((n - n0) * 38).rem_euclid(223);
+        ((s0 - 1 + inex) % 223 + 1) as i32
+    }
+
+    /// The mean longitude of the ascending node of the orbit of the moon, where it crosses the ecliptic going north
+    ///
+    /// The node moves backwards around the ecliptic once every 18.6 years.
+    pub fn node(self, d: time::Date) -> time::Angle {
+        mean_node(d.centuries())
+    }
+
+    /// The next time the moon crosses the ecliptic after a date, at its ascending or descending node
+    ///
+    /// This searches the latitude from [`Moon::info_precise()`], to within a second.
+    pub fn next_node(self, d: time::Date) -> (NodeKind, time::Date) {
+        let beta = |d: time::Date| self.info_precise(d).beta.to_latitude().radians();
+        let (day, f) = d.jd_parts();
+        // The moon crosses the ecliptic twice a draconic month of 27.2 days, about 13.6 days apart
+        // Skip past a crossing at the date itself
+        let start = time::Date::from_jd_parts(day, f + 1.0 / 1440.0);
+        let end = time::Date::from_jd_parts(day, f + 16.0);
+        let date = events::find_crossing(beta, start..end, Duration::from_secs(1))
+            .expect("the moon crosses the ecliptic within 16 days");
+        let (nday, nf) = date.jd_parts();
+        let later = time::Date::from_jd_parts(nday, nf + 1.0 / 24.0);
+        match beta(later) > beta(date) {
+            true => (NodeKind::Ascending, date),
+            false => (NodeKind::Descending, date),
+        }
+    }
+
+    /// The next lunar standstill after a date, when the declination of the moon swings the furthest or least in a month
+    ///
+    /// At a major standstill the ascending node is at the March equinox, so the declination of the moon reaches
+    /// ±28.6°, at a minor standstill it is at the September equinox and the declination only reaches ±18.3°.
+    /// These are the times of the mean node, the monthly extremes are spread out over a year or so around them.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let (kind, date) = moon::MOON.next_standstill(time::Date::from_calendar(2020, 1, 1, time::Angle::default()));
+    /// assert_eq!(kind, moon::StandstillKind::Major);
+    /// ```
+    pub fn next_standstill(self, d: time::Date) -> (StandstillKind, time::Date) {
+        let (day, f) = d.jd_parts();
+        // A standstill is every half of the period of the node, 3399 days
+        let start = time::Date::from_jd_parts(day, f + 1.0);
+        let end = time::Date::from_jd_parts(day, f + 3500.0);
+        let date = events::find_crossing(
+            |d| self.node(d).sin(),
+            start..end,
+            Duration::from_secs(3600),
+        )
+        .expect("the node reaches an equinox within 3500 days");
+        match self.node(date).cos() > 0.0 {
+            true => (StandstillKind::Major, date),
+            false => (StandstillKind::Minor, date),
+        }
+    }
+
+    /// The next perigee or apogee of the moon after a date, and the distance to the moon then
+    ///
+    /// This searches the distance from [`Moon::info_precise()`]. The apogee is shallow, so it is only
+    /// found to within half an hour or so, the perigee to within a few minutes.
+    /// ```
+    /// # use pracstro::{moon, time};
+    /// let (kind, date, dist) = moon::MOON.next_apsis(time::Date::from_calendar(2025, 4, 1, time::Angle::default()));
+    /// ```
+    pub fn next_apsis(self, d: time::Date) -> (ApsisKind, time::Date, coord::Distance) {
+        let km = |d: time::Date| self.distance_precise(d).km();
+        let (day, f) = d.jd_parts();
+        // An apsis is at most half of an anomalistic month away, and the next one is further than that
+        let end = time::Date::from_jd_parts(day, f + 16.0);
+        let later = time::Date::from_jd_parts(day, f + 1.0 / 24.0);
+        let (kind, sign) = match km(later) > km(d) {
+            true => (ApsisKind::Apogee, -1.0),
+            false => (ApsisKind::Perigee, 1.0),
+        };
+        let date = events::find_minimum(|d| sign * km(d), d..end, Duration::from_secs(60));
+        (kind, date, self.distance_precise(date))
+    }
+
     /// Returns the coordinates of the moon
     ///
-    /// This code has a low accuracy of around 5 degrees
+    /// This code has a low accuracy of around 5 degrees, see [`Moon::location_precise()`] for a better one
     pub fn location(self, d: time::Date) -> coord::Coord {
-        self.mooninfo(d).1
+        self.info(d).location()
+    }
+
+    /// Returns the distance to the moon
+    pub fn distance(self, d: time::Date) -> coord::Distance {
+        self.info(d).distance()
+    }
+
+    /// Returns the coordinates of the moon as seen by an observer, see [`MoonInfo::location_topo()`]
+    pub fn location_topo(self, d: time::Date, obs: &coord::Observer) -> coord::Coord {
+        self.info(d).location_topo(obs)
+    }
+
+    /// Returns angular diameter of the moon as seen by an observer, see [`MoonInfo::angdia_topo()`]
+    pub fn angdia_topo(self, d: time::Date, obs: &coord::Observer) -> time::Angle {
+        self.info(d).angdia_topo(obs)
+    }
+
+    /// Returns the angle between the moon and the sun, and which side of the sun it is on, see [`MoonInfo::elongation()`]
+    pub fn elongation(self, d: time::Date) -> (time::Angle, sol::Elongation) {
+        self.info(d).elongation()
+    }
+
+    /// Returns the distance to the moon in kilometers, from [`Moon::distance_precise()`]
+    ///
+    /// This is not the same as `distance(d).km()`, which is from the low accuracy [`Moon::distance()`].
+    pub fn distance_precise_km(self, d: time::Date) -> f64 {
+        self.distance_precise(d).km()
+    }
+
+    /// How fast the moon is moving across the sky, in right ascension and declination
+    ///
+    /// Found from [`Moon::location_precise()`] with [`coord::sky_motion()`], as [`CelObj::sky_motion()`] is
+    /// from the location. The moon moves around 13° a day.
+    pub fn velocity(self, d: time::Date) -> (time::AngularRate, time::AngularRate) {
+        coord::sky_motion(|t| self.location_precise(t), d)
     }
 
-    /// Returns the distance to the moon in AU
-    pub fn distance(self, d: time::Date) -> f64 {
-        self.mooninfo(d).2
+    /// Magnitude of the moon as seen by an observer, dimmed by the atmosphere, see [`coord::extinction()`]
+    pub fn magnitude_at(self, d: time::Date, obs: &coord::Observer) -> f64 {
+        let info = self.info(d);
+        let alt = info.location_topo(obs).horizon_for(obs, d).1;
+        info.magnitude() + coord::extinction(alt)
     }
 
     /// Returns angular diameter of the planet at current time
     pub fn angdia(self, d: time::Date) -> time::Angle {
-        self.theta0 / self.distance(d)
+        self.info(d).angdia()
     }
 
     /// Calculates the moons horizontal parallax
     pub fn parallax(self, d: time::Date) -> time::Angle {
-        self.pi0 / self.distance(d)
+        self.info(d).parallax()
     }
 
     /// Magnitude of the moon
     pub fn magnitude(self, d: time::Date) -> f64 {
-        5.0 * (self.distance(d) / self.illumfrac(d).sqrt()).log10() + 0.21
+        self.info(d).magnitude()
+    }
+}
+
+/// The properties of the moon at a date, from [`Moon::info()`] or [`Moon::info_precise()`]
+///
+/// The position of the moon is found once, so each method is cheap.
+#[derive(Debug, Clone, Copy)]
+pub struct MoonInfo {
+    moon: Moon,
+    date: time::Date,
+    age: time::Angle,
+    lambda: time::Angle,
+    beta: time::Angle,
+    node: time::Angle,
+    mean_longitude: time::Angle,
+    dist: f64,
+}
+impl MoonInfo {
+    /// The date this is for
+    pub fn date(&self) -> time::Date {
+        self.date
+    }
+
+    /// The coordinates of the moon, see [`Moon::location()`]
+    pub fn location(&self) -> coord::Coord {
+        coord::Coord::new_ecliptic(self.lambda, self.beta, self.date).to_equatorial()
+    }
+
+    /// The cartesian coordinates of the moon in AU, see [`Moon::locationcart()`]
+    pub fn locationcart(&self) -> coord::Vector3 {
+        self.location().cartesian(self.dist)
+    }
+
+    /// The distance to the moon
+    pub fn distance(&self) -> coord::Distance {
+        coord::Distance::from_au(self.dist)
+    }
+
+    /// The age of the phase in days, see [`Moon::phaseage()`]
+    pub fn phaseage(&self) -> f64 {
+        SYNODIC_MONTH * self.age.turns()
+    }
+
+    /// The illuminated fraction of the moons surface
+    pub fn illumfrac(&self) -> f64 {
+        (1.0 - self.age.cos()) / 2.0
+    }
+
+    /// The phase angle of the moon, see [`Moon::phaseangle()`]
+    pub fn phaseangle(&self) -> time::Angle {
+        self.age
+    }
+
+    /// The phase of the moon, see [`Moon::phase()`]
+    pub fn phase(&self) -> MoonPhase {
+        let (illumfrac, waxing) = (self.illumfrac(), self.age.degrees() < 180.0);
+        MoonPhase {
+            illumfrac,
+            age: self.phaseage(),
+            angle: self.age,
+            name: PhaseName::new(illumfrac, waxing),
+            waxing,
+        }
+    }
+
+    /// The angular diameter of the moon
+    pub fn angdia(&self) -> time::Angle {
+        self.moon.theta0 / self.dist
+    }
+
+    /// The horizontal parallax of the moon
+    pub fn parallax(&self) -> time::Angle {
+        self.moon.pi0 / self.dist
+    }
+
+    /// The topocentric coordinates of the moon, as seen by an observer instead of from the center of the earth
+    ///
+    /// The moon is close enough that this shifts it by up to its horizontal parallax, about a degree.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 40
+    pub fn location_topo(&self, obs: &coord::Observer) -> coord::Coord {
+        self.topocentric(obs).0
+    }
+
+    /// The distance from an observer to the moon
+    pub fn distance_topo(&self, obs: &coord::Observer) -> coord::Distance {
+        coord::Distance::from_au(self.dist * self.topocentric(obs).1)
+    }
+
+    /// The angular diameter of the moon as seen by an observer, up to 2% larger when the moon is overhead
+    pub fn angdia_topo(&self, obs: &coord::Observer) -> time::Angle {
+        self.angdia() / self.topocentric(obs).1
+    }
+
+    /// Topocentric coordinates and the ratio of the topocentric to the geocentric distance
+    fn topocentric(&self, obs: &coord::Observer) -> (coord::Coord, f64) {
+        let location = self.location();
+        let (rs, rc) = obs.parallax_terms();
+        let sp = self.parallax().sin();
+        let ((sh, ch), (sd, cd)) = (
+            location.hour_angle(self.date, obs.lon).sincos(),
+            location.equatorial().1.sincos(),
+        );
+        let (x, y, z) = (cd * ch - rc * sp, cd * sh, sd - rs * sp);
+        let q = (x * x + y * y + z * z).sqrt();
+        (
+            coord::Coord::from_hour_angle(
+                time::Angle::atan2(y, x),
+                time::Angle::asin(z / q),
+                self.date,
+                obs.lon,
+            ),
+            q,
+        )
+    }
+
+    /// The angle between the moon and the sun, and which side of the sun the moon is on
+    ///
+    /// The moon is east of the sun while waxing.
+    pub fn elongation(&self) -> (time::Angle, sol::Elongation) {
+        (
+            time::Angle::acos(self.age.cos() * self.beta.cos()),
+            sol::Elongation::from_longitude(self.age),
+        )
+    }
+
+    /// The angle between the sun and the earth as seen from the moon, zero at full moon
+    ///
+    /// This takes the sun to be far enough away that the lines to it from the earth and moon are parallel,
+    /// which is off by at most 0.15°.
+    fn sun_angle(&self) -> f64 {
+        180.0 - self.elongation().0.degrees()
+    }
+
+    /// The magnitude of the moon
+    ///
+    /// Uses the phase law of Allen, which fits observations from full moon down to thin crescents.
+    /// The earthshine on the dark side of the moon is not included.
+    ///
+    /// From Astrophysical Quantities by C. W. Allen
+    pub fn magnitude(&self) -> f64 {
+        let i = self.sun_angle();
+        let r =
+            sol::SUN.distance(self.date).au() * coord::Distance::from_au(self.dist).km() / 384400.0;
+        -12.73 + 0.026 * i + 4e-9 * i.powi(4) + 5.0 * r.log10()
+    }
+
+    /// The optical libration in longitude and latitude, how far the moon is turned from its mean face towards the earth
+    ///
+    /// Positive values turn more of the east and north limbs (as seen on the moon) towards the earth.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 53, ignoring the physical libration and nutation
+    pub fn libration(&self) -> (time::Latitude, time::Latitude) {
+        // Inclination of the mean lunar equator to the ecliptic
+        let i = time::Angle::from_degrees(1.54242);
+        let w = self.lambda - self.node;
+        let f = self.mean_longitude - self.node;
+        let ((sw, cw), (sb, cb), (si, ci)) = (w.sincos(), self.beta.sincos(), i.sincos());
+        let a = time::Angle::atan2(sw * cb * ci - sb * si, cw * cb);
+        (
+            (a - f).into(),
+            time::Angle::asin(-sw * cb * si - sb * ci).into(),
+        )
+    }
+}
+
+/// The phase of the moon at a date, from [`Moon::phase()`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MoonPhase {
+    /// Illuminated fraction of the moons surface
+    pub illumfrac: f64,
+    /// Days since the new moon
+    pub age: f64,
+    /// Phase angle, see [`Moon::phaseangle()`]
+    pub angle: time::Angle,
+    /// Name of the phase
+    pub name: PhaseName,
+    /// If the illuminated part is growing, between the new moon and the full moon
+    pub waxing: bool,
+}
+
+/// The name of a phase of the moon
+///
+/// The new moon, quarters, and full moon are given when within a few percent of their illuminated fraction,
+/// so they last for around two days.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PhaseName {
+    /// New Moon
+    New,
+    /// Waxing Crescent
+    WaxingCrescent,
+    /// First Quarter
+    FirstQuarter,
+    /// Waxing Gibbous
+    WaxingGibbous,
+    /// Full Moon
+    Full,
+    /// Waning Gibbous
+    WaningGibbous,
+    /// Last Quarter
+    LastQuarter,
+    /// Waning Crescent
+    WaningCrescent,
+}
+impl PhaseName {
+    /// The phase from the illuminated fraction, and if the moon is waxing
+    pub fn new(illumfrac: f64, waxing: bool) -> Self {
+        match (illumfrac, waxing) {
+            (..0.04, _) => PhaseName::New,
+            (0.96.., _) => PhaseName::Full,
+            (0.46..0.54, true) => PhaseName::FirstQuarter,
+            (0.46..0.54, false) => PhaseName::LastQuarter,
+            (0.54.., true) => PhaseName::WaxingGibbous,
+            (0.54.., false) => PhaseName::WaningGibbous,
+            (_, true) => PhaseName::WaxingCrescent,
+            (_, false) => PhaseName::WaningCrescent,
+        }
+    }
+}
+impl std::fmt::Display for PhaseName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            PhaseName::New => "New",
+            PhaseName::WaxingCrescent => "Waxing Crescent",
+            PhaseName::FirstQuarter => "First Quarter",
+            PhaseName::WaxingGibbous => "Waxing Gibbous",
+            PhaseName::Full => "Full",
+            PhaseName::WaningGibbous => "Waning Gibbous",
+            PhaseName::LastQuarter => "Last Quarter",
+            PhaseName::WaningCrescent => "Waning Crescent",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One of the four principal phases of the moon, the instants found by [`Moon::next_phase()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Phase {
+    /// New Moon
+    New,
+    /// First Quarter
+    FirstQuarter,
+    /// Full Moon
+    Full,
+    /// Last Quarter
+    LastQuarter,
+}
+impl Phase {
+    /// All four phases, in the order they happen
+    pub const ALL: [Phase; 4] = [
+        Phase::New,
+        Phase::FirstQuarter,
+        Phase::Full,
+        Phase::LastQuarter,
+    ];
+
+    /// The phase angle of the moon at this phase, see [`Moon::phaseangle()`]
+    pub const fn angle(self) -> time::Angle {
+        time::Angle::from_degrees(match self {
+            Phase::New => 0.0,
+            Phase::FirstQuarter => 90.0,
+            Phase::Full => 180.0,
+            Phase::LastQuarter => 270.0,
+        })
+    }
+}
+impl From<Phase> for PhaseName {
+    fn from(p: Phase) -> Self {
+        match p {
+            Phase::New => PhaseName::New,
+            Phase::FirstQuarter => PhaseName::FirstQuarter,
+            Phase::Full => PhaseName::Full,
+            Phase::LastQuarter => PhaseName::LastQuarter,
+        }
+    }
+}
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        PhaseName::from(*self).fmt(f)
+    }
+}
+
+/// The closest or furthest point of the orbit of the moon, from [`Moon::next_apsis()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ApsisKind {
+    /// Closest to the earth
+    Perigee,
+    /// Furthest from the earth
+    Apogee,
+}
+
+/// Where the moon crosses the ecliptic, from [`Moon::next_node()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NodeKind {
+    /// Going north
+    Ascending,
+    /// Going south
+    Descending,
+}
+
+/// The extremes of the monthly swing in the declination of the moon, from [`Moon::next_standstill()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum StandstillKind {
+    /// The largest swing, the ascending node is at the March equinox
+    Major,
+    /// The smallest swing, the ascending node is at the September equinox
+    Minor,
+}
+
+/// The origin is the center of the earth, the same as [`Moon::locationcart()`]
+impl CelObj for Moon {
+    fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        Moon::locationcart(*self, d)
+    }
+    fn location(&self, d: time::Date) -> coord::Coord {
+        Moon::location(*self, d)
+    }
+    fn distance(&self, d: time::Date) -> coord::Distance {
+        Moon::distance(*self, d)
+    }
+    fn illumfrac(&self, d: time::Date) -> Option<f64> {
+        Some(Moon::illumfrac(*self, d))
+    }
+    fn magnitude(&self, d: time::Date) -> f64 {
+        Moon::magnitude(*self, d)
+    }
+    fn angdia(&self, d: time::Date) -> time::Angle {
+        Moon::angdia(*self, d)
+    }
+    fn name(&self) -> &str {
+        "Moon"
     }
 }
 
@@ -165,7 +1031,7 @@ mod tests {
 
     #[test]
     fn test_moonlocation() {
-        assert_eq!(
+        assert_approx_eq!(
             MOON.location(time::Date::from_julian(2460748.554861)),
             coord::Coord::from_equatorial(
                 time::Angle::from_degminsec(172, 11, 15.7),
@@ -183,7 +1049,7 @@ mod tests {
                 29,
                 time::Angle::default()
             )),
-            0.002799062630499616
+            0.0027988573560385754
         );
         assert_eq!(
             MOON.illumfrac(time::Date::from_calendar(
@@ -192,7 +1058,7 @@ mod tests {
                 09,
                 time::Angle::default()
             )),
-            0.8694887493109439
+            0.8694814938342236
         );
         assert_eq!(
             MOON.magnitude(time::Date::from_calendar(
@@ -201,17 +1067,266 @@ mod tests {
                 25,
                 time::Angle::default()
             )),
-            -11.366493493867907
+            -9.115539945466152
         );
+        // Around -12.7 when full, and it doesn't diverge when new
+        let full = MOON.magnitude(time::Date::from_calendar(
+            2025,
+            4,
+            13,
+            time::Angle::default(),
+        ));
+        assert!((full + 12.7).abs() < 0.3, "{full}");
+        let new = MOON.magnitude(time::Date::from_calendar(
+            2025,
+            3,
+            29,
+            time::Angle::from_clock(11, 0, 0.0),
+        ));
+        assert!(new.is_finite() && new > -5.0);
+        let obs =
+            coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+        let d = time::Date::from_calendar(2025, 4, 13, time::Angle::default());
+        assert!(MOON.magnitude_at(d, &obs) > MOON.magnitude(d));
     }
 
     #[test]
-    fn test_moondist() {
+    fn test_phase() {
+        let d = time::Date::from_calendar(2025, 4, 9, time::Angle::default());
+        let p = MOON.phase(d);
+        assert_eq!(p.illumfrac, MOON.illumfrac(d));
+        assert_eq!(p.age, MOON.phaseage(d));
+        assert_eq!(p.angle, MOON.phaseangle(d));
+        assert!(p.waxing);
+        assert_eq!(p.name, PhaseName::WaxingGibbous);
+        assert_eq!(p.name.to_string(), "Waxing Gibbous");
+
+        let p = MOON.phase(time::Date::from_calendar(
+            2025,
+            3,
+            29,
+            time::Angle::default(),
+        ));
+        assert_eq!(p.name, PhaseName::New);
+        assert_eq!(PhaseName::new(0.3, false), PhaseName::WaningCrescent);
+        assert_eq!(PhaseName::new(0.5, false), PhaseName::LastQuarter);
+    }
+
+    #[test]
+    fn test_info() {
+        let d = time::Date::from_calendar(2025, 4, 9, time::Angle::default());
+        let info = MOON.info(d);
+        assert_eq!(info.location(), MOON.location(d));
+        assert_eq!(info.distance().au(), MOON.distance(d).au());
+        assert_eq!(info.magnitude(), MOON.magnitude(d));
+        assert_eq!(info.angdia(), MOON.angdia(d));
+        assert_eq!(info.phase().name, MOON.phase(d).name);
+    }
+
+    #[test]
+    fn test_precise() {
+        // Example 47.a
+        let d = time::Date::from_calendar(1992, 4, 12, time::Angle::default());
+        let info = MOON.info_precise(d);
+        let ecl = info.location().to_ecliptic(d);
+        let (lambda, beta) = (ecl.lon(), ecl.lat().angle());
+        let arcsec = time::Angle::from_degminsec(0, 0, 1.0);
+        assert_approx_eq!(lambda, time::Angle::from_degrees(133.162655), arcsec);
+        assert_approx_eq!(beta, time::Angle::from_degrees(-3.229126), arcsec);
+        assert!((info.distance().km() - 368409.7).abs() < 0.1);
+
+        // Example 53.a, optical libration only
+        let (l, b) = info.libration();
+        assert_approx_eq!(l.angle(), time::Angle::from_degrees(-1.206));
+        assert_approx_eq!(b.angle(), time::Angle::from_degrees(4.194));
+
+        // The fast path agrees to within its few degrees
+        assert_approx_eq!(
+            MOON.location(d).dist(MOON.location_precise(d)),
+            time::Angle::default(),
+            time::Angle::from_degrees(5.0)
+        );
+    }
+
+    #[test]
+    fn test_topo() {
+        let d = time::Date::from_calendar(2025, 4, 9, time::Angle::from_clock(21, 0, 0.0));
+        let obs =
+            coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+        let seen = MOON.observe_with(
+            &obs,
+            d,
+            crate::celobj::ObserveOptions {
+                refraction: false,
+                parallax: true,
+            },
+        );
+        let arcsec = time::Angle::from_degminsec(0, 0, 1.0);
+        assert_approx_eq!(MOON.location_topo(d, &obs), seen.location, arcsec);
+        assert_approx_eq!(MOON.angdia_topo(d, &obs), seen.angdia, arcsec);
+        assert!(MOON.location(d).dist(seen.location) < MOON.parallax(d));
+
+        // From the center of the earth, nothing changes
+        let center = coord::Observer::new(
+            time::Angle::default(),
+            time::Angle::default(),
+            -coord::WGS84_A,
+        );
+        assert_approx_eq!(MOON.location_topo(d, &center), MOON.location(d), arcsec);
+    }
+
+    #[test]
+    fn test_next_phase() {
+        // Example 49.a, the new moon of 1977 February 18 at 3:37:42
+        let new = MOON.next_phase(
+            time::Date::from_calendar(1977, 2, 1, time::Angle::default()),
+            Phase::New,
+        );
+        let actual = time::Date::from_calendar(1977, 2, 18, time::Angle::from_clock(3, 37, 42.0));
+        assert!((new.julian() - actual.julian()).abs() < 2.0 / 1440.0);
+
+        let phases = MOON.phases_in(2025, 4);
+        let names: Vec<Phase> = phases.iter().map(|p| p.0).collect();
         assert_eq!(
-            MOON.distance(time::Date::from_julian(2460748.467894)),
-            0.0026765709280575905
+            names,
+            [
+                Phase::FirstQuarter,
+                Phase::Full,
+                Phase::LastQuarter,
+                Phase::New
+            ]
+        );
+        // 2025 April 13 00:22 UT
+        let full = time::Date::from_calendar(2025, 4, 13, time::Angle::from_clock(0, 22, 0.0));
+        assert!((phases[1].1.julian() - full.julian()).abs() < 3.0 / 1440.0);
+        assert_eq!(Phase::LastQuarter.to_string(), "Last Quarter");
+    }
+
+    #[test]
+    fn test_apsis() {
+        // Example 50.a, the apogee of 1988 October 7 at 20:30, with a parallax of 3240.679" (405 977 km)
+        let (kind, date, dist) = MOON.next_apsis(time::Date::from_calendar(
+            1988,
+            10,
+            1,
+            time::Angle::default(),
+        ));
+        let actual = time::Date::from_calendar(1988, 10, 7, time::Angle::from_clock(20, 30, 0.0));
+        assert_eq!(kind, ApsisKind::Apogee);
+        assert!((date.julian() - actual.julian()).abs() < 1.0 / 24.0);
+        assert!((dist.km() - 405977.0).abs() < 10.0);
+
+        let (kind, next, dist) = MOON.next_apsis(date);
+        assert_eq!(kind, ApsisKind::Perigee);
+        assert!((next.julian() - date.julian() - 13.8).abs() < 2.0);
+        assert!(dist.km() < 370000.0);
+    }
+
+    #[test]
+    fn test_lunation() {
+        let date =
+            |y, m, d, h| time::Date::from_calendar(y, m, d, time::Angle::from_clock(h, 0, 0.0));
+        assert_eq!(MOON.lunation(date(1923, 1, 20, 0)), 1);
+        assert_eq!(MOON.lunation(date(2000, 1, 6, 20)), 953);
+        assert_eq!(MOON.lunation(date(2000, 1, 6, 16)), 952);
+
+        // Solar
+        assert_eq!(MOON.saros(date(1999, 8, 11, 11)), 145);
+        assert_eq!(MOON.saros(date(2046, 8, 2, 10)), 146);
+        assert_eq!(MOON.saros(date(2026, 8, 12, 18)), 126);
+        // Lunar
+        assert_eq!(MOON.saros(date(2025, 3, 14, 7)), 123);
+        assert_eq!(MOON.saros(date(2025, 9, 7, 18)), 128);
+    }
+
+    #[test]
+    fn test_node() {
+        // Example 51.a, the ascending node of 1987 May 23 at 6:25:58
+        let (kind, date) = MOON.next_node(time::Date::from_calendar(
+            1987,
+            5,
+            15,
+            time::Angle::default(),
+        ));
+        let actual = time::Date::from_calendar(1987, 5, 23, time::Angle::from_clock(6, 25, 58.0));
+        assert_eq!(kind, NodeKind::Ascending);
+        assert!((date.julian() - actual.julian()).abs() < 5.0 / 1440.0);
+        assert_eq!(MOON.next_node(date).0, NodeKind::Descending);
+
+        let (kind, date) = MOON.next_standstill(time::Date::from_calendar(
+            2020,
+            1,
+            1,
+            time::Angle::default(),
+        ));
+        assert_eq!(kind, StandstillKind::Major);
+        assert_eq!(date.calendar().0, 2025);
+        let (kind, date) = MOON.next_standstill(date);
+        assert_eq!(kind, StandstillKind::Minor);
+        assert_eq!(date.calendar().0, 2034);
+    }
+
+    #[test]
+    fn test_velocity() {
+        let d = time::Date::from_calendar(1992, 4, 12, time::Angle::default());
+        let (ra, de) = MOON.velocity(d);
+        let (ra_fast, de_fast) = MOON.sky_motion(d);
+        assert!((10.0..17.0).contains(&ra.degrees_per_day()));
+        assert!(de.degrees_per_day().abs() < 7.0);
+        assert!((ra.degrees_per_day() - ra_fast.degrees_per_day()).abs() < 2.0);
+        assert!((de.degrees_per_day() - de_fast.degrees_per_day()).abs() < 2.0);
+        // Astronomical Algorithms by Jean Meeus, Example 47.a
+        assert!((MOON.distance_precise_km(d) - 368409.7).abs() < 0.5);
+        assert_eq!(MOON.distance_precise_km(d), MOON.distance_precise(d).km());
+    }
+
+    #[test]
+    fn test_for_epoch() {
+        // The elements of 1980.0 are found again
+        let moon = Moon::for_epoch(time::Date::from_julian(MOON.epoch));
+        let deg = |x: f64| time::Angle::from_degrees(x);
+        let tol = deg(0.01);
+        assert_approx_eq!(deg(moon.l0), deg(MOON.l0), tol);
+        assert_approx_eq!(deg(moon.n0), deg(MOON.n0), tol);
+        // So are the perigee and the solar anomaly of moontool.c
+        let (p, m) = MOON.perigee_and_sun();
+        assert_approx_eq!(deg(p), deg(349.383063), tol);
+        assert_approx_eq!(deg(m), deg(278.833540 - 282.596403), tol);
+
+        // And a thousand years ago, they keep the moon much closer to where it was
+        let d = time::Date::from_calendar(1025, 4, 9, time::Angle::default());
+        let precise = MOON.location_precise(d);
+        let (near, far) = (
+            Moon::for_epoch(d).location(d).dist(precise),
+            MOON.location(d).dist(precise),
+        );
+        assert!(near < far, "{near:?} {far:?}");
+    }
+
+    #[test]
+    fn test_elongation() {
+        // Around first quarter, the moon is 90° east of the sun
+        let (angle, side) = MOON.elongation(MOON.next_phase(
+            time::Date::from_calendar(2025, 4, 1, time::Angle::default()),
+            Phase::FirstQuarter,
+        ));
+        assert_eq!(side, sol::Elongation::East);
+        assert_approx_eq!(
+            angle,
+            time::Angle::from_degrees(90.0),
+            time::Angle::from_degrees(6.0)
         );
+        let d = time::Date::from_calendar(2025, 4, 21, time::Angle::default());
+        assert_eq!(MOON.elongation(d).1, sol::Elongation::West);
+    }
+
+    #[test]
+    fn test_moondist() {
         assert_eq!(
+            MOON.distance(time::Date::from_julian(2460748.467894)).au(),
+            0.00267657134894455
+        );
+        assert_approx_eq!(
             MOON.angdia(time::Date::from_julian(2460748.467894)),
             time::Angle::from_degrees(0.499999999)
         );
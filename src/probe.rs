@@ -9,7 +9,12 @@ Orbital property and correction numbers from <https://ssd.jpl.nasa.gov/planets/a
 and JPL Horizons <https://ssd.jpl.nasa.gov/horizons/>
 */
 
-use crate::{coord, sol::EARTH, time};
+use crate::{
+    celobj::CelObj,
+    coord,
+    sol::{self, EARTH},
+    time,
+};
 
 /// Generalized Planet Structure containing keplerian orbital properties and corrections.
 ///
@@ -39,53 +44,41 @@ pub struct SegmentedPlanet {
 impl SegmentedPlanet {
     /// Returns the location of the planets as rectangular coordinates as relative to the Sun, in AU
     ///
+    /// Hyperbolic orbits, with an eccentricity over 1, take the semi-major axis as negative, as JPL Horizons gives it.
+    /// Parabolic orbits have no semi-major axis, see [`Comet`](crate::comet::Comet) for those.
+    ///
     /// From <https://ssd.jpl.nasa.gov/planets/approx_pos.html>
     pub fn locationcart(&self, d: time::Date) -> (f64, f64, f64) {
         let t = (d.julian() - self.l_epoch.julian()) / 36525.0;
-        let a = self.a;
-        let e = self.e;
-        let i = time::Angle::from_degrees(self.i);
-        let o = time::Angle::from_degrees(self.o);
         let w = time::Angle::from_degrees(self.w);
-        let l = time::Angle::from_degrees(self.l + (self.l_delta_century * t));
-        let ww = w - o;
-        let mut m = (l - w).degrees();
-        m = time::Angle::from_degrees(m).to_latitude().degrees();
-
-        fn kepler(m: f64, e: f64, ee: f64) -> f64 {
-            let dm = m - (ee - e.to_degrees() * (ee.to_radians().sin()));
-            dm / (1.0 - e * (ee.to_radians()).cos())
-        }
-        let mut ee = m + 57.29578 * e * (m.to_radians().sin());
-        let mut de: f64 = 1.0;
-        while de.abs() > 1e-7 {
-            de = kepler(m, e, ee);
-            ee += de;
-        }
-
-        if e < 1.0 {
-            let v = 2.0
-                * ((1.0 + e).sqrt() * (ee.to_radians() / 2.0).sin())
-                    .atan2((1.0 - e).sqrt() * (ee.to_radians() / 2.0).cos());
-            eprintln!("{}", l.degrees());
-            let xp = a * ((ee.to_radians()).cos() - e);
-            let yp = a * (1.0 - e * e).sqrt() * (ee.to_radians().sin());
-
-            let xecl = (ww.cos() * o.cos() - ww.sin() * o.sin() * i.cos()) * xp
-                + (-ww.sin() * o.cos() - ww.cos() * o.sin() * i.cos()) * yp;
-            let yecl = (ww.cos() * o.sin() + ww.sin() * o.cos() * i.cos()) * xp
-                + (-ww.sin() * o.sin() + ww.cos() * o.cos() * i.cos()) * yp;
-            let zecl = (ww.sin() * i.sin()) * xp + (ww.cos() * i.sin()) * yp;
-
-            let eps = 23.43928_f64.to_radians();
-            let tx = xecl;
-            let ty = eps.cos() * yecl - eps.sin() * zecl;
-            let tz = eps.sin() * yecl + eps.cos() * zecl;
-
-            return (tx, ty, tz);
+        let o = time::Angle::from_degrees(self.o);
+        let i = time::Angle::from_degrees(self.i);
+        let l = self.l + (self.l_delta_century * t);
+
+        let ecl = if self.e < 1.0 {
+            let l = time::Angle::from_degrees(l);
+            sol::Elements::new(self.a, self.e, i, l, w, o, l - w).position()
         } else {
-            todo!();
-        }
+            // The hyperbolic form of Keplers equation, e sinh H - H = M, where the mean anomaly isn't reduced
+            let (a, e, m) = (self.a.abs(), self.e, (l - self.w).to_radians());
+            let mut h = (m / e).asinh();
+            for _ in 0..50 {
+                let dh = (e * h.sinh() - h - m) / (e * h.cosh() - 1.0);
+                h -= dh;
+                if dh.abs() < 1e-12 {
+                    break;
+                }
+            }
+            let (xp, yp) = (a * (e - h.cosh()), a * (e * e - 1.0).sqrt() * h.sinh());
+            sol::orbit_to_ecliptic(i, o, w - o, xp, yp)
+        };
+
+        let eps = 23.43928_f64.to_radians();
+        let tx = ecl.x;
+        let ty = eps.cos() * ecl.y - eps.sin() * ecl.z;
+        let tz = eps.sin() * ecl.y + eps.cos() * ecl.z;
+
+        (tx, ty, tz)
     }
 
     /// Returns coordinates as subtracted from the earths coordinates
@@ -93,24 +86,54 @@ impl SegmentedPlanet {
         let c = self.locationcart(d);
         let e = EARTH.locationcart(d);
 
-        coord::Coord::from_cartesian(c.0 - e.0, c.1 - e.1, c.2 - e.2)
+        coord::Coord::from_cartesian(c.0 - e.x, c.1 - e.y, c.2 - e.z)
     }
 
     /// Returns distance in AU
     pub fn distance(&self, d: time::Date) -> f64 {
         let c = self.locationcart(d);
         let e = EARTH.locationcart(d);
-        let (tx, ty, tz) = (c.0 - e.0, c.1 - e.1, c.2 - e.2);
+        let (tx, ty, tz) = (c.0 - e.x, c.1 - e.y, c.2 - e.z);
 
         (tx * tx + ty * ty + tz * tz).sqrt()
     }
 
-    fn sun_distance(&self, d: time::Date) -> f64 {
+    /// Returns the distance from the sun in AU
+    pub fn sun_distance(&self, d: time::Date) -> f64 {
         let (tx, ty, tz) = self.locationcart(d);
         (tx * tx + ty * ty + tz * tz).sqrt()
     }
 }
 
+/// The origin of [`CelObj::locationcart()`] is the sun, as with [`SegmentedPlanet::locationcart()`],
+/// but [`CelObj::location()`] and [`CelObj::distance()`] are as seen from the earth
+///
+/// There's no physical data for these objects, so the magnitude is NaN and the angular diameter is zero.
+impl CelObj for SegmentedPlanet {
+    fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        let (x, y, z) = SegmentedPlanet::locationcart(self, d);
+        coord::Vector3::new(x, y, z)
+    }
+    fn location(&self, d: time::Date) -> coord::Coord {
+        SegmentedPlanet::location(self, d)
+    }
+    fn location_of_date(&self, d: time::Date) -> coord::Coord {
+        SegmentedPlanet::location(self, d).precess_rigorous(time::J2000, d)
+    }
+    fn distance(&self, d: time::Date) -> coord::Distance {
+        coord::Distance::from_au(SegmentedPlanet::distance(self, d))
+    }
+    fn magnitude(&self, _: time::Date) -> f64 {
+        f64::NAN
+    }
+    fn angdia(&self, _: time::Date) -> time::Angle {
+        time::Angle::default()
+    }
+    fn name(&self) -> &str {
+        self.name
+    }
+}
+
 /// Voyager 2 Test Object
 pub const VOYAGER2TEST: SegmentedPlanet = SegmentedPlanet {
     name: "Voyager 2 Test of 1983-11-30",
@@ -177,19 +200,50 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_voy2831130() {
-        return;
-        let x = SUPERSURE.location(time::Date::from_calendar(
-            2025,
-            9,
-            9,
-            time::Angle::from_degrees(0.0),
-        ));
-        eprintln!(
-            "{}, {}",
-            x.equatorial().0.decimal(),
-            x.equatorial().1.to_latitude().degrees()
+    fn test_elliptical() {
+        // The same elements as the planet at J2000, without its rates of change, which move it little by 2025
+        let d = time::Date::from_calendar(2025, 9, 9, time::Angle::default());
+        assert_approx_eq!(
+            MARS.location(d),
+            sol::MARS.location(d),
+            time::Angle::from_degrees(0.1)
+        );
+        assert!((MARS.sun_distance(d) - sol::MARS.locationcart(d).norm()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_hyperbolic() {
+        let escaping = SegmentedPlanet {
+            name: "Escaping",
+            a: -2.0,
+            e: 1.5,
+            i: 0.0,
+            w: 30.0,
+            o: 0.0,
+            l: 30.0,
+            l_delta_century: 36525.0,
+            l_epoch: time::J2000,
+        };
+        // At the perihelion, q = a (1 - e) from the sun, toward the longitude of the perihelion
+        let (x, y, z) = escaping.locationcart(time::J2000);
+        assert!((escaping.sun_distance(time::J2000) - 1.0).abs() < 1e-12);
+        let eps = 23.43928_f64.to_radians();
+        let lon = (eps.cos() * y + eps.sin() * z).atan2(x);
+        assert!((lon.to_degrees() - 30.0).abs() < 1e-9);
+        // Keplers equation holds later on, r = |a| (e cosh H - 1)
+        let d = time::Date::from_julian(time::J2000.julian() + 100.0);
+        let m = 100f64.to_radians();
+        let r = escaping.sun_distance(d);
+        let h = ((r / 2.0 + 1.0) / 1.5).acosh();
+        assert!((1.5 * h.sinh() - h - m).abs() < 1e-9);
+        // Always further out, and the trait gives the same position as the planet
+        assert!(
+            r > 1.0
+                && r < escaping.sun_distance(time::Date::from_julian(time::J2000.julian() + 200.0))
         );
-        todo!();
+        assert_eq!(CelObj::name(&escaping), "Escaping");
+        assert_eq!(CelObj::location(&escaping, d), escaping.location(d));
+        assert!(CelObj::distance(&escaping, d).au() > 0.0);
+        assert!(VOYAGER2TEST.sun_distance(VOYAGER2TEST.l_epoch).is_finite());
     }
 }
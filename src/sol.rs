@@ -2,31 +2,48 @@
 
 This function has two main types, [`Planet`] and [`Sun`] With methods for:
 
-* Cartesian Coordinates
+* Cartesian Coordinates, and velocity [`Planet::statecart()`]
 * Distance from earth
-* Magnitude
+* Magnitude, with the phase laws of Mallama & Hilton [`MagnitudeLaw`]
 * Angular Diameter
 
 Planets also have methods for:
 
+* Heliocentric ecliptic coordinates [`Planet::heliocentric()`]
+* Orbital elements and anomalies at a date [`Planet::elements_at()`]
+* Elongation from the sun [`Planet::elongation()`]
+* Rise, transit, and set [`Planet::riseset()`]
+* Orbits of other bodies, built with validation by [`Planet::builder()`] or read by [`Planet::from_jpl_row()`]
 * Phase angle
 * Illuminated fraction
+* Position corrected for light time
+
+The sun also has sunrise and sunset with [`Sun::riseset()`], twilight with [`Sun::twilights()`],
+the subsolar point and terminator with [`Sun::subsolar()`] and [`Sun::terminator()`],
+and the orientation of its axis and Carrington rotation with [`Sun::physical_ephemeris()`].
+
+The rings of Saturn are described by [`saturn_ring()`], the positions of the moons of Jupiter by [`jupiter_moons()`],
+and the major moons of the other planets by [`Satellite`].
+
+Along with functions for solar time, such as [`equation_of_time()`], [`solar_noon()`], and [`apparent_solar_time()`],
+and the seasons with [`equinoxes_solstices()`]. Heliocentric positions are moved to the barycenter of the solar system
+by [`ssb_offset()`], and geocentric positions are from the center of the earth, [`earth_geocenter()`],
+rather than the Earth-Moon barycenter of [`EARTH`].
 
 ```
 use pracstro::{time, sol};
 
 let now = time::Date::now();
 for p in sol::PLANETS {
-    let (ra, de) =  p.location(now).equatorial();
-    let ((rah, ram, _), (ded, dem, _)) = (ra.clock(), de.to_latitude().degminsec());
-    println!("{:<10} {:>2}h{:02} RA {:>3}°{:02}' De {:.2} AU", p.name, rah, ram, ded, dem, p.distance(now));
+    println!("{:<10} {:.0} {:.2}", p.name, p.location(now), p.distance(now));
 }
 ```
 
 Orbital property and correction numbers from <https://ssd.jpl.nasa.gov/planets/approx_pos.html>
 */
 
-use crate::{coord, time};
+use crate::{celobj::CelObj, coord, events, time};
+use std::time::Duration;
 
 /// A blank type that represents the sun
 pub struct Sun;
@@ -35,32 +52,586 @@ pub const SUN: Sun = Sun;
 impl Sun {
     /// The geocentric rectangular coordinates of the sun relative to the earth, in AU
     ///
-    /// The inverse of the location of the earth relative to the sun
-    pub fn locationcart(&self, d: time::Date) -> (f64, f64, f64) {
-        let (x, y, z) = EARTH.locationcart(d);
-        (-x, -y, -z)
+    /// The inverse of the location of the center of the earth relative to the sun, see [`earth_geocenter()`]
+    pub fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        -earth_geocenter(d)
     }
 
     /// Calculate the coordinates of the sun at a given time
     pub fn location(&self, d: time::Date) -> coord::Coord {
-        let (x, y, z) = self.locationcart(d);
-        coord::Coord::from_cartesian(x, y, z)
+        self.locationcart(d).into()
     }
 
-    /// Calculate the distance to the sun, in AU
-    pub fn distance(&self, d: time::Date) -> f64 {
-        let (tx, ty, tz) = self.locationcart(d);
-        (tx * tx + ty * ty + tz * tz).sqrt()
+    /// Calculate the distance to the sun
+    pub fn distance(&self, d: time::Date) -> coord::Distance {
+        coord::Distance::from_au(self.locationcart(d).norm())
+    }
+
+    /// The location of the sun relative to the barycenter of the solar system, see [`ssb_offset()`]
+    pub fn location_barycentric(&self, d: time::Date) -> coord::Vector3 {
+        ssb_offset(d)
     }
 
     /// Calculate the angular diameter of the sun
     pub fn angdia(&self, d: time::Date) -> time::Angle {
-        time::Angle::from_degrees(0.5333333333) / self.distance(d)
+        time::Angle::from_degrees(0.5333333333) / self.distance(d).au()
+    }
+
+    /// The subsolar point, the (latitude, longitude) on the earth where the sun is directly overhead
+    ///
+    /// As from [`coord::Coord::sub_point()`], the longitude is signed and positive to the east.
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let (lat, lon) = sol::SUN.subsolar(time::Date::from_calendar(2025, 6, 21, time::Angle::default()));
+    /// lat.degrees(); // About 23.4°, over the tropic of cancer
+    /// lon.degrees(); // About -179.6°, over the Pacific
+    /// ```
+    pub fn subsolar(&self, d: time::Date) -> (time::Latitude, time::Angle) {
+        self.location_of_date(d).sub_point(d)
+    }
+
+    /// Points on the terminator, the line between day and night on the earth, as (latitude, longitude)
+    ///
+    /// These are `n` evenly spaced points around the great circle 90° from the subsolar point, without correcting
+    /// for refraction or the size of the sun. Each point is given as in [`Sun::subsolar()`].
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// for (lat, lon) in sol::SUN.terminator(time::Date::now(), 36) {
+    ///     println!("{:.1} {:.1}", lat.degrees(), lon.degrees());
+    /// }
+    /// ```
+    pub fn terminator(&self, d: time::Date, n: usize) -> Vec<(time::Latitude, time::Angle)> {
+        let (lat, lon) = self.subsolar(d);
+        let (slat, clat) = lat.angle().sincos();
+        (0..n)
+            .map(|k| {
+                let (sb, cb) = time::Angle::from_turns(k as f64 / n as f64).sincos();
+                let plat = time::Angle::asin(clat * cb);
+                (
+                    time::Latitude::from(plat),
+                    (lon + time::Angle::atan2(sb * clat, -slat * plat.sin())).to_latitude(),
+                )
+            })
+            .collect()
+    }
+
+    /// The orientation of the sun as seen from the earth, and the Carrington rotation, used to locate sunspots
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let eph = sol::SUN.physical_ephemeris(time::Date::from_calendar(1992, 10, 13, time::Angle::default()));
+    /// assert!((eph.p.to_latitude().degrees() - 26.27).abs() < 0.01);
+    /// assert_eq!(eph.carrington.floor(), 1861.0); // Rotation 1861
+    /// ```
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Chapter 29
+    pub fn physical_ephemeris(&self, d: time::Date) -> PhysicalEphemeris {
+        let jd = d.julian();
+        let theta = time::Angle::from_degrees((jd - 2398220.0) * 360.0 / 25.38);
+        let i = time::Angle::from_degrees(7.25);
+        let k = time::Angle::from_degrees(73.6667 + 1.3958333 * (jd - 2396758.0) / 36525.0);
+        let lambda = self
+            .location(d)
+            .precess(time::J2000, d)
+            .to_ecliptic(d)
+            .lon()
+            - time::Angle::from_degrees(20.496 / 3600.0);
+        let lambda_nut = lambda + coord::nutation(d).0;
+        let eps = coord::true_obliquity_ecl(d);
+
+        let (slk, clk) = (lambda - k).sincos();
+        let x = (-lambda_nut.cos() * eps.tan()).atan();
+        let y = (-clk * i.tan()).atan();
+        let eta = time::Angle::atan2(-slk * i.cos(), -clk);
+        let l0 = eta - theta;
+
+        // The rotation number increases as the central meridian passes 0°
+        let approx = (jd - 2398140.2270) / 27.2752316;
+        let frac = 1.0 - l0.degrees() / 360.0;
+        PhysicalEphemeris {
+            p: time::Angle::from_radians(x + y),
+            b0: time::Angle::asin(slk * i.sin()),
+            l0,
+            carrington: (approx - frac).round() + frac,
+        }
+    }
+
+    /// Rise, transit, and set of the sun on the UT day of a date, for when its center crosses an altitude
+    ///
+    /// The position of the sun is followed through the day, and precessed to the date. See [`coord::riseset_moving()`].
+    pub fn riseset_altitude(
+        &self,
+        d: time::Date,
+        obs: &coord::Observer,
+        h0: time::Angle,
+    ) -> Result<coord::RiseSet, coord::NoRiseSet> {
+        coord::riseset_moving(|d| self.location(d).precess(time::J2000, d), d, obs, h0)
+    }
+
+    /// Sunrise, transit, and sunset on the UT day of a date
+    ///
+    /// The sun rises and sets when its upper limb touches the horizon, with the center 0°50' below it from
+    /// refraction and the semidiameter of the sun, and further by the dip of the horizon for an observer above
+    /// sea level. This is the convention used by almanacs.
+    /// ```
+    /// # use pracstro::{coord, sol, time};
+    /// let london = coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+    /// let rs = sol::SUN.riseset(time::Date::from_calendar(2025, 3, 20, time::Angle::default()), &london).unwrap();
+    /// // About 06:02 UT
+    /// let expected = time::Date::from_calendar(2025, 3, 20, time::Angle::from_clock(6, 2, 0.0));
+    /// assert!((rs.rise.julian() - expected.julian()).abs() < 2.0 / 1440.0);
+    /// ```
+    pub fn riseset(
+        &self,
+        d: time::Date,
+        obs: &coord::Observer,
+    ) -> Result<coord::RiseSet, coord::NoRiseSet> {
+        self.riseset_altitude(d, obs, coord::H0_SUN - obs.dip())
+    }
+
+    /// Sunrise, sunset, and the twilights on the UT day of a date
+    /// ```
+    /// # use pracstro::{coord, sol, time};
+    /// let london = coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+    /// let t = sol::SUN.twilights(time::Date::from_calendar(2025, 6, 21, time::Angle::default()), &london);
+    /// assert!(t.civil.is_ok());
+    /// assert_eq!(t.astronomical, Err(coord::NoRiseSet::Circumpolar)); // It never gets fully dark
+    /// ```
+    pub fn twilights(&self, d: time::Date, obs: &coord::Observer) -> Twilights {
+        let span = |h0| {
+            self.riseset_altitude(d, obs, h0)
+                .map(|rs| (rs.rise, rs.set))
+        };
+        let day = self.riseset(d, obs).map(|rs| (rs.rise, rs.set));
+        Twilights {
+            sunrise: day.map(|(rise, _)| rise),
+            sunset: day.map(|(_, set)| set),
+            civil: span(coord::H0_CIVIL),
+            nautical: span(coord::H0_NAUTICAL),
+            astronomical: span(coord::H0_ASTRONOMICAL),
+        }
     }
 
     /// Visual Magnitude of the sun
     pub fn magnitude(&self, d: time::Date) -> f64 {
-        5.0 * self.distance(d).log10() - 26.74
+        5.0 * self.distance(d).au().log10() - 26.74
+    }
+}
+
+/// The origin is the center of the earth, the same as [`Sun::locationcart()`]
+impl CelObj for Sun {
+    fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        Sun::locationcart(self, d)
+    }
+    fn location_of_date(&self, d: time::Date) -> coord::Coord {
+        Sun::location(self, d).precess_rigorous(time::J2000, d)
+    }
+    fn magnitude(&self, d: time::Date) -> f64 {
+        Sun::magnitude(self, d)
+    }
+    fn angdia(&self, d: time::Date) -> time::Angle {
+        Sun::angdia(self, d)
+    }
+    fn name(&self) -> &str {
+        "Sun"
+    }
+}
+
+/// Sunrise, sunset, and the twilights of a day, from [`Sun::twilights()`]
+///
+/// Each twilight is the start of morning twilight and the end of evening twilight, when the center of the sun
+/// is 6° (civil), 12° (nautical), or 18° (astronomical) below the horizon. If the sun doesn't get that low or
+/// high during the day, the reason is given instead.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Twilights {
+    /// When the upper limb of the sun rises, see [`Sun::riseset()`]
+    pub sunrise: Result<time::Date, coord::NoRiseSet>,
+    /// When the upper limb of the sun sets, see [`Sun::riseset()`]
+    pub sunset: Result<time::Date, coord::NoRiseSet>,
+    /// Civil twilight, when it is light enough for most outdoor activities
+    pub civil: Result<(time::Date, time::Date), coord::NoRiseSet>,
+    /// Nautical twilight, when the horizon can be seen at sea
+    pub nautical: Result<(time::Date, time::Date), coord::NoRiseSet>,
+    /// Astronomical twilight, after which the sky is fully dark
+    pub astronomical: Result<(time::Date, time::Date), coord::NoRiseSet>,
+}
+
+/// The physical ephemeris of the sun, from [`Sun::physical_ephemeris()`]
+///
+/// All of the angles are in the heliographic coordinates of Carrington, use [`time::Angle::to_latitude()`]
+/// to get the signed values of `p` and `b0`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PhysicalEphemeris {
+    /// Position angle of the northern end of the axis of rotation, measured eastward from the north point of the disk
+    pub p: time::Angle,
+    /// Heliographic latitude of the center of the disk
+    pub b0: time::Angle,
+    /// Heliographic longitude of the center of the disk, the central meridian
+    pub l0: time::Angle,
+    /// The Carrington rotation number, with the fraction of the rotation completed
+    ///
+    /// Rotation 1 began on 1853 November 9, each one starts when `l0` passes 360°.
+    pub carrington: f64,
+}
+
+/// The equation of time, the difference between apparent and mean solar time
+///
+/// Positive values mean a sundial is ahead of a clock, use [`time::Angle::to_latitude()`] to get the signed value.
+/// ```
+/// # use pracstro::{sol, time};
+/// sol::equation_of_time(time::Date::from_calendar(2025, 11, 3, time::Angle::default())).to_latitude().decimal() * 60.0; // About 16.4 minutes
+/// ```
+///
+/// From Astronomical Algorithms by Jean Meeus, Chapter 28
+pub fn equation_of_time(d: time::Date) -> time::Angle {
+    let tau = d.centuries() / 10.0;
+    let l0 = time::Angle::from_degrees(
+        [
+            280.4664567,
+            360007.6982779,
+            0.03032028,
+            1.0 / 49931.0,
+            -1.0 / 15300.0,
+            -1.0 / 2e6,
+        ]
+        .iter()
+        .rev()
+        .fold(0.0, |sum, c| sum * tau + c),
+    );
+    // The apparent right ascension, trailing by the aberration and corrected for nutation
+    let ecl = SUN
+        .location(d)
+        .precess_rigorous(time::J2000, d)
+        .to_ecliptic(d);
+    let lambda = ecl.lon() - time::Angle::from_degminsec(0, 0, 20.4898 / SUN.distance(d).au());
+    let alpha = coord::Coord::new_ecliptic(lambda, ecl.lat().angle(), d)
+        .to_equatorial()
+        .nutate(d)
+        .equatorial()
+        .0;
+    let dpsi = coord::nutation(d).0.to_latitude().radians();
+    let eps = coord::true_obliquity_ecl(d);
+    l0 - time::Angle::from_degrees(0.0057183) - alpha + time::Angle::from_radians(dpsi * eps.cos())
+}
+
+/// The instant of solar noon (when the sun crosses the meridian) at a longitude, positive to the east
+///
+/// The noon returned is the one nearest to local mean noon on the UT calendar date of `d`.
+pub fn solar_noon(d: time::Date, longi: time::Angle) -> time::Date {
+    let (day, midnight) = time::Date::from_time(d, time::Angle::default()).jd_parts();
+    let mean_noon = midnight + 0.5 - longi.to_latitude().turns();
+    let mut noon = time::Date::from_jd_parts(day, mean_noon);
+    for _ in 0..2 {
+        let eot = equation_of_time(noon).to_latitude().turns();
+        noon = time::Date::from_jd_parts(day, mean_noon - eot);
+    }
+    noon
+}
+
+/// Local apparent solar time, what a sundial shows, at a longitude positive to the east
+///
+/// This is the local mean time (UT offset by longitude) corrected by the [`equation_of_time()`].
+/// ```
+/// # use pracstro::{sol, time};
+/// let d = time::Date::from_calendar(2025, 2, 11, time::Angle::from_clock(17, 14, 11.0));
+/// sol::apparent_solar_time(d, time::Angle::from_degrees(-75.0)).clock(); // About (12, 0, 0.0)
+/// ```
+pub fn apparent_solar_time(d: time::Date, longi: time::Angle) -> time::Angle {
+    d.time() + longi + equation_of_time(d)
+}
+
+/// The hour angle of the sun at a longitude, the angle a sundial's shadow is turned from noon
+///
+/// Negative in the morning and positive in the afternoon, after [`time::Angle::to_latitude()`].
+pub fn solar_hour_angle(d: time::Date, longi: time::Angle) -> time::Angle {
+    apparent_solar_time(d, longi) - time::Angle::from_clock(12, 0, 0.0)
+}
+
+/// The instants of the March equinox, June solstice, September equinox, and December solstice in a year
+///
+/// These are the times the apparent ecliptic longitude of the sun crosses 0°, 90°, 180°, and 270°.
+/// Found by searching five days on either side of the mean instant with [`events::find_crossing()`],
+/// as in Astronomical Algorithms by Jean Meeus, Chapter 27. The search itself stops within a tenth of a second,
+/// but the position of the sun is only good to around 20", so the instants are within about eight minutes.
+/// ```
+/// # use pracstro::sol;
+/// let [march, june, september, december] = sol::equinoxes_solstices(2025);
+/// ```
+pub fn equinoxes_solstices(year: i64) -> [time::Date; 4] {
+    [0, 1, 2, 3].map(|k| {
+        let target = time::Angle::from_degrees(90.0 * k as f64);
+        // The mean instant, from Table 27.b, which is within a day of the true one for thousands of years
+        let y = (year as f64 - 2000.0) / 1000.0;
+        let mean = [
+            [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+            [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+            [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+            [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+        ][k as usize]
+            .iter()
+            .rev()
+            .fold(0.0, |sum, c| sum * y + c);
+        let around = |days: f64| time::Date::from_julian(mean + days);
+        let offset = |d: time::Date| {
+            // Nutation moves the equinox by Δψ, and aberration shifts the apparent sun back by 20.5"
+            let lambda = SUN
+                .location(d)
+                .precess_rigorous(time::J2000, d)
+                .to_ecliptic(d)
+                .lon()
+                + coord::nutation(d).0
+                - time::Angle::from_degminsec(0, 0, 20.4898 / SUN.distance(d).au());
+            (lambda - target).to_latitude().radians()
+        };
+        events::find_crossing(
+            offset,
+            around(-5.0)..around(5.0),
+            Duration::from_millis(100),
+        )
+        .expect("the sun reaches each season within five days of its mean instant")
+    })
+}
+
+/// Rotates rectangular coordinates from the ecliptic frame of J2000 to the equatorial frame
+pub(crate) fn to_equatorial(ecl: coord::Vector3) -> coord::Vector3 {
+    let (seps, ceps) = 23.43928_f64.to_radians().sin_cos();
+    let tx = ecl.x;
+    let ty = ceps * ecl.y - seps * ecl.z;
+    let tz = seps * ecl.y + ceps * ecl.z;
+
+    coord::Vector3::new(tx, ty, tz)
+}
+
+/// Which side of the sun an object is on, from [`Planet::elongation()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Elongation {
+    /// East of the sun, in the evening sky
+    East,
+    /// West of the sun, in the morning sky
+    West,
+}
+impl Elongation {
+    /// The side of the sun for a difference in ecliptic longitude from it
+    pub fn from_longitude(diff: time::Angle) -> Self {
+        match diff.degrees() < 180.0 {
+            true => Elongation::East,
+            false => Elongation::West,
+        }
+    }
+}
+
+/// Keplerian orbital elements at a date, along with the anomalies of the position in the orbit
+///
+/// Angles are in the ecliptic frame of J2000, from [`Planet::elements_at()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Elements {
+    /// Semi-Major Axis (AU)
+    pub a: f64,
+    /// Eccentricity
+    pub e: f64,
+    /// Inclination
+    pub i: time::Angle,
+    /// Mean longitude
+    pub l: time::Angle,
+    /// Longitude of the Periapsis
+    pub w: time::Angle,
+    /// Longitude of the ascending node
+    pub o: time::Angle,
+    /// Mean anomaly
+    pub m: time::Angle,
+    /// Eccentric anomaly
+    pub ea: time::Angle,
+    /// True anomaly
+    pub nu: time::Angle,
+}
+impl Elements {
+    /// Elements of an elliptical orbit, solving Keplers equation for the eccentric and true anomalies
+    ///
+    /// The mean anomaly is usually `l - w`, but is given separately for corrections to it.
+    ///
+    /// From <https://ssd.jpl.nasa.gov/planets/approx_pos.html>
+    pub fn new(
+        a: f64,
+        e: f64,
+        i: time::Angle,
+        l: time::Angle,
+        w: time::Angle,
+        o: time::Angle,
+        m: time::Angle,
+    ) -> Self {
+        let m = m.to_latitude().degrees();
+        fn kepler(m: f64, e: f64, ee: f64) -> f64 {
+            let dm = m - (ee - e.to_degrees() * (ee.to_radians().sin()));
+            dm / (1.0 - e * (ee.to_radians()).cos())
+        }
+        let mut ee = m + 57.29578 * e * (m.to_radians().sin());
+        let mut de: f64 = 1.0;
+        while de.abs() > 1e-7 {
+            de = kepler(m, e, ee);
+            ee += de;
+        }
+        let ea = time::Angle::from_degrees(ee);
+        let half = ea / 2.0;
+        let nu =
+            time::Angle::atan2((1.0 + e).sqrt() * half.sin(), (1.0 - e).sqrt() * half.cos()) * 2.0;
+        Elements {
+            a,
+            e,
+            i,
+            l,
+            w,
+            o,
+            m: time::Angle::from_degrees(m),
+            ea,
+            nu,
+        }
+    }
+
+    /// Distance from the focus of the orbit, the sun for a planet, in AU
+    pub fn radius(&self) -> f64 {
+        self.a * (1.0 - self.e * self.ea.cos())
+    }
+
+    /// Position in rectangular coordinates in the ecliptic frame, relative to the focus of the orbit, in AU
+    pub fn position(&self) -> coord::Vector3 {
+        let (see, cee) = self.ea.sincos();
+        self.rotate(
+            self.a * (cee - self.e),
+            self.a * (1.0 - self.e * self.e).sqrt() * see,
+        )
+    }
+
+    /// Rotates coordinates in the plane of the orbit, with x towards the periapsis, into the ecliptic frame
+    fn rotate(&self, xp: f64, yp: f64) -> coord::Vector3 {
+        orbit_to_ecliptic(self.i, self.o, self.w - self.o, xp, yp)
+    }
+}
+
+/// Rotates coordinates in the plane of an orbit, with x towards the periapsis, into the ecliptic frame
+///
+/// The orbit is placed by its inclination, longitude of the ascending node, and argument of the periapsis.
+pub(crate) fn orbit_to_ecliptic(
+    i: time::Angle,
+    o: time::Angle,
+    ww: time::Angle,
+    xp: f64,
+    yp: f64,
+) -> coord::Vector3 {
+    let ((sww, cww), (so, co), (si, ci)) = (ww.sincos(), o.sincos(), i.sincos());
+    let xecl = (cww * co - sww * so * ci) * xp + (-sww * co - cww * so * ci) * yp;
+    let yecl = (cww * so + sww * co * ci) * xp + (-sww * so + cww * co * ci) * yp;
+    let zecl = (sww * si) * xp + (cww * si) * yp;
+    coord::Vector3::new(xecl, yecl, zecl)
+}
+
+/// How the brightness of a planet changes with its phase angle, see [`Planet::magnitude()`]
+///
+/// Other than [`MagnitudeLaw::Simple`], these are the fits to observations of each planet in
+/// "Computing Apparent Planetary Magnitudes for The Astronomical Almanac" by Mallama & Hilton (2018).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MagnitudeLaw {
+    /// Brightness in proportion to the illuminated fraction, from [`Planet::v0`]
+    Simple,
+    /// Mercury, valid for phase angles from 2° to 170°
+    Mercury,
+    /// Venus, including the brightening from forward scattering near inferior conjunction
+    Venus,
+    /// The earth, as seen from elsewhere
+    Earth,
+    /// Mars, without the changes from its surface markings
+    Mars,
+    /// Jupiter
+    Jupiter,
+    /// Saturn, including the rings while they are open
+    Saturn,
+    /// Uranus
+    Uranus,
+    /// Neptune, including its brightening over 1980-2000
+    Neptune,
+}
+impl MagnitudeLaw {
+    /// The law for one of the major planets in [`PLANETS`], [`MagnitudeLaw::Simple`] for Pluto and any other body
+    ///
+    /// A planet made with [`PlanetBuilder`] or [`Planet::from_jpl_row()`] uses its own [`Planet::v0`], even if it is named
+    /// after a major planet.
+    /// ```
+    /// # use pracstro::sol::{self, MagnitudeLaw};
+    /// assert_eq!(MagnitudeLaw::for_planet(&sol::SATURN), MagnitudeLaw::Saturn);
+    /// assert_eq!(MagnitudeLaw::for_planet(&sol::PLUTO), MagnitudeLaw::Simple);
+    /// ```
+    pub fn for_planet(p: &Planet) -> MagnitudeLaw {
+        [
+            (&MERCURY, MagnitudeLaw::Mercury),
+            (&VENUS, MagnitudeLaw::Venus),
+            (&EARTH, MagnitudeLaw::Earth),
+            (&MARS, MagnitudeLaw::Mars),
+            (&JUPITER, MagnitudeLaw::Jupiter),
+            (&SATURN, MagnitudeLaw::Saturn),
+            (&URANUS, MagnitudeLaw::Uranus),
+            (&NEPTUNE, MagnitudeLaw::Neptune),
+        ]
+        .into_iter()
+        .find(|(planet, _)| *planet == p)
+        .map_or(MagnitudeLaw::Simple, |(_, law)| law)
+    }
+}
+
+/// The appearance of the rings of Saturn, from [`saturn_ring()`]
+///
+/// The latitudes are signed, positive when the northern face of the rings is lit or visible,
+/// use [`time::Angle::to_latitude()`] to get signed values.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RingGeometry {
+    /// Saturnicentric latitude of the earth, how far the rings are opened to us
+    pub b_earth: time::Angle,
+    /// Saturnicentric latitude of the sun, which side of the rings is lit
+    pub b_sun: time::Angle,
+    /// Position angle of the northern semiminor axis of the rings, measured eastward from north
+    pub pa: time::Angle,
+}
+
+/// The tilt of the rings of Saturn as seen from the earth and sun, and their orientation in the sky
+///
+/// The rings appear edge-on when `b_earth` crosses zero, see [`events::ring_plane_crossings()`].
+/// ```
+/// # use pracstro::{sol, time};
+/// // Example 45.a of Astronomical Algorithms by Jean Meeus, 1992 December 16: B = 16.442°, P = 6.741°
+/// let ring = sol::saturn_ring(time::Date::from_julian(2448972.50068));
+/// assert!((ring.b_earth.degrees() - 16.442).abs() < 0.1);
+/// assert!((ring.pa.degrees() - 6.741).abs() < 0.1);
+/// // Near the widest, in 2017
+/// let wide = sol::saturn_ring(time::Date::from_calendar(2017, 6, 15, time::Angle::default()));
+/// assert!((wide.b_earth.degrees() - 26.5).abs() < 0.5);
+/// ```
+///
+/// From Astronomical Algorithms by Jean Meeus, Chapter 45
+pub fn saturn_ring(d: time::Date) -> RingGeometry {
+    let t = d.centuries();
+    let i = time::Angle::from_degrees(28.075216 - 0.012998 * t + 0.000004 * t * t);
+    let o = time::Angle::from_degrees(169.508470 + 1.394681 * t + 0.000412 * t * t);
+    let latitude = |c: coord::Coord| {
+        let ecl = c.precess(time::J2000, d).to_ecliptic(d);
+        let (lambda, beta) = (ecl.lon(), ecl.lat().angle());
+        time::Angle::asin(i.sin() * beta.cos() * (lambda - o).sin() - i.cos() * beta.sin())
+    };
+    let geo = SATURN.location(d);
+
+    // The pole of the rings, and the angle to it from the north at Saturn
+    let pole = coord::Coord::new_ecliptic(
+        o - time::Angle::from_degrees(90.0),
+        time::Angle::from_degrees(90.0) - i,
+        d,
+    )
+    .to_equatorial();
+    let ((a0, d0), (a, de)) = (pole.equatorial(), geo.precess(time::J2000, d).equatorial());
+    let (sd0, cd0) = d0.sincos();
+    let (sde, cde) = de.sincos();
+    RingGeometry {
+        b_earth: latitude(geo),
+        b_sun: latitude(SATURN.locationcart(d).into()),
+        pa: time::Angle::atan2(cd0 * (a0 - a).sin(), sd0 * cde - cd0 * sde * (a0 - a).cos()),
     }
 }
 
@@ -68,7 +639,10 @@ impl Sun {
 ///
 /// Ephemeris for planets uses Keplerian motion with correction for perturbations of other planets
 /// Error is at most 10' for most use, well within range of wanted accuracy.
+///
+/// With the `serde` feature, planets can be serialized, but not deserialized, since the name is borrowed for `'static`.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Planet {
     /// Planet Name
     pub name: &'static str,
@@ -84,94 +658,354 @@ pub struct Planet {
     pub w: f64,
     /// Longitude of the ascending node (Degrees)
     pub o: f64,
-    /// Correction rates for all 6 preceding properties.
+    /// Correction rates for all 6 preceding properties, per Julian century.
+    ///
+    /// The angular rates (inclination, mean longitude, and both longitudes) are in degrees per century,
+    /// see [`Planet::mean_motion()`] for the mean motion as an [`time::AngularRate`].
     pub rates: [f64; 6],
     /// Correction values for the mean anomaly, needed in larger planets
     pub extra: Option<(f64, f64, f64, f64)>,
     // Physical Properties
     /// Angular Diameter at 1AU (Degrees)
     pub theta0: time::Angle,
-    /// Visual Magnitude at 1AU
+    /// Visual Magnitude at 1AU, used by [`MagnitudeLaw::Simple`]
     pub v0: f64,
 }
 impl Planet {
+    /// Starts building a planet with its own orbit, see [`PlanetBuilder`]
+    pub fn builder(name: &'static str) -> PlanetBuilder {
+        PlanetBuilder {
+            name,
+            a: None,
+            e: None,
+            angles: [0.0; 4],
+            rates: None,
+            extra: None,
+            theta0: time::Angle::default(),
+            v0: 0.0,
+        }
+    }
+
+    /// Reads a planet from its rows in the tables of <https://ssd.jpl.nasa.gov/planets/approx_pos.html>
+    ///
+    /// The row is the six elements and their six rates, as they are laid out over two lines in the tables. The
+    /// four extra terms for the outer planets from Table 2b can follow. The name can be left at the start of the
+    /// row as it is in the tables, every other word has to be a number. Physical properties are left blank.
+    /// ```
+    /// # use pracstro::sol;
+    /// let mars = sol::Planet::from_jpl_row(
+    ///     "Mars",
+    ///     "Mars     1.52371034      0.09339410      1.84969142      -4.55343205    -23.94362959     49.55953891
+    ///               0.00001847      0.00007882     -0.00813131   19140.30268499      0.44441088     -0.29257343",
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_jpl_row(name: &'static str, row: &str) -> Result<Planet, InvalidPlanet> {
+        let row = row.trim_start();
+        let row = row.strip_prefix(name).unwrap_or(row);
+        let field = |k: usize| match k {
+            0..6 => ["a", "e", "i", "l", "w", "o"][k],
+            6..12 => "rates",
+            _ => "extra",
+        };
+        let values = row
+            .split_whitespace()
+            .enumerate()
+            .map(|(k, t)| {
+                t.parse::<f64>()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .ok_or(InvalidPlanet { field: field(k) })
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        if values.len() != 12 && values.len() != 16 {
+            return Err(InvalidPlanet {
+                field: field(values.len().min(12)),
+            });
+        }
+        let deg = time::Angle::from_degrees;
+        let mut b = Planet::builder(name)
+            .semi_major_axis(values[0])
+            .eccentricity(values[1])
+            .inclination(deg(values[2]))
+            .rates(std::array::from_fn(|k| values[6 + k]));
+        if let [b0, c, s, f] = values[12..] {
+            b = b.extra(b0, c, s, f);
+        }
+        // The angles are kept as written, rather than put into the range of an angle
+        let p = b.build()?;
+        Ok(Planet {
+            l: values[3],
+            w: values[4],
+            o: values[5],
+            ..p
+        })
+    }
+
     /// Returns the heliocentric location of the planets as rectangular coordinates as relative to the Sun, in AU
     ///
+    /// The frame is equatorial (J2000): x towards the March equinox, z towards the north celestial pole.
+    /// See [`Planet::locationcart_ecliptic()`] for the ecliptic frame.
+    ///
     /// From <https://ssd.jpl.nasa.gov/planets/approx_pos.html>
-    pub fn locationcart(&self, d: time::Date) -> (f64, f64, f64) {
+    pub fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        to_equatorial(self.locationcart_ecliptic(d))
+    }
+
+    /// Returns the heliocentric position and velocity of the planet as rectangular coordinates, in AU and AU per day
+    ///
+    /// The frame is the same as [`Planet::locationcart()`]. The velocity is from differentiating the motion in the
+    /// orbit, the slow changes of the orbit itself are left out.
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let (_, v) = sol::EARTH.statecart(time::Date::from_calendar(2025, 1, 1, time::Angle::default()));
+    /// v.norm(); // About 0.0172, or 30 km/s
+    /// ```
+    pub fn statecart(&self, d: time::Date) -> (coord::Vector3, coord::Vector3) {
+        let (p, v) = self.state_ecliptic(d);
+        (to_equatorial(p), to_equatorial(v))
+    }
+
+    /// Returns the heliocentric location of the planet as rectangular coordinates in the ecliptic frame, in AU
+    ///
+    /// The origin is the Sun, x points towards the March equinox and z towards the north ecliptic pole, both of J2000.
+    pub fn locationcart_ecliptic(&self, d: time::Date) -> coord::Vector3 {
+        self.state_ecliptic(d).0
+    }
+
+    /// Position and velocity in the ecliptic frame, in AU and AU per day
+    fn state_ecliptic(&self, d: time::Date) -> (coord::Vector3, coord::Vector3) {
+        let el = self.elements_at(d);
+        let t = d.centuries();
+        // Rate of change of the mean anomaly, in degrees per century
+        let mut mdot = self.rates[3] - self.rates[4];
+        if let Some((b, c, s, f)) = self.extra {
+            mdot += 2.0 * b * t
+                + f.to_radians()
+                    * (s * (f * t).to_radians().cos() - c * (f * t).to_radians().sin());
+        }
+        // Rate of change of the eccentric anomaly in radians per day, from Keplers equation
+        let (see, cee) = el.ea.sincos();
+        let eedot = mdot.to_radians() / 36525.0 / (1.0 - el.e * cee);
+        let velocity = el.rotate(
+            -el.a * see * eedot,
+            el.a * (1.0 - el.e * el.e).sqrt() * cee * eedot,
+        );
+        (el.position(), velocity)
+    }
+
+    /// The orbital elements of the planet at a date, along with where it is in its orbit
+    ///
+    /// The elements change slowly from those at J2000 by [`Planet::rates`], and the mean anomaly of the outer planets
+    /// is corrected by [`Planet::extra`].
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let el = sol::MARS.elements_at(time::Date::from_calendar(2025, 1, 1, time::Angle::default()));
+    /// el.nu.degrees(); // The true anomaly, how far past perihelion Mars is
+    /// ```
+    pub fn elements_at(&self, d: time::Date) -> Elements {
         let t = d.centuries();
-        let a = self.a + self.rates[0] * t;
-        let e = self.e + self.rates[1] * t;
-        let i = time::Angle::from_degrees(self.i + self.rates[2] * t);
         let l = time::Angle::from_degrees(self.l + self.rates[3] * t);
         let w = time::Angle::from_degrees(self.w + self.rates[4] * t);
-        let o = time::Angle::from_degrees(self.o + self.rates[5] * t);
-        let ww = w - o;
         let mut m = (l - w).degrees();
         if let Some((b, c, s, f)) = self.extra {
             m = m + b * t * t + c * ((f * t).to_radians().cos()) + s * ((f * t).to_radians().sin());
         }
-        m = time::Angle::from_degrees(m).to_latitude().degrees();
-
-        fn kepler(m: f64, e: f64, ee: f64) -> f64 {
-            let dm = m - (ee - e.to_degrees() * (ee.to_radians().sin()));
-            dm / (1.0 - e * (ee.to_radians()).cos())
-        }
-        let mut ee = m + 57.29578 * e * (m.to_radians().sin());
-        let mut de: f64 = 1.0;
-        while de.abs() > 1e-7 {
-            de = kepler(m, e, ee);
-            ee += de;
-        }
-
-        let xp = a * ((ee.to_radians()).cos() - e);
-        let yp = a * (1.0 - e * e).sqrt() * (ee.to_radians().sin());
+        Elements::new(
+            self.a + self.rates[0] * t,
+            self.e + self.rates[1] * t,
+            time::Angle::from_degrees(self.i + self.rates[2] * t),
+            l,
+            w,
+            time::Angle::from_degrees(self.o + self.rates[5] * t),
+            time::Angle::from_degrees(m),
+        )
+    }
 
-        let xecl = (ww.cos() * o.cos() - ww.sin() * o.sin() * i.cos()) * xp
-            + (-ww.sin() * o.cos() - ww.cos() * o.sin() * i.cos()) * yp;
-        let yecl = (ww.cos() * o.sin() + ww.sin() * o.cos() * i.cos()) * xp
-            + (-ww.sin() * o.sin() + ww.cos() * o.cos() * i.cos()) * yp;
-        let zecl = (ww.sin() * i.sin()) * xp + (ww.cos() * i.sin()) * yp;
+    /// Returns the heliocentric ecliptic longitude, latitude, and distance from the sun (radius vector) of the planet
+    ///
+    /// The ecliptic is of J2000, the same frame as [`Planet::locationcart_ecliptic()`]. The latitude is signed,
+    /// see [`time::Angle::to_latitude()`].
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let (l, b, r) = sol::VENUS.heliocentric(time::Date::from_calendar(1992, 12, 20, time::Angle::default()));
+    /// ```
+    pub fn heliocentric(&self, d: time::Date) -> (time::Angle, time::Angle, coord::Distance) {
+        let v = self.locationcart_ecliptic(d);
+        let r = v.norm();
+        (
+            time::Angle::atan2(v.y, v.x),
+            time::Angle::asin(v.z / r).to_latitude(),
+            coord::Distance::from_au(r),
+        )
+    }
 
-        let eps = 23.43928_f64.to_radians();
-        let tx = xecl;
-        let ty = eps.cos() * yecl - eps.sin() * zecl;
-        let tz = eps.sin() * yecl + eps.cos() * zecl;
+    /// Returns the geocentric location of the planet as rectangular coordinates in the ecliptic frame, in AU
+    ///
+    /// The same frame as [`Planet::locationcart_ecliptic()`], with the origin moved to the center of the Earth.
+    pub fn geocentric_ecliptic(&self, d: time::Date) -> coord::Vector3 {
+        self.locationcart_ecliptic(d)
+            - (EARTH.locationcart_ecliptic(d) + geocenter_offset_ecliptic(d))
+    }
 
-        (tx, ty, tz)
+    /// The mean motion of the planet, the rate of change of the mean longitude
+    /// ```
+    /// # use pracstro::sol;
+    /// sol::EARTH.mean_motion().degrees_per_day(); // 0.9856
+    /// ```
+    pub const fn mean_motion(&self) -> time::AngularRate {
+        time::AngularRate::from_degrees_per_century(self.rates[3])
     }
 
     /// Returns coordinates as subtracted from the earths coordinates
     pub fn location(&self, d: time::Date) -> coord::Coord {
-        let c = self.locationcart(d);
-        let e = EARTH.locationcart(d);
+        (self.locationcart(d) - earth_geocenter(d)).into()
+    }
+
+    /// Returns coordinates as seen from the earth, where the planet was when its light left it
+    ///
+    /// Unlike [`Planet::location()`], which is the geometric position, this corrects for light time.
+    /// The light time is iterated, since it depends on the distance at the earlier time.
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Ch. 33
+    pub fn location_apparent(&self, d: time::Date) -> coord::Coord {
+        let earth = earth_geocenter(d);
+        let (day, f) = d.jd_parts();
+        let mut v = self.locationcart(d) - earth;
+        for _ in 0..3 {
+            let tau = coord::Distance::from_au(v.norm()).light_minutes() / 1440.0;
+            v = self.locationcart(time::Date::from_jd_parts(day, f - tau)) - earth;
+        }
+        v.into()
+    }
 
-        coord::Coord::from_cartesian(c.0 - e.0, c.1 - e.1, c.2 - e.2)
+    /// The angle between the planet and the sun as seen from the earth, and which side of the sun it is on
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let (angle, side) = sol::VENUS.elongation(time::Date::from_calendar(2025, 1, 10, time::Angle::default()));
+    /// assert_eq!(side, sol::Elongation::East); // An evening star
+    /// ```
+    pub fn elongation(&self, d: time::Date) -> (time::Angle, Elongation) {
+        let (sun, planet) = (SUN.location(d), self.location(d));
+        (
+            sun.dist(planet),
+            Elongation::from_longitude(
+                planet.to_ecliptic(time::J2000).lon() - sun.to_ecliptic(time::J2000).lon(),
+            ),
+        )
     }
 
-    /// Returns distance in AU
-    pub fn distance(&self, d: time::Date) -> f64 {
-        let c = self.locationcart(d);
-        let e = EARTH.locationcart(d);
-        let (tx, ty, tz) = (c.0 - e.0, c.1 - e.1, c.2 - e.2);
+    /// Rise, transit, and set of the planet on the UT day of a date, following its motion through the day
+    ///
+    /// The position of the planet is precessed to the date, since the sidereal time is of the date.
+    /// The planet rises and sets when its center is 0°34' below the horizon, lowered by refraction, and further
+    /// by the dip of the horizon for an observer above sea level. See [`coord::riseset_moving()`].
+    /// ```
+    /// # use pracstro::{coord, sol, time};
+    /// // Venus from Boston, Example 15.a of Astronomical Algorithms by Jean Meeus: rises at 12h25m UT, sets at 2h55m
+    /// let boston = coord::Observer::new(time::Angle::from_degrees(42.3333), time::Angle::from_degrees(-71.0833), 0.0);
+    /// let day = time::Date::from_calendar(1988, 3, 20, time::Angle::default());
+    /// let rs = sol::VENUS.riseset(day, &boston).unwrap();
+    /// let at = |h, m| time::Date::from_calendar(1988, 3, 20, time::Angle::from_clock(h, m, 0.0)).julian();
+    /// assert!((rs.rise.julian() - at(12, 25)).abs() < 1.0 / 1440.0);
+    /// assert!((rs.set.julian() - at(2, 55)).abs() < 1.0 / 1440.0);
+    /// ```
+    pub fn riseset(
+        &self,
+        d: time::Date,
+        obs: &coord::Observer,
+    ) -> Result<coord::RiseSet, coord::NoRiseSet> {
+        let position = |d| self.location(d).precess(time::J2000, d);
+        coord::riseset_moving(position, d, obs, coord::H0_STAR - obs.dip())
+    }
 
-        (tx * tx + ty * ty + tz * tz).sqrt()
+    /// Returns the distance from the earth
+    pub fn distance(&self, d: time::Date) -> coord::Distance {
+        coord::Distance::from_au((self.locationcart(d) - earth_geocenter(d)).norm())
+    }
+
+    /// Returns the location of the planet relative to the barycenter of the solar system, in AU
+    ///
+    /// The same frame as [`Planet::locationcart()`], with the origin moved by [`ssb_offset()`].
+    pub fn location_barycentric(&self, d: time::Date) -> coord::Vector3 {
+        self.locationcart(d) + ssb_offset(d)
     }
 
     /// Returns angular diameter of the planet at current time
     pub fn angdia(&self, d: time::Date) -> time::Angle {
-        self.theta0 / self.distance(d)
+        self.theta0 / self.distance(d).au()
     }
 
     fn sun_distance(&self, d: time::Date) -> f64 {
-        let (tx, ty, tz) = self.locationcart(d);
-        (tx * tx + ty * ty + tz * tz).sqrt()
+        self.locationcart(d).norm()
     }
 
     /// Get apparent magnitude of a planet
+    ///
+    /// The change in brightness with phase follows the [`MagnitudeLaw`] of the planet, see [`MagnitudeLaw::for_planet()`].
+    /// For the major planets these are the fits of Mallama & Hilton (2018), which include the rings of Saturn.
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// sol::VENUS.magnitude(time::Date::from_calendar(2025, 2, 16, time::Angle::default())); // About -4.9
+    /// ```
     pub fn magnitude(&self, d: time::Date) -> f64 {
-        5.0 * ((self.distance(d) * self.sun_distance(d)) / self.illumfrac(d).sqrt()).log10()
-            + self.v0
+        let (delta, r) = (self.distance(d).au(), self.sun_distance(d));
+        let a = self.true_phaseangle(d).degrees();
+        let poly = |c: &[f64]| c.iter().rev().fold(0.0, |acc, c| acc * a + c);
+        let reduced = match MagnitudeLaw::for_planet(self) {
+            MagnitudeLaw::Simple => {
+                return 5.0 * ((delta * r) / self.illumfrac(d).sqrt()).log10() + self.v0
+            }
+            MagnitudeLaw::Mercury => poly(&[
+                -0.613,
+                6.3280e-2,
+                -1.6336e-3,
+                3.3644e-5,
+                -3.4265e-7,
+                1.6893e-9,
+                -3.0334e-12,
+            ]),
+            MagnitudeLaw::Venus if a < 163.7 => {
+                poly(&[-4.384, -1.044e-3, 3.687e-4, -2.814e-6, 8.938e-9])
+            }
+            MagnitudeLaw::Venus => poly(&[236.05828, -2.81914, 8.39034e-3]),
+            MagnitudeLaw::Earth => poly(&[-3.99, -1.060e-3, 2.054e-4]),
+            MagnitudeLaw::Mars if a <= 50.0 => poly(&[-1.601, 2.267e-2, -1.302e-4]),
+            MagnitudeLaw::Mars => poly(&[-0.367, -2.573e-2, 3.445e-4]),
+            MagnitudeLaw::Jupiter if a <= 12.0 => poly(&[-9.395, -3.7e-4, 6.16e-4]),
+            MagnitudeLaw::Jupiter => {
+                let x = a / 180.0;
+                let f = [1.0, -1.507, -0.363, -0.062, 2.809, -1.876];
+                -9.428 - 2.5 * f.iter().rev().fold(0.0, |acc, c| acc * x + c).log10()
+            }
+            MagnitudeLaw::Saturn => {
+                // The rings are only fit while they are open to the earth, and seen at a small phase
+                let sb = saturn_ring(d).b_earth.sin().abs();
+                if a <= 6.5 && sb <= time::Angle::from_degrees(27.0).sin() {
+                    -8.914 - 1.825 * sb + 0.026 * a - 0.378 * sb * (-2.25 * a).exp()
+                } else if a <= 6.0 {
+                    poly(&[-8.95, -3.7e-4, 6.16e-4])
+                } else {
+                    poly(&[-8.94, 2.446e-4, 2.672e-4, -1.505e-6, 4.767e-9])
+                }
+            }
+            // The small term for the latitude of the pole facing the earth is left out
+            MagnitudeLaw::Uranus => poly(&[-7.110, 6.587e-3, 1.045e-4]),
+            MagnitudeLaw::Neptune => {
+                // Neptune brightened between 1980 and 2000
+                let year = 2000.0 + (d.julian() - time::J2000.julian()) / 365.25;
+                let v10 = -6.89 - 0.0055 * (year.clamp(1980.0, 2000.0) - 1980.0);
+                v10 + poly(&[0.0, 7.944e-3, 9.617e-5])
+            }
+        };
+        5.0 * (delta * r).log10() + reduced
+    }
+
+    /// The angle between the sun and earth as seen from the planet
+    fn true_phaseangle(&self, d: time::Date) -> time::Angle {
+        let p = self.locationcart(d);
+        let to_earth = earth_geocenter(d) - p;
+        time::Angle::acos(-p.dot(to_earth) / (p.norm() * to_earth.norm()))
     }
 
     /// Gets the phase angle of a planet
@@ -179,10 +1013,9 @@ impl Planet {
     /// This is simple trig work with the triangle between the planet, earth, and sun.
     pub fn phaseangle(&self, d: time::Date) -> time::Angle {
         let sep = SUN.location(d).dist(self.location(d));
-        let (tx, ty, tz) = self.locationcart(d);
-        let sp = (tx * tx + ty * ty + tz * tz).sqrt();
-        let upa = time::Angle::asin(SUN.distance(d) * (sep.sin() / sp));
-        if (tx * tx + ty * ty + tz * tz).sqrt() < 1.0 {
+        let sp = self.sun_distance(d);
+        let upa = time::Angle::asin(SUN.distance(d).au() * (sep.sin() / sp));
+        if sp < 1.0 {
             upa
         } else {
             upa + time::Angle::from_degrees(180.0)
@@ -196,6 +1029,179 @@ impl Planet {
     }
 }
 
+/// The origin of [`CelObj::locationcart()`] is the sun, as with [`Planet::locationcart()`],
+/// but [`CelObj::location()`] and [`CelObj::distance()`] are as seen from the earth
+impl CelObj for Planet {
+    fn locationcart(&self, d: time::Date) -> coord::Vector3 {
+        Planet::locationcart(self, d)
+    }
+    fn location(&self, d: time::Date) -> coord::Coord {
+        Planet::location(self, d)
+    }
+    fn location_of_date(&self, d: time::Date) -> coord::Coord {
+        Planet::location(self, d).precess_rigorous(time::J2000, d)
+    }
+    fn distance(&self, d: time::Date) -> coord::Distance {
+        Planet::distance(self, d)
+    }
+    fn illumfrac(&self, d: time::Date) -> Option<f64> {
+        Some(Planet::illumfrac(self, d))
+    }
+    fn magnitude(&self, d: time::Date) -> f64 {
+        Planet::magnitude(self, d)
+    }
+    fn angdia(&self, d: time::Date) -> time::Angle {
+        Planet::angdia(self, d)
+    }
+    fn name(&self) -> &str {
+        self.name
+    }
+}
+
+/// A planet with an orbit that can't be used, from [`PlanetBuilder::build()`] or [`Planet::from_jpl_row()`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InvalidPlanet {
+    /// The field of [`Planet`] that is missing, unreadable, or out of range
+    pub field: &'static str,
+}
+impl std::fmt::Display for InvalidPlanet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid planet, bad value for {}", self.field)
+    }
+}
+impl std::error::Error for InvalidPlanet {}
+impl From<InvalidPlanet> for crate::Error {
+    fn from(e: InvalidPlanet) -> Self {
+        crate::Error::InvalidPlanet { field: e.field }
+    }
+}
+
+/// Builds a [`Planet`] one property at a time, checking the orbit when done, from [`Planet::builder()`]
+///
+/// Only the semi-major axis and eccentricity are needed. Angles default to 0°, and the mean longitude moves at the
+/// rate from Keplers third law unless [`PlanetBuilder::rates()`] is given.
+/// ```
+/// # use pracstro::{sol, time};
+/// let ceres = sol::Planet::builder("Ceres")
+///     .semi_major_axis(2.7675)
+///     .eccentricity(0.0785)
+///     .inclination(time::Angle::from_degrees(10.59))
+///     .longitude_of_node(time::Angle::from_degrees(80.27))
+///     .longitude_of_periapsis(time::Angle::from_degrees(153.9))
+///     .mean_longitude(time::Angle::from_degrees(129.8))
+///     .build()
+///     .unwrap();
+/// assert!(sol::Planet::builder("Nope").semi_major_axis(1.0).eccentricity(1.5).build().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanetBuilder {
+    name: &'static str,
+    a: Option<f64>,
+    e: Option<f64>,
+    angles: [f64; 4],
+    rates: Option<[f64; 6]>,
+    extra: Option<(f64, f64, f64, f64)>,
+    theta0: time::Angle,
+    v0: f64,
+}
+impl PlanetBuilder {
+    /// Semi-major axis in AU
+    pub fn semi_major_axis(mut self, a: f64) -> Self {
+        self.a = Some(a);
+        self
+    }
+    /// Eccentricity, which has to be less than 1
+    pub fn eccentricity(mut self, e: f64) -> Self {
+        self.e = Some(e);
+        self
+    }
+    /// Inclination to the ecliptic of J2000, up to 180°
+    pub fn inclination(mut self, i: time::Angle) -> Self {
+        self.angles[0] = i.degrees();
+        self
+    }
+    /// Mean longitude at J2000
+    pub fn mean_longitude(mut self, l: time::Angle) -> Self {
+        self.angles[1] = l.degrees();
+        self
+    }
+    /// Longitude of the periapsis at J2000
+    pub fn longitude_of_periapsis(mut self, w: time::Angle) -> Self {
+        self.angles[2] = w.degrees();
+        self
+    }
+    /// Longitude of the ascending node at J2000
+    pub fn longitude_of_node(mut self, o: time::Angle) -> Self {
+        self.angles[3] = o.degrees();
+        self
+    }
+    /// Rates of change of the elements per century, as in [`Planet::rates`]
+    pub fn rates(mut self, rates: [f64; 6]) -> Self {
+        self.rates = Some(rates);
+        self
+    }
+    /// Correction terms for the mean anomaly, as in [`Planet::extra`]
+    pub fn extra(mut self, b: f64, c: f64, s: f64, f: f64) -> Self {
+        self.extra = Some((b, c, s, f));
+        self
+    }
+    /// Angular diameter at 1 AU
+    pub fn angular_diameter(mut self, theta0: time::Angle) -> Self {
+        self.theta0 = theta0;
+        self
+    }
+    /// Visual magnitude at 1 AU, for [`MagnitudeLaw::Simple`]
+    pub fn magnitude(mut self, v0: f64) -> Self {
+        self.v0 = v0;
+        self
+    }
+
+    /// Checks the orbit and makes the planet
+    pub fn build(self) -> Result<Planet, InvalidPlanet> {
+        let check = |ok: bool, field| {
+            if ok {
+                Ok(())
+            } else {
+                Err(InvalidPlanet { field })
+            }
+        };
+        let a = self.a.ok_or(InvalidPlanet { field: "a" })?;
+        let e = self.e.ok_or(InvalidPlanet { field: "e" })?;
+        check(a.is_finite() && a > 0.0, "a")?;
+        check((0.0..1.0).contains(&e), "e")?;
+        let [i, l, w, o] = self.angles;
+        check((0.0..=180.0).contains(&i), "i")?;
+        check(l.is_finite(), "l")?;
+        check(w.is_finite(), "w")?;
+        check(o.is_finite(), "o")?;
+        // Keplers third law, scaled from the mean motion of the earth
+        let rates = self
+            .rates
+            .unwrap_or([0.0, 0.0, 0.0, 35999.37244981 / a.powf(1.5), 0.0, 0.0]);
+        check(rates.iter().all(|r| r.is_finite()), "rates")?;
+        check(
+            self.extra
+                .is_none_or(|(b, c, s, f)| [b, c, s, f].iter().all(|x| x.is_finite())),
+            "extra",
+        )?;
+        check(self.theta0.radians().is_finite(), "theta0")?;
+        check(self.v0.is_finite(), "v0")?;
+        Ok(Planet {
+            name: self.name,
+            a,
+            e,
+            i,
+            l,
+            w,
+            o,
+            rates,
+            extra: self.extra,
+            theta0: self.theta0,
+            v0: self.v0,
+        })
+    }
+}
+
 /// Mercury
 pub const MERCURY: Planet = Planet {
     name: "Mercury",
@@ -238,7 +1244,7 @@ pub const VENUS: Planet = Planet {
     theta0: time::Angle::from_degrees(0.0047),
     v0: -4.4,
 };
-/// Earth (Technically the Earth-Moon Barycenter)
+/// Earth (Technically the Earth-Moon Barycenter, see [`earth_geocenter()`] for the center of the earth)
 pub const EARTH: Planet = Planet {
     name: "Earth",
     a: 1.00000261,
@@ -393,20 +1399,467 @@ pub const PLUTO: Planet = Planet {
 /// ```
 /// use pracstro::*;
 /// for p in sol::PLANETS {
-///   println!("{} AU", p.distance(time::Date::now()));
+///   println!("{}", p.distance(time::Date::now()));
 /// }
 /// ```
 pub const PLANETS: [&Planet; 9] = [
     &MERCURY, &VENUS, &EARTH, &MARS, &JUPITER, &SATURN, &URANUS, &NEPTUNE, &PLUTO,
 ];
 
+/// The mass of the sun divided by the mass of each of [`PLANETS`] along with its moons
+///
+/// From the JPL DE440 ephemeris, <https://ssd.jpl.nasa.gov/astro_par.html>
+const MASS_RATIOS: [f64; 9] = [
+    6023657.33,
+    408523.719,
+    328900.5596,
+    3098703.59,
+    1047.348644,
+    3497.9018,
+    22902.951,
+    19412.237,
+    136045556.0,
+];
+
+/// The position of the sun relative to the barycenter of the solar system, in AU
+///
+/// Adding this to a heliocentric position, such as [`Planet::locationcart()`], moves its origin to the barycenter,
+/// the origin of pulsar timing and of spacecraft ephemerides. The frame is the same as [`Planet::locationcart()`].
+/// The sun moves about the barycenter by up to two of its own radii, mostly pulled by Jupiter and Saturn.
+/// ```
+/// # use pracstro::{sol, time};
+/// let d = time::Date::from_calendar(2025, 1, 1, time::Angle::default());
+/// let jupiter = sol::JUPITER.locationcart(d) + sol::ssb_offset(d);
+/// assert_eq!(jupiter, sol::JUPITER.location_barycentric(d));
+/// ```
+pub fn ssb_offset(d: time::Date) -> coord::Vector3 {
+    let total = 1.0 + MASS_RATIOS.iter().map(|r| 1.0 / r).sum::<f64>();
+    PLANETS
+        .iter()
+        .zip(MASS_RATIOS)
+        .fold(coord::Vector3::default(), |sum, (p, ratio)| {
+            sum - p.locationcart(d) * (1.0 / (ratio * total))
+        })
+}
+
+/// The mass of the earth divided by the mass of the moon, from the JPL DE440 ephemeris
+const EARTH_MOON_RATIO: f64 = 81.3005682;
+
+/// The position of the center of the earth relative to the Earth-Moon barycenter, in the ecliptic frame of J2000
+fn geocenter_offset_ecliptic(d: time::Date) -> coord::Vector3 {
+    let (lambda, beta, dist) = crate::moon::MOON.ecliptic_precise(d);
+    let ecl = coord::Coord::new_ecliptic(lambda, beta, d)
+        .to_equatorial()
+        .precess_rigorous(d, time::J2000)
+        .to_ecliptic(time::J2000);
+    let (l, b) = (ecl.lon(), ecl.lat().angle());
+    let ((sl, cl), (sb, cb)) = (l.sincos(), b.sincos());
+    coord::Vector3::new(cb * cl, cb * sl, sb) * (-dist / (1.0 + EARTH_MOON_RATIO))
+}
+
+/// The heliocentric position of the center of the earth, in AU
+///
+/// [`EARTH`] is the barycenter of the earth and moon, which the center of the earth circles about 4700 km from,
+/// opposite the moon. Seen from there, a planet can be up to 6" away from where it is seen from the earth.
+/// The frame is the same as [`Planet::locationcart()`], and this is the origin of [`Planet::location()`].
+/// ```
+/// # use pracstro::{sol, time};
+/// let d = time::Date::from_calendar(2025, 1, 1, time::Angle::default());
+/// let offset = sol::earth_geocenter(d) - sol::EARTH.locationcart(d);
+/// (offset.norm() * 149597870.7).round(); // 4638 km
+/// ```
+pub fn earth_geocenter(d: time::Date) -> coord::Vector3 {
+    to_equatorial(EARTH.locationcart_ecliptic(d) + geocenter_offset_ecliptic(d))
+}
+
+/// The four large moons of Jupiter, discovered by Galileo
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GalileanMoon {
+    /// Jupiter I
+    Io,
+    /// Jupiter II
+    Europa,
+    /// Jupiter III
+    Ganymede,
+    /// Jupiter IV
+    Callisto,
+}
+impl GalileanMoon {
+    /// All four moons, in order of distance from Jupiter, as returned by [`jupiter_moons()`]
+    pub const ALL: [GalileanMoon; 4] = [
+        GalileanMoon::Io,
+        GalileanMoon::Europa,
+        GalileanMoon::Ganymede,
+        GalileanMoon::Callisto,
+    ];
+}
+
+/// Where a moon appears relative to the center of its planet, in equatorial radii of the planet
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoonOffset {
+    /// Towards the west in the sky, the direction the planet seems to move in a day
+    pub x: f64,
+    /// Towards the north in the sky, along the axis of the planet for [`jupiter_moons()`], and towards the
+    /// north celestial pole for [`Satellite::offset()`]
+    pub y: f64,
+    /// Towards the observer, positive when the moon is in front of the planet
+    pub z: f64,
+}
+
+/// The flattening of Jupiter, from its equatorial and polar radii
+pub(crate) const JUPITER_FLATTENING: f64 = 1.0 - 66854.0 / 71492.0;
+
+/// The positions of the Galilean moons relative to Jupiter as seen from the earth, in the order of [`GalileanMoon::ALL`]
+///
+/// Accurate to a few hundredths of a radius of Jupiter, enough to draw them and to time their phenomena to a few
+/// minutes, see [`events::galilean_events()`].
+/// ```
+/// # use pracstro::{sol, time};
+/// let d = time::Date::from_calendar(1992, 12, 16, time::Angle::default());
+/// for (moon, pos) in sol::GalileanMoon::ALL.iter().zip(sol::jupiter_moons(d)) {
+///     println!("{:?} {:+.2} {:+.2}", moon, pos.x, pos.y);
+/// }
+/// ```
+///
+/// From the low accuracy method in Astronomical Algorithms by Jean Meeus, Chapter 44
+pub fn jupiter_moons(d: time::Date) -> [MoonOffset; 4] {
+    galilean_offsets(d, false)
+}
+
+/// The Galilean moons relative to Jupiter as seen from the earth, or from the sun for their shadows
+pub(crate) fn galilean_offsets(d: time::Date, from_sun: bool) -> [MoonOffset; 4] {
+    let deg = time::Angle::from_degrees;
+    let d = d.julian() - time::J2000.julian();
+    let v = deg(172.74 + 0.00111588 * d);
+    let m = deg(357.529 + 0.9856003 * d);
+    let n = deg(20.020 + 0.0830853 * d + 0.329 * v.sin());
+    let j = deg(66.115 + 0.9025179 * d - 0.329 * v.sin());
+    let a = 1.915 * m.sin() + 0.020 * (m * 2.0).sin();
+    let b = 5.555 * n.sin() + 0.168 * (n * 2.0).sin();
+    let k = j + deg(a - b);
+    // Distances of the earth and Jupiter from the sun, and of Jupiter from the earth
+    let re = 1.00014 - 0.01671 * m.cos() - 0.00014 * (m * 2.0).cos();
+    let rj = 5.20872 - 0.25208 * n.cos() - 0.00611 * (n * 2.0).cos();
+    let delta = (rj * rj + re * re - 2.0 * rj * re * k.cos()).sqrt();
+    // The phase angle of Jupiter, which turns the view from the sun to the earth
+    let psi = time::Angle::asin(re / delta * k.sin());
+
+    let t = d - delta / 173.0;
+    let shift = match from_sun {
+        true => -b,
+        false => psi.to_latitude().degrees() - b,
+    };
+    let mut u = [
+        163.8069 + 203.4058646 * t,
+        358.4140 + 101.2916335 * t,
+        5.7176 + 50.2345180 * t,
+        224.8092 + 21.4879800 * t,
+    ]
+    .map(|x| deg(x + shift));
+    let g = deg(331.18 + 50.310482 * t);
+    let h = deg(87.45 + 21.569231 * t);
+    let (e12, e23) = ((u[0] - u[1]) * 2.0, (u[1] - u[2]) * 2.0);
+    u[0] += deg(0.473 * e12.sin());
+    u[1] += deg(1.065 * e23.sin());
+    u[2] += deg(0.165 * g.sin());
+    u[3] += deg(0.843 * h.sin());
+    let r = [
+        5.9057 - 0.0244 * e12.cos(),
+        9.3966 - 0.0882 * e23.cos(),
+        14.9883 - 0.0216 * g.cos(),
+        26.3627 - 0.1939 * h.cos(),
+    ];
+
+    // The jovicentric declination of the sun, and of the earth
+    let lambda = deg(34.35 + 0.083091 * d + 0.329 * v.sin()) + deg(b);
+    let ds = 3.12 * (lambda + deg(42.8)).sin();
+    let de = ds
+        - 2.22 * psi.sin() * (lambda + deg(22.0)).cos()
+        - 1.30 * (rj - delta) / delta * (lambda - deg(100.5)).sin();
+    let (sd, cd) = deg(if from_sun { ds } else { de }).sincos();
+    [0, 1, 2, 3].map(|i| {
+        let (su, cu) = u[i].sincos();
+        MoonOffset {
+            x: r[i] * su,
+            y: -r[i] * cu * sd,
+            z: r[i] * cu * cd,
+        }
+    })
+}
+
+/// A moon of another planet, with mean elements of its orbit around the planet
+///
+/// The orbit is referred to its Laplace plane, about which the orbit precesses, and the elements are at J2000.
+/// Angles are in degrees, as with [`Planet`].
+///
+/// With the `serde` feature, satellites can be serialized, but not deserialized, since the name is borrowed for `'static`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Satellite {
+    /// Satellite Name
+    pub name: &'static str,
+    /// The planet it goes around
+    pub planet: &'static Planet,
+    /// Semi-Major Axis (km)
+    pub a: f64,
+    /// Eccentricity
+    pub e: f64,
+    /// Argument of periapsis
+    pub w: f64,
+    /// Mean anomaly
+    pub m: f64,
+    /// Inclination to the Laplace plane
+    pub i: f64,
+    /// Longitude of the ascending node, along the Laplace plane from where it crosses the equator of J2000
+    pub o: f64,
+    /// Sidereal period (days)
+    pub period: f64,
+    /// Periods of the advance of the periapsis and the regression of the node (years), zero if too slow to matter
+    pub precession: [f64; 2],
+    /// Right ascension and declination of the pole of the Laplace plane in J2000
+    pub pole: [f64; 2],
+}
+impl Satellite {
+    /// The position of the satellite relative to its planet, as rectangular coordinates in km
+    ///
+    /// The frame is the same as [`Planet::locationcart()`], equatorial (J2000).
+    pub fn planetocentric(&self, d: time::Date) -> coord::Vector3 {
+        let t = d.julian() - time::J2000.julian();
+        let rate = |period: f64| match period {
+            0.0 => 0.0,
+            p => t * 360.0 / (p * 365.25),
+        };
+        let deg = time::Angle::from_degrees;
+        let o = deg(self.o - rate(self.precession[1]));
+        let w = deg(self.w + rate(self.precession[0])) + o;
+        let m = deg(self.m + t * 360.0 / self.period);
+        let v = Elements::new(self.a, self.e, deg(self.i), w + m, w, o, m).position();
+
+        // From the Laplace plane, with x towards its ascending node on the equator, to the equator
+        let (sd, cd) = deg(90.0 - self.pole[1]).sincos();
+        let (y, z) = (v.y * cd - v.z * sd, v.y * sd + v.z * cd);
+        let (sa, ca) = deg(self.pole[0] + 90.0).sincos();
+        coord::Vector3::new(v.x * ca - y * sa, v.x * sa + y * ca, z)
+    }
+
+    /// The geocentric location of the satellite, as [`Planet::location()`]
+    ///
+    /// The satellite is placed around the planet where it was when its light left it, as in [`Satellite::offset()`].
+    pub fn location(&self, d: time::Date) -> coord::Coord {
+        let (planet, v) = self.seen(d);
+        (planet + v * (1.0 / coord::Distance::from_au(1.0).km())).into()
+    }
+
+    /// The geocentric position of the planet in AU, and of the satellite around it in km when its light left it
+    fn seen(&self, d: time::Date) -> (coord::Vector3, coord::Vector3) {
+        let planet = self.planet.locationcart(d) - earth_geocenter(d);
+        let light = coord::Distance::from_au(planet.norm()).light_minutes() / 1440.0;
+        let (day, f) = d.jd_parts();
+        (
+            planet,
+            self.planetocentric(time::Date::from_jd_parts(day, f - light)),
+        )
+    }
+
+    /// Where the satellite appears relative to its planet as seen from the earth
+    ///
+    /// The offsets are in radii of the planet as drawn by [`Planet::angdia()`]. The satellite is seen where it was
+    /// when its light left it.
+    /// ```
+    /// # use pracstro::{sol, time};
+    /// let titan = sol::TITAN.offset(time::Date::from_calendar(2025, 9, 21, time::Angle::default()));
+    /// (titan.x * titan.x + titan.y * titan.y).sqrt(); // Up to 20 radii of Saturn away
+    /// ```
+    pub fn offset(&self, d: time::Date) -> MoonOffset {
+        let (planet, v) = self.seen(d);
+        let au = coord::Distance::from_au(1.0).km();
+        let radius = self.planet.theta0.radians() / 2.0 * au;
+
+        let (ra, de) = coord::Coord::from(planet).equatorial();
+        let ((sa, ca), (sd, cd)) = (ra.sincos(), de.sincos());
+        let east = coord::Vector3::new(-sa, ca, 0.0);
+        let north = coord::Vector3::new(-sd * ca, -sd * sa, cd);
+        let away = coord::Vector3::new(cd * ca, cd * sa, sd);
+        MoonOffset {
+            x: -v.dot(east) / radius,
+            y: v.dot(north) / radius,
+            z: -v.dot(away) / radius,
+        }
+    }
+}
+
+/// Phobos, the inner moon of Mars
+pub const PHOBOS: Satellite = Satellite {
+    name: "Phobos",
+    planet: &MARS,
+    a: 9376.0,
+    e: 0.0151,
+    w: 150.057,
+    m: 91.059,
+    i: 1.075,
+    o: 207.784,
+    period: 0.3189,
+    precession: [1.131, 2.262],
+    pole: [317.671, 52.893],
+};
+/// Deimos, the outer moon of Mars
+pub const DEIMOS: Satellite = Satellite {
+    name: "Deimos",
+    planet: &MARS,
+    a: 23458.0,
+    e: 0.0002,
+    w: 260.729,
+    m: 325.329,
+    i: 1.788,
+    o: 24.525,
+    period: 1.2624,
+    precession: [27.36, 54.72],
+    pole: [316.657, 53.529],
+};
+/// Rhea, the second largest moon of Saturn
+pub const RHEA: Satellite = Satellite {
+    name: "Rhea",
+    planet: &SATURN,
+    a: 527068.0,
+    e: 0.0002,
+    w: 241.619,
+    m: 179.781,
+    i: 0.345,
+    o: 311.531,
+    period: 4.518,
+    precession: [0.0, 0.0],
+    pole: [40.59, 83.54],
+};
+/// Titan, the largest moon of Saturn
+pub const TITAN: Satellite = Satellite {
+    name: "Titan",
+    planet: &SATURN,
+    a: 1221870.0,
+    e: 0.0288,
+    w: 180.532,
+    m: 163.310,
+    i: 0.306,
+    o: 28.060,
+    period: 15.945,
+    precession: [703.0, 0.0],
+    pole: [36.213, 83.537],
+};
+/// Iapetus, the outer large moon of Saturn, far out of the plane of the rings
+pub const IAPETUS: Satellite = Satellite {
+    name: "Iapetus",
+    planet: &SATURN,
+    a: 3560820.0,
+    e: 0.0286,
+    w: 271.606,
+    m: 201.789,
+    i: 8.298,
+    o: 81.105,
+    period: 79.331,
+    precession: [0.0, 0.0],
+    pole: [284.715, 78.749],
+};
+/// Miranda, the inner large moon of Uranus
+pub const MIRANDA: Satellite = Satellite {
+    name: "Miranda",
+    planet: &URANUS,
+    a: 129900.0,
+    e: 0.0013,
+    w: 68.312,
+    m: 311.330,
+    i: 4.338,
+    o: 326.438,
+    period: 1.413,
+    precession: [0.0, 0.0],
+    pole: [257.311, -15.175],
+};
+/// Ariel, a moon of Uranus
+pub const ARIEL: Satellite = Satellite {
+    name: "Ariel",
+    planet: &URANUS,
+    a: 190900.0,
+    e: 0.0012,
+    w: 115.349,
+    m: 39.481,
+    i: 0.041,
+    o: 22.394,
+    period: 2.520,
+    precession: [0.0, 0.0],
+    pole: [257.311, -15.175],
+};
+/// Umbriel, a moon of Uranus
+pub const UMBRIEL: Satellite = Satellite {
+    name: "Umbriel",
+    planet: &URANUS,
+    a: 266000.0,
+    e: 0.0039,
+    w: 84.709,
+    m: 12.469,
+    i: 0.128,
+    o: 33.485,
+    period: 4.144,
+    precession: [0.0, 0.0],
+    pole: [257.311, -15.175],
+};
+/// Titania, the largest moon of Uranus
+pub const TITANIA: Satellite = Satellite {
+    name: "Titania",
+    planet: &URANUS,
+    a: 436300.0,
+    e: 0.0011,
+    w: 284.400,
+    m: 24.614,
+    i: 0.079,
+    o: 99.771,
+    period: 8.706,
+    precession: [0.0, 0.0],
+    pole: [257.311, -15.175],
+};
+/// Oberon, the outer large moon of Uranus
+pub const OBERON: Satellite = Satellite {
+    name: "Oberon",
+    planet: &URANUS,
+    a: 583500.0,
+    e: 0.0014,
+    w: 104.400,
+    m: 283.088,
+    i: 0.068,
+    o: 279.771,
+    period: 13.463,
+    precession: [0.0, 0.0],
+    pole: [257.311, -15.175],
+};
+/// Triton, the large moon of Neptune, which goes around backwards
+pub const TRITON: Satellite = Satellite {
+    name: "Triton",
+    planet: &NEPTUNE,
+    a: 354759.0,
+    e: 0.0,
+    w: 0.0,
+    m: 264.775,
+    i: 156.865,
+    o: 177.608,
+    period: 5.877,
+    precession: [0.0, 688.0],
+    pole: [299.456, 43.414],
+};
+
+/// The satellites with orbits in this module, other than the moons of Jupiter, see [`jupiter_moons()`]
+///
+/// Elements from <https://ssd.jpl.nasa.gov/sats/elem/>, good to a few degrees along the orbit for decades around J2000.
+pub const SATELLITES: [&Satellite; 11] = [
+    &PHOBOS, &DEIMOS, &RHEA, &TITAN, &IAPETUS, &MIRANDA, &ARIEL, &UMBRIEL, &TITANIA, &OBERON,
+    &TRITON,
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_sunpos() {
-        assert_eq!(
+        assert_approx_eq!(
             SUN.location(time::Date::from_julian(2268932.541667)),
             coord::Coord::from_equatorial(
                 time::Angle::from_degminsec(298, 29, 42.42),
@@ -418,27 +1871,122 @@ mod tests {
     // "Is this a reliable way of getting the ecliptic longitude of the sun?"
     #[test]
     fn test_lambdasun() {
-        assert_eq!(
+        assert_approx_eq!(
             SUN.location(time::Date::from_calendar(
                 1980,
                 7,
                 27,
                 time::Angle::default()
             ))
-            .ecliptic(time::Date::from_calendar(
+            .to_ecliptic(time::Date::from_calendar(
                 1980,
                 7,
                 27,
                 time::Angle::default()
             ))
-            .0,
+            .lon(),
             time::Angle::from_degminsec(124, 23, 40.8)
         )
     }
 
+    #[test]
+    fn test_equation_of_time() {
+        // Astronomical Algorithms, Example 28.b: 13m42.6s
+        assert_approx_eq!(
+            equation_of_time(time::Date::from_julian(2448908.5)),
+            time::Angle::from_clock(0, 13, 42.6),
+            time::Angle::from_clock(0, 0, 0.5)
+        );
+        assert_approx_eq!(
+            equation_of_time(time::Date::from_calendar(
+                2025,
+                2,
+                11,
+                time::Angle::default()
+            )),
+            time::Angle::from_clock(0, 14, 11.0).inverse()
+        );
+        assert_approx_eq!(
+            solar_noon(
+                time::Date::from_calendar(2025, 2, 11, time::Angle::default()),
+                time::Angle::from_degrees(-75.0)
+            )
+            .time(),
+            time::Angle::from_clock(17, 14, 11.0)
+        );
+    }
+
+    #[test]
+    fn test_apparent_solar_time() {
+        let longi = time::Angle::from_degrees(-75.0);
+        let noon = solar_noon(
+            time::Date::from_calendar(2025, 2, 11, time::Angle::default()),
+            longi,
+        );
+        assert_approx_eq!(
+            apparent_solar_time(noon, longi),
+            time::Angle::from_clock(12, 0, 0.0),
+            time::Angle::from_clock(0, 0, 0.1)
+        );
+        // Two hours after noon, the shadow has turned 30°
+        let afternoon = time::Date::from_julian(noon.julian() + 2.0 / 24.0);
+        assert_approx_eq!(
+            solar_hour_angle(afternoon, longi),
+            time::Angle::from_degrees(30.0)
+        );
+    }
+
+    #[test]
+    fn test_equinoxes_solstices() {
+        let expected = [
+            time::Date::from_calendar(2025, 3, 20, time::Angle::from_clock(9, 1, 0.0)),
+            time::Date::from_calendar(2025, 6, 21, time::Angle::from_clock(2, 42, 0.0)),
+            time::Date::from_calendar(2025, 9, 22, time::Angle::from_clock(18, 19, 0.0)),
+            time::Date::from_calendar(2025, 12, 21, time::Angle::from_clock(15, 3, 0.0)),
+        ];
+        for (d, e) in equinoxes_solstices(2025).iter().zip(expected) {
+            // Within 15 minutes
+            assert!((d.julian() - e.julian()).abs() < 0.01, "{:?}", d.calendar());
+        }
+        // The instants from 1996 to 2005 in Chapter 27 of Astronomical Algorithms by Jean Meeus, in dynamical time
+        #[rustfmt::skip]
+        let table = [
+            (1996, [(3, 20, 8, 4, 7), (6, 21, 2, 24, 46), (9, 22, 18, 1, 8), (12, 21, 14, 6, 56)]),
+            (1997, [(3, 20, 13, 55, 42), (6, 21, 8, 20, 59), (9, 22, 23, 56, 49), (12, 21, 20, 8, 5)]),
+            (1998, [(3, 20, 19, 55, 35), (6, 21, 14, 3, 38), (9, 23, 5, 38, 15), (12, 22, 1, 57, 31)]),
+            (1999, [(3, 21, 1, 46, 53), (6, 21, 19, 50, 11), (9, 23, 11, 32, 34), (12, 22, 7, 44, 52)]),
+            (2000, [(3, 20, 7, 36, 19), (6, 21, 1, 48, 46), (9, 22, 17, 28, 40), (12, 21, 13, 38, 30)]),
+            (2001, [(3, 20, 13, 31, 47), (6, 21, 7, 38, 48), (9, 22, 23, 5, 32), (12, 21, 19, 22, 34)]),
+            (2002, [(3, 20, 19, 17, 13), (6, 21, 13, 25, 29), (9, 23, 4, 56, 28), (12, 22, 1, 15, 26)]),
+            (2003, [(3, 21, 1, 0, 50), (6, 21, 19, 11, 32), (9, 23, 10, 47, 53), (12, 22, 7, 4, 53)]),
+            (2004, [(3, 20, 6, 49, 42), (6, 21, 0, 57, 57), (9, 22, 16, 30, 54), (12, 21, 12, 42, 40)]),
+            (2005, [(3, 20, 12, 34, 29), (6, 21, 6, 47, 12), (9, 22, 22, 24, 14), (12, 21, 18, 36, 1)]),
+        ];
+        for (year, seasons) in table {
+            for (d, (m, day, h, min, s)) in equinoxes_solstices(year).iter().zip(seasons) {
+                let e = time::Date::from_calendar(
+                    year,
+                    m,
+                    day,
+                    time::Angle::from_clock(h, min, s as f64),
+                );
+                // Within the eight minutes the sun takes to move 20", the error of its position
+                let error = (d.julian() - e.julian()).abs() * 1440.0;
+                assert!(error < 8.0, "{year}-{m}: {error} minutes");
+            }
+        }
+        // Far from the present, where the Julian calendar has drifted, the seasons are still in order a year apart
+        for year in [-2000, 4000] {
+            let [march, .., december] = equinoxes_solstices(year);
+            let next = equinoxes_solstices(year + 1)[0];
+            assert!(march < december && december < next);
+            assert!((next.julian() - march.julian() - 365.2422).abs() < 0.1);
+        }
+    }
+
     #[test]
     fn test_planet() {
-        assert_eq!(
+        assert_approx_eq!(
             VENUS.location(time::Date::from_calendar(
                 2025,
                 3,
@@ -450,7 +1998,7 @@ mod tests {
                 time::Angle::from_degminsec(10, 54, 50.7)
             )
         );
-        assert_eq!(
+        assert_approx_eq!(
             JUPITER.location(time::Date::from_julian(2460748.41871)),
             coord::Coord::from_equatorial(
                 time::Angle::from_clock(4, 47, 10.5),
@@ -458,15 +2006,464 @@ mod tests {
             )
         );
         assert_eq!(
-            MARS.distance(time::Date::from_julian(2460748.41871)),
-            0.9721731869765856
+            MARS.distance(time::Date::from_julian(2460748.41871)).au(),
+            0.9721891442713538
         );
         assert_eq!(
-            JUPITER.distance(time::Date::from_julian(2460748.41871)),
-            5.183932727328779
+            JUPITER
+                .distance(time::Date::from_julian(2460748.41871))
+                .au(),
+            5.183929355659305
+        );
+    }
+
+    #[test]
+    fn test_ecliptic_cart() {
+        let d = time::Date::from_julian(2460748.41871);
+        // The earth orbits in the plane of the ecliptic
+        assert!(EARTH.locationcart_ecliptic(d).z.abs() < 1e-4);
+        let geo = JUPITER.geocentric_ecliptic(d);
+        assert!((geo.norm() - JUPITER.distance(d).au()).abs() < 1e-12);
+        let ecl = JUPITER.location(d).to_ecliptic(time::J2000);
+        let (lambda, beta) = (ecl.lon(), ecl.lat().angle());
+        assert_approx_eq!(
+            coord::Coord::from(geo),
+            coord::Coord::from_equatorial(lambda, beta)
         );
     }
 
+    #[test]
+    fn test_statecart() {
+        let d = time::Date::from_calendar(2025, 1, 1, time::Angle::default());
+        let (h, (day, f)) = (0.1, d.jd_parts());
+        for p in PLANETS {
+            let (pos, vel) = p.statecart(d);
+            assert_eq!(pos, p.locationcart(d));
+            // Agrees with the change in position over a short time
+            let diff = (p.locationcart(time::Date::from_jd_parts(day, f + h))
+                - p.locationcart(time::Date::from_jd_parts(day, f - h)))
+                * (0.5 / h);
+            assert!((diff - vel).norm() < 1e-3 * vel.norm(), "{}", p.name);
+        }
+        // The earth moves at 30 km/s
+        let v = EARTH.statecart(d).1.norm();
+        assert!((coord::Distance::from_au(v).km() / 86400.0 - 30.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_heliocentric() {
+        // Example 32.a, Venus at 26.11428°, -2.62070°, 0.724603 AU in the ecliptic of date
+        let d = time::Date::from_calendar(1992, 12, 20, time::Angle::default());
+        let (l, b, r) = VENUS.heliocentric(d);
+        let precession = time::Angle::from_degrees(50.29 / 3600.0 * (2000.0 - 1992.97));
+        let tol = time::Angle::from_degrees(0.05);
+        assert_approx_eq!(l, time::Angle::from_degrees(26.11428) + precession, tol);
+        assert_approx_eq!(b, time::Angle::from_degrees(-2.62070), tol);
+        assert!((r.au() - 0.724603).abs() < 1e-4);
+
+        // The earth is opposite the sun, as far as the center of the earth is from the Earth-Moon barycenter
+        let (l, _, r) = EARTH.heliocentric(d);
+        let lambda = SUN.location(d).to_ecliptic(time::J2000).lon();
+        assert_approx_eq!(l, lambda + time::Angle::from_degrees(180.0), tol);
+        assert!((r.au() - SUN.distance(d).au()).abs() < 4e-5);
+    }
+
+    #[test]
+    fn test_elements_at() {
+        let d = time::Date::from_calendar(2025, 1, 1, time::Angle::default());
+        for p in PLANETS {
+            let el = p.elements_at(d);
+            assert_eq!(el.position(), p.locationcart_ecliptic(d));
+            assert!((el.radius() - p.locationcart(d).norm()).abs() < 1e-12);
+            // Keplers equation, and the true anomaly is ahead of the mean anomaly after perihelion
+            let m = el.ea.radians() - el.e * el.ea.sin();
+            assert_approx_eq!(
+                time::Angle::from_radians(m),
+                el.m,
+                time::Angle::from_degrees(1e-6)
+            );
+            assert_eq!(
+                el.nu.to_latitude().radians() > el.m.to_latitude().radians(),
+                el.m.to_latitude().radians() > 0.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_elongation() {
+        // Greatest elongations of Venus, 47.2° east on 2025 January 10, and 45.9° west on 2025 June 1
+        let tol = time::Angle::from_degrees(0.3);
+        let (angle, side) = VENUS.elongation(time::Date::from_calendar(
+            2025,
+            1,
+            10,
+            time::Angle::default(),
+        ));
+        assert_approx_eq!(angle, time::Angle::from_degrees(47.2), tol);
+        assert_eq!(side, Elongation::East);
+        let (angle, side) = VENUS.elongation(time::Date::from_calendar(
+            2025,
+            6,
+            1,
+            time::Angle::default(),
+        ));
+        assert_approx_eq!(angle, time::Angle::from_degrees(45.9), tol);
+        assert_eq!(side, Elongation::West);
+    }
+
+    #[test]
+    fn test_riseset() {
+        let obs = coord::Observer::new(
+            time::Angle::from_degrees(40.0),
+            time::Angle::from_degrees(-75.0),
+            0.0,
+        );
+        let day = time::Date::from_calendar(2025, 3, 10, time::Angle::default());
+        let altitude = |d: time::Date| {
+            let c = MARS.location(d).precess(time::J2000, d);
+            let ((slat, clat), (sde, cde)) = (obs.lat.sincos(), c.declination().angle().sincos());
+            time::Angle::asin(slat * sde + clat * cde * c.hour_angle(d, obs.lon).cos())
+        };
+        let rs = MARS.riseset(day, &obs).unwrap();
+        let tol = time::Angle::from_degminsec(0, 1, 0.0);
+        assert_approx_eq!(altitude(rs.rise), coord::H0_STAR, tol);
+        assert_approx_eq!(altitude(rs.set), coord::H0_STAR, tol);
+        // Highest at transit
+        let (day, f) = rs.transit.jd_parts();
+        for dt in [-0.01, 0.01] {
+            let near = time::Date::from_jd_parts(day, f + dt);
+            assert!(
+                altitude(near).to_latitude().radians()
+                    < altitude(rs.transit).to_latitude().radians()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sun_riseset() {
+        let london =
+            coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+        let day = time::Date::from_calendar(2025, 3, 20, time::Angle::default());
+        let rs = SUN.riseset(day, &london).unwrap();
+        let tol = time::Angle::from_degminsec(0, 15, 0.0); // One minute of time
+        assert_approx_eq!(
+            rs.rise.calendar().3,
+            time::Angle::from_clock(6, 2, 0.0),
+            tol
+        );
+        assert_approx_eq!(
+            rs.set.calendar().3,
+            time::Angle::from_clock(18, 14, 0.0),
+            tol
+        );
+        // The geometric sunrise is several minutes later
+        let geometric = SUN
+            .riseset_altitude(day, &london, time::Angle::default())
+            .unwrap();
+        let minutes = (geometric.rise.julian() - rs.rise.julian()) * 1440.0;
+        assert!((3.0..6.0).contains(&minutes));
+        // The horizon is lower from a height
+        let hill = coord::Observer::new(london.lat, london.lon, 500.0);
+        assert!(SUN.riseset(day, &hill).unwrap().rise < rs.rise);
+    }
+
+    #[test]
+    fn test_subsolar() {
+        let [_, june, _, december] = equinoxes_solstices(2025);
+        let tropic = time::Angle::from_degrees(23.436);
+        assert_approx_eq!(SUN.subsolar(june).0, tropic.into());
+        assert_approx_eq!(SUN.subsolar(december).0, (-tropic).into());
+        // At solar noon the sun is over the meridian
+        let lon = time::Angle::from_degrees(-75.0);
+        let noon = solar_noon(
+            time::Date::from_calendar(2025, 3, 10, time::Angle::default()),
+            lon,
+        );
+        assert_approx_eq!(
+            SUN.subsolar(noon).1,
+            lon,
+            time::Angle::from_degminsec(0, 5, 0.0)
+        );
+        assert!((SUN.subsolar(noon).1.degrees() + 75.0).abs() < 0.1);
+
+        let d = time::Date::from_calendar(2025, 8, 1, time::Angle::from_clock(15, 30, 0.0));
+        let (slat, slon) = SUN.subsolar(d);
+        let points = SUN.terminator(d, 24);
+        assert_eq!(points.len(), 24);
+        for (lat, lon) in points {
+            assert!(-180.0 < lon.degrees() && lon.degrees() <= 180.0);
+            // 90° from the subsolar point, so the sun is on the horizon
+            let (slat, lat) = (slat.angle(), lat.angle());
+            let cosdist = slat.sin() * lat.sin() + slat.cos() * lat.cos() * (lon - slon).cos();
+            assert!(cosdist.abs() < 1e-12);
+            let obs = coord::Observer::new(lat, lon, 0.0);
+            let c = SUN.location_of_date(d);
+            let ((sl, cl), (sde, cde)) = (obs.lat.sincos(), c.declination().angle().sincos());
+            let alt = time::Angle::asin(sl * sde + cl * cde * c.hour_angle(d, obs.lon).cos());
+            assert_approx_eq!(alt, time::Angle::default());
+        }
+    }
+
+    #[test]
+    fn test_physical_ephemeris() {
+        // Example 29.a
+        let eph = SUN.physical_ephemeris(time::Date::from_julian(2448908.5));
+        let tol = time::Angle::from_degrees(0.01);
+        assert_approx_eq!(eph.p, time::Angle::from_degrees(26.27), tol);
+        assert_approx_eq!(eph.b0, time::Angle::from_degrees(5.99), tol);
+        // The sun rotates 14° a day, so this is about a minute of time
+        assert_approx_eq!(eph.l0, time::Angle::from_degrees(238.63), tol * 2.0);
+        assert_eq!(eph.carrington.floor(), 1861.0);
+        // Example 29.b, rotation 1699 began on 1980 August 29.22
+        let start = SUN.physical_ephemeris(time::Date::from_julian(2444480.7230));
+        assert!((start.carrington - 1699.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        // Published magnitudes near oppositions and greatest brilliancy
+        for (p, (y, m, d), mag) in [
+            (&VENUS, (2025, 2, 16), -4.9),
+            (&MARS, (2025, 1, 16), -1.4),
+            (&JUPITER, (2022, 9, 26), -2.9),
+            (&SATURN, (2017, 6, 15), 0.0),
+            (&SATURN, (2025, 9, 21), 0.6),
+            (&URANUS, (2025, 11, 21), 5.6),
+            (&NEPTUNE, (2025, 9, 23), 7.7),
+        ] {
+            let d = time::Date::from_calendar(y, m, d, time::Angle::default());
+            assert!((p.magnitude(d) - mag).abs() < 0.1, "{}", p.name);
+        }
+        // The rings of Saturn add about half a magnitude when open
+        let opposition =
+            |y, m, d| SATURN.magnitude(time::Date::from_calendar(y, m, d, time::Angle::default()));
+        assert!(opposition(2017, 6, 15) < opposition(2025, 9, 21) - 0.4);
+        assert_eq!(MagnitudeLaw::for_planet(&PLUTO), MagnitudeLaw::Simple);
+        // Laws go with the planets themselves, not their names, so a built planet uses its v0
+        assert_eq!(MagnitudeLaw::for_planet(&MARS.clone()), MagnitudeLaw::Mars);
+        let built = Planet { v0: -1.0, ..MARS };
+        assert_eq!(MagnitudeLaw::for_planet(&built), MagnitudeLaw::Simple);
+        let d = time::Date::from_calendar(2025, 1, 16, time::Angle::default());
+        let brighter = Planet { v0: -2.0, ..MARS };
+        assert!((built.magnitude(d) - brighter.magnitude(d) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_saturn_ring() {
+        // Example 45.a
+        let ring = saturn_ring(time::Date::from_julian(2448972.50068));
+        // The position of Saturn is about 12' off from Meeus then, which moves the latitudes by 5'
+        let tol = time::Angle::from_degrees(0.1);
+        assert_approx_eq!(ring.b_earth, time::Angle::from_degrees(16.442), tol);
+        assert_approx_eq!(ring.b_sun, time::Angle::from_degrees(14.679), tol);
+        assert_approx_eq!(ring.pa, time::Angle::from_degrees(6.741), tol);
+        // Wide open in 2017, to the south in 2032
+        let open =
+            |y| saturn_ring(time::Date::from_calendar(y, 6, 15, time::Angle::default())).b_earth;
+        assert!(open(2017).degrees() > 26.0);
+        assert!(open(2032).to_latitude().degrees() < -20.0);
+    }
+
+    #[test]
+    fn test_jupiter_moons() {
+        // Example 44.a
+        let expected = [(-3.44, 0.21), (7.44, 0.25), (1.24, 0.65), (7.08, 1.10)];
+        for (p, (x, y)) in jupiter_moons(time::Date::from_julian(2448972.50068))
+            .into_iter()
+            .zip(expected)
+        {
+            assert!((p.x - x).abs() < 0.01 && (p.y - y).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_satellites() {
+        // The moons of Saturn go around in the plane of the rings, so appear on the same ellipse
+        let start = time::Date::from_calendar(2017, 6, 15, time::Angle::default());
+        let ring = saturn_ring(start);
+        let (sp, cp) = ring.pa.sincos();
+        for s in [&RHEA, &TITAN] {
+            let offsets: Vec<MoonOffset> = (0..400)
+                .map(|k| {
+                    s.offset(time::Date::from_julian(
+                        start.julian() + k as f64 * s.period / 400.0,
+                    ))
+                })
+                .collect();
+            // Along the axis of the rings, and across it towards the west
+            let along = offsets
+                .iter()
+                .map(|o| (o.y * cp - o.x * sp).abs())
+                .fold(0.0, f64::max);
+            let across = offsets
+                .iter()
+                .map(|o| (o.x * cp + o.y * sp).abs())
+                .fold(0.0, f64::max);
+            assert!(
+                (along / across - ring.b_earth.sin()).abs() < 0.02,
+                "{}",
+                s.name
+            );
+            assert!((across - s.a / 60268.0).abs() < 1.0, "{}", s.name);
+        }
+        // The offsets are the same as the separation in the sky
+        let d = time::Date::from_calendar(2025, 1, 1, time::Angle::default());
+        for s in SATELLITES {
+            let o = s.offset(d);
+            let gap = s.location(d).dist(s.planet.location(d)).radians()
+                / (s.planet.angdia(d).radians() / 2.0);
+            assert!(
+                (gap - (o.x * o.x + o.y * o.y).sqrt()).abs() < 0.05 * gap,
+                "{}",
+                s.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let mars = Planet::from_jpl_row(
+            "Mars",
+            "Mars     1.52371034      0.09339410      1.84969142      -4.55343205    -23.94362959     49.55953891
+                      0.00001847      0.00007882     -0.00813131   19140.30268499      0.44441088     -0.29257343",
+        )
+        .unwrap();
+        assert_eq!(
+            mars,
+            Planet {
+                theta0: time::Angle::default(),
+                v0: 0.0,
+                ..MARS
+            }
+        );
+        let jupiter = Planet::from_jpl_row(
+            "Jupiter",
+            "5.20248019  0.04853590  1.29861416  34.33479152  14.27495244  100.29282654
+            -0.00002864  0.00018026  -0.00322699  3034.90371757  0.18199196  0.13024619
+            -0.00012452  0.06064060  -0.35635438  38.35125000",
+        )
+        .unwrap();
+        assert_eq!(jupiter.extra, JUPITER.extra);
+        let row = String::from("EM Bary 1.0 0.0167 0.0 100.46 102.94 0.0 0 0 0 35999.37 0 0");
+        assert_eq!(
+            Planet::from_jpl_row("EM Bary", &row).unwrap().name,
+            "EM Bary"
+        );
+        // Names that read as numbers are only taken from the name
+        assert_eq!(
+            Planet::from_jpl_row(
+                "NaN",
+                "NaN 1.0 0.0167 0.0 100.46 102.94 0.0 0 0 0 35999.37 0 0"
+            )
+            .unwrap()
+            .a,
+            1.0
+        );
+        assert_eq!(
+            Planet::from_jpl_row("Mars", "Mars 1.5 0.09 1.8 inf 0 0 0 0 0 0 0 0"),
+            Err(InvalidPlanet { field: "l" })
+        );
+        assert_eq!(
+            Planet::from_jpl_row("Mars", "Mars 1.5 0.09 1.8 x 0 0 0 0 0 0 0 0"),
+            Err(InvalidPlanet { field: "l" })
+        );
+        assert_eq!(
+            Planet::from_jpl_row("Mars", "Mars 1.5 0.09 1.8"),
+            Err(InvalidPlanet { field: "l" })
+        );
+        assert_eq!(
+            Planet::from_jpl_row("Mars", "Marte 1.5 0.09 1.8 0 0 0 0 0 0 0 0 0"),
+            Err(InvalidPlanet { field: "a" })
+        );
+
+        // The mean motion from Keplers third law
+        let deg = time::Angle::from_degrees;
+        let built = Planet::builder("Mars")
+            .semi_major_axis(MARS.a)
+            .eccentricity(MARS.e)
+            .inclination(deg(MARS.i))
+            .mean_longitude(deg(MARS.l))
+            .longitude_of_periapsis(deg(MARS.w))
+            .longitude_of_node(deg(MARS.o))
+            .build()
+            .unwrap();
+        assert!((built.rates[3] / MARS.rates[3] - 1.0).abs() < 1e-4);
+        let d = time::Date::from_calendar(2001, 6, 13, time::Angle::default());
+        assert_approx_eq!(built.location(d), MARS.location(d), deg(0.1));
+
+        let orbit = |a, e| Planet::builder("Test").semi_major_axis(a).eccentricity(e);
+        assert_eq!(orbit(-1.0, 0.1).build(), Err(InvalidPlanet { field: "a" }));
+        assert_eq!(orbit(1.0, 1.0).build(), Err(InvalidPlanet { field: "e" }));
+        assert_eq!(
+            orbit(1.0, 0.1).inclination(deg(200.0)).build(),
+            Err(InvalidPlanet { field: "i" })
+        );
+        assert_eq!(
+            Planet::builder("Test").eccentricity(0.1).build(),
+            Err(InvalidPlanet { field: "a" })
+        );
+        assert_eq!(
+            crate::Error::from(InvalidPlanet { field: "e" }).to_string(),
+            InvalidPlanet { field: "e" }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_twilights() {
+        let london =
+            coord::Observer::new(time::Angle::from_degrees(51.5), time::Angle::default(), 0.0);
+        let day = time::Date::from_calendar(2025, 3, 20, time::Angle::default());
+        let altitude = |d: time::Date| {
+            let c = SUN.location(d).precess(time::J2000, d);
+            let ((slat, clat), (sde, cde)) =
+                (london.lat.sincos(), c.declination().angle().sincos());
+            time::Angle::asin(slat * sde + clat * cde * c.hour_angle(d, london.lon).cos())
+        };
+        let t = SUN.twilights(day, &london);
+        let (sunrise, sunset) = (t.sunrise.unwrap(), t.sunset.unwrap());
+        let (civil, nautical, astronomical) = (
+            t.civil.unwrap(),
+            t.nautical.unwrap(),
+            t.astronomical.unwrap(),
+        );
+        let tol = time::Angle::from_degminsec(0, 1, 0.0);
+        assert_approx_eq!(altitude(sunrise), coord::H0_SUN, tol);
+        assert_approx_eq!(altitude(civil.0), coord::H0_CIVIL, tol);
+        assert_approx_eq!(altitude(nautical.1), coord::H0_NAUTICAL, tol);
+        assert_approx_eq!(altitude(astronomical.1), coord::H0_ASTRONOMICAL, tol);
+        assert!(astronomical.0 < nautical.0 && nautical.0 < civil.0 && civil.0 < sunrise);
+        assert!(sunset < civil.1 && civil.1 < nautical.1 && nautical.1 < astronomical.1);
+        // Near the equinox, the day is about 12 hours long
+        assert!(((sunset.julian() - sunrise.julian()) * 24.0 - 12.2).abs() < 0.2);
+
+        let tromso = coord::Observer::new(
+            time::Angle::from_degrees(69.65),
+            time::Angle::from_degrees(18.96),
+            0.0,
+        );
+        let t = SUN.twilights(
+            time::Date::from_calendar(2025, 12, 21, time::Angle::default()),
+            &tromso,
+        );
+        assert_eq!(t.sunrise, Err(coord::NoRiseSet::NeverRises));
+        assert!(t.civil.is_ok());
+    }
+
+    #[test]
+    fn test_location_apparent() {
+        let d = time::Date::from_calendar(2025, 3, 10, time::Angle::default());
+        let (geo, app) = (JUPITER.location(d), JUPITER.location_apparent(d));
+        // Jupiter moves about 0.08° a day, and its light takes around 40 minutes
+        let sep = geo.dist(app).degrees() * 3600.0;
+        assert!(sep > 1.0 && sep < 10.0, "{sep}");
+        let sep = NEPTUNE
+            .location(d)
+            .dist(NEPTUNE.location_apparent(d))
+            .degrees()
+            * 3600.0;
+        assert!(sep > 0.5 && sep < 5.0, "{sep}");
+    }
+
     #[test]
     fn test_phase() {
         assert_eq!(
@@ -476,7 +2473,7 @@ mod tests {
                 24,
                 time::Angle::default()
             )),
-            0.010520980535268565
+            0.010521450538735988
         );
         assert_eq!(
             MARS.illumfrac(time::Date::from_calendar(
@@ -485,7 +2482,7 @@ mod tests {
                 24,
                 time::Angle::default()
             )),
-            0.9103262022711702
+            0.9103261608528271
         );
         assert_eq!(
             VENUS.illumfrac(time::Date::from_calendar(
@@ -494,7 +2491,38 @@ mod tests {
                 22,
                 time::Angle::default()
             )),
-            0.30982782608980997
+            0.30979941707477243
+        );
+    }
+
+    #[test]
+    fn test_ssb_offset() {
+        // The sun relative to the barycenter at J2000, from the JPL DE440 ephemeris
+        let v = ssb_offset(time::J2000);
+        let de440 = coord::Vector3::new(-0.0071393, -0.0026431, -0.0009210);
+        assert!((v - de440).norm() < 1e-5);
+        assert_eq!(SUN.location_barycentric(time::J2000), v);
+        assert_eq!(
+            EARTH.location_barycentric(time::J2000) - v,
+            EARTH.locationcart(time::J2000)
+        );
+    }
+
+    #[test]
+    fn test_earth_geocenter() {
+        // The center of the earth is about 4670 km from the barycenter, on the far side from the moon
+        let d = time::Date::from_calendar(2025, 3, 14, time::Angle::default());
+        let offset = earth_geocenter(d) - EARTH.locationcart(d);
+        let km = coord::Distance::from_au(offset.norm()).km();
+        assert!((4400.0..4900.0).contains(&km), "{km}");
+        let moon = crate::moon::MOON
+            .location_precise(d)
+            .precess_rigorous(d, time::J2000);
+        assert_approx_eq!(
+            coord::Coord::from(-offset),
+            moon,
+            time::Angle::from_degrees(0.01)
         );
+        assert_eq!(SUN.locationcart(d), -earth_geocenter(d));
     }
 }
@@ -0,0 +1,169 @@
+/*! Stars, with proper motion and parallax
+
+A [`Star`] is a [`CelObj`], so it can be used anywhere a planet can, such as [`CelObj::observe()`].
+```
+# use pracstro::{star::Star, time::{self, Angle, AngularRate}, CelObj};
+let barnard = Star {
+    pm_ra: AngularRate::from_arcsec_per_year(-0.80258),
+    pm_dec: AngularRate::from_arcsec_per_year(10.36202),
+    parallax: Angle::from_degminsec(0, 0, 0.54655),
+    ..Star::new("Barnard's Star", Angle::from_degrees(269.45207), Angle::from_degrees(4.69339), 9.51)
+};
+barnard.location(time::Date::from_calendar(2050, 1, 1, Angle::default()));
+```
+*/
+
+use crate::celobj::CelObj;
+use crate::coord::{Coord, Distance, Vector3};
+use crate::sol::EARTH;
+use crate::time::{Angle, AngularRate, Date, J2000};
+use std::borrow::Cow;
+
+/// A star, from its catalog position and motion
+///
+/// The location is the mean position of date: proper motion is applied from the epoch of the catalog,
+/// then the position is precessed to the date and corrected for annual parallax.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Star {
+    /// Name of the star
+    pub name: Cow<'static, str>,
+    /// Position in the ICRS at the epoch
+    pub coord_icrs: Coord,
+    /// Proper motion in right ascension, as μα·cos(δ), which is how catalogs give it
+    pub pm_ra: AngularRate,
+    /// Proper motion in declination
+    pub pm_dec: AngularRate,
+    /// Annual parallax, zero if unknown
+    pub parallax: Angle,
+    /// Visual magnitude
+    pub vmag: f64,
+    /// Epoch of the position, J2000 for most catalogs, but J2016 for Gaia DR3
+    pub epoch: Date,
+}
+impl Star {
+    /// A star at a fixed ICRS position at J2000, without proper motion or parallax
+    pub const fn new(name: &'static str, ra: Angle, de: Angle, vmag: f64) -> Self {
+        Star {
+            name: Cow::Borrowed(name),
+            coord_icrs: Coord::from_equatorial(ra, de),
+            pm_ra: AngularRate::from_radians_per_day(0.0),
+            pm_dec: AngularRate::from_radians_per_day(0.0),
+            parallax: Angle::from_radians(0.0),
+            vmag,
+            epoch: J2000,
+        }
+    }
+
+    /// Rectangular coordinates of the star, as the mean position of date, in AU from the earth
+    ///
+    /// Without `annual_parallax`, or for a star without a parallax, the position is from the sun,
+    /// and the vector has a length of 1 if the parallax is unknown.
+    pub fn position(&self, d: Date, annual_parallax: bool) -> Vector3 {
+        let (ra, de) = self.coord_icrs.from_icrs().equatorial();
+        let ((sra, cra), (sde, cde)) = (ra.sincos(), de.sincos());
+        // Proper motion is linear on the unit sphere, towards the east and north
+        let days = d.julian() - self.epoch.julian();
+        let (dra, dde) = (
+            self.pm_ra.radians_per_day() * days,
+            self.pm_dec.radians_per_day() * days,
+        );
+        let east = Vector3::new(-sra, cra, 0.0);
+        let north = Vector3::new(-sde * cra, -sde * sra, cde);
+        let mut v = Coord::from_equatorial(ra, de).cartesian(1.0) + east * dra + north * dde;
+        v = v * (1.0 / v.norm());
+
+        let p = self.parallax.to_latitude().radians();
+        if p > 0.0 {
+            v = v * (1.0 / p);
+            if annual_parallax {
+                v = v - EARTH.locationcart(d);
+            }
+        }
+        let dist = v.norm();
+        Coord::from(v).precess_rigorous(J2000, d).cartesian(dist)
+    }
+}
+
+/// The origin is the earth, with annual parallax applied
+///
+/// A star without a parallax is infinitely far away, and its [`CelObj::locationcart()`] is a unit vector.
+impl CelObj for Star {
+    fn locationcart(&self, d: Date) -> Vector3 {
+        self.position(d, true)
+    }
+    fn distance(&self, d: Date) -> Distance {
+        match self.parallax.to_latitude().radians() > 0.0 {
+            true => Distance::from_au(self.locationcart(d).norm()),
+            false => Distance::from_au(f64::INFINITY),
+        }
+    }
+    fn magnitude(&self, _: Date) -> f64 {
+        self.vmag
+    }
+    fn angdia(&self, _: Date) -> Angle {
+        Angle::default()
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Observer;
+
+    #[test]
+    fn test_star() {
+        // Astronomical Algorithms, Example 21.b, Theta Persei from J2000 to 2028 November 13.19
+        let theta_persei = Star {
+            pm_ra: AngularRate::from_arcsec_per_year(
+                0.03425 * 15.0 * 49.2285_f64.to_radians().cos(),
+            ),
+            pm_dec: AngularRate::from_arcsec_per_year(-0.0895),
+            ..Star::new(
+                "Theta Persei",
+                Angle::from_clock(2, 44, 11.986),
+                Angle::from_degminsec(49, 13, 42.48),
+                4.1,
+            )
+        };
+        let d = Date::from_calendar(2028, 11, 13, Angle::from_clock(4, 33, 36.0));
+        assert_approx_eq!(
+            theta_persei.location(d),
+            Coord::from_equatorial(
+                Angle::from_clock(2, 46, 11.331),
+                Angle::from_degminsec(49, 20, 54.54)
+            ),
+            Angle::from_degminsec(0, 0, 0.1)
+        );
+        assert_eq!(theta_persei.distance(d).au(), f64::INFINITY);
+        assert_eq!(theta_persei.name(), "Theta Persei");
+
+        // Annual parallax moves a star by up to its parallax, here 1" for a star 1 parsec away
+        let near = Star {
+            parallax: Angle::from_degminsec(0, 0, 1.0),
+            ..Star::new(
+                "Near",
+                Angle::from_degrees(90.0),
+                Angle::from_degrees(66.56),
+                0.0,
+            )
+        };
+        let (far, d) = (
+            Star {
+                parallax: Angle::default(),
+                ..near.clone()
+            },
+            J2000,
+        );
+        let shift = near.location(d).dist(far.location(d)).degrees() * 3600.0;
+        assert!(shift > 0.5 && shift < 1.0001, "{shift}");
+        assert!((near.distance(d).au() - 206264.8).abs() < 2.0);
+        assert!((near.position(d, false).norm() - 206264.8).abs() < 0.1);
+
+        let obs = Observer::new(Angle::from_degrees(40.0), Angle::default(), 0.0);
+        assert!(near.observe(&obs, d).riseset.is_err());
+    }
+}
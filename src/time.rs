@@ -2,8 +2,10 @@
 //!
 //! This module contains functions for the handling and conversion of Times, Dates, and Angles.
 //!
-//! This data can be represented in two types:
+//! This data can be represented in four types:
 //! - The [`Angle`] type, which represents anything modulo arithmetic should be used to handle
+//! - The [`Latitude`] type, which represents a signed angle such as a declination or altitude
+//! - The [`AngularRate`] type, which represents how fast an angle changes
 //! - The [`Date`] type, which represents an instant in continuous time
 //!
 //! ```rust
@@ -11,9 +13,10 @@
 //! time::Date::from_calendar(2024, 06, 30, time::Angle::from_clock(16, 30, 0.0)).julian(); // Gets the julian date at 2024-06-30T16:30:00Z
 //! ```
 
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use std::fmt;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /**
 Angles and Time are the most prominent use for this type
@@ -27,16 +30,23 @@ Angles and Time are the most prominent use for this type
 | Clock Time        | [`Angle::clock()`]     | [`Angle::from_clock()`]     |
 | Degrees (DMS)     | [`Angle::degminsec()`] | [`Angle::from_degminsec()`] |
 | Sine              | [`Angle::sin()`]       | [`Angle::asin()`]           |
+| Sine and Cosine   | [`Angle::sincos()`]    | [`Angle::atan2()`]          |
 | Cosine            | [`Angle::cos()`]       | [`Angle::acos()`]           |
 | Tangent           | [`Angle::tan()`]       | [`Angle::atan2()`]          |
 
 Additional Methods:
 * Latitude displaying: [`Angle::to_latitude()`]
 * Inverse of angle: [`Angle::inverse()`]
+* Interpolation: [`Angle::lerp()`], see [`crate::misc`] for more points
+* Clock Time and DMS rounded for display: [`Angle::clock_rounded()`] and [`Angle::degminsec_rounded()`]
+* Comparison within a tolerance: [`Angle::approx_eq()`], `==` is exact
+* Shortest signed difference: [`Angle::signed_diff()`]
 * GST Correction: [`Angle::gst()`] and [`Angle::ungst()`]
-* Approx. Atmosphereic Refraction: [`Angle::refract()`] and [`Angle::refractdelta()`]
+* Approx. Atmosphereic Refraction: [`Angle::refract()`], [`Angle::unrefract()`], and [`Angle::refractdelta()`]
+* Parsing from text: [`parse_angle()`], also available through [`str::parse()`]
+* Formatting as text: [`Angle::fmt_hms()`] and [`Angle::fmt_dms()`], also available through [`std::fmt::Display`]
 */
-#[derive(Clone, Copy, Default, PartialOrd)]
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct Angle(f64);
 impl Angle {
     /// Returns the angle as radians.
@@ -173,6 +183,34 @@ impl Angle {
             (y.fract() * 60.0).fract() * 60.0,
         )
     }
+    /// Converts an angle to hours, minutes, and seconds, with seconds rounded to `precision` decimal places
+    ///
+    /// Rounding is carried into the minutes and hours, so seconds are never 60.
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// Angle::from_clock(7, 59, 59.9999).clock_rounded(2); // (8, 0, 0.0)
+    /// ```
+    pub fn clock_rounded(self, precision: usize) -> (u8, u8, f64) {
+        let (_, h, m, s) = sexagesimal(self.decimal(), precision);
+        ((h % 24) as u8, m, s)
+    }
+    /// Converts an angle to whether it is negative, degrees, arcminutes, and arcseconds, with arcseconds rounded to
+    /// `precision` decimal places
+    ///
+    /// Rounding is carried into the arcminutes and degrees, so arcseconds are never 60.
+    /// The sign is kept apart from the degrees, so that a latitude between 0° and -1° keeps it.
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// Angle::from_degrees(-0.5).to_latitude().degminsec_rounded(0); // (true, 0, 30, 0.0)
+    /// ```
+    pub fn degminsec_rounded(self, precision: usize) -> (bool, u16, u8, f64) {
+        let (neg, d, m, s) = sexagesimal(self.degrees(), precision);
+        match neg {
+            true => (true, d as u16, m, s),
+            false => (false, (d % 360) as u16, m, s),
+        }
+    }
+
     /// Identical to from_clock in math
     pub const fn from_degminsec(d: i16, m: u8, s: f64) -> Self {
         Angle::from_degrees((d as f64) + (((m as f64) + (s / 60.0)) / 60.0))
@@ -211,6 +249,12 @@ impl Angle {
     pub fn tan(self) -> f64 {
         self.radians().tan()
     }
+    /// Sine and Cosine of Angle, computed together
+    ///
+    /// A wrapper around [`f64::sin_cos()`], which is faster than calling both when both are needed
+    pub fn sincos(self) -> (f64, f64) {
+        self.radians().sin_cos()
+    }
     /// Angle from Arcsine
     pub fn asin(x: f64) -> Self {
         Angle::from_radians(x.asin())
@@ -228,21 +272,58 @@ impl Angle {
         Angle::from_radians(TAU - self.radians())
     }
 
+    /// The shortest signed difference `self - other`, in the range of (-180°, 180°\]
+    ///
+    /// Positive when this angle is ahead of (east of) `other`. The result is a latitude, as from [`Angle::to_latitude()`].
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// Angle::from_degrees(5.0).signed_diff(Angle::from_degrees(355.0)).degrees(); // 10.0
+    /// Angle::from_degrees(355.0).signed_diff(Angle::from_degrees(5.0)).degrees(); // -10.0
+    /// ```
+    pub fn signed_diff(self, other: Self) -> Self {
+        (self - other).to_latitude()
+    }
+
+    /// Linear interpolation between two angles, taking the shortest way around the circle
+    ///
+    /// `t` is 0 at this angle, and 1 at `b`, values outside of \[0, 1\] extrapolate.
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// let ra = Angle::lerp(Angle::from_clock(23, 58, 0.0), Angle::from_clock(0, 2, 0.0), 0.75);
+    /// ra.clock(); // (0, 1, 0.0)
+    /// ```
+    pub fn lerp(self, b: Self, t: f64) -> Self {
+        self + Angle::from_radians(b.signed_diff(self).radians() * t)
+    }
+
+    /// Checks if two angles are within a tolerance of each other, taking the shortest way around the circle
+    ///
+    /// Comparison with `==` is exact, which is rarely what is wanted for computed results.
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// let a = Angle::from_degminsec(359, 59, 50.0);
+    /// assert!(a.approx_eq(Angle::from_degrees(0.0), Angle::from_degminsec(0, 0, 15.0)));
+    /// assert!(a != Angle::from_degminsec(359, 59, 50.1));
+    /// ```
+    pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+        self.signed_diff(other).radians().abs() <= tolerance.radians()
+    }
+
     /// Calculates the approximate atmospheric refraction
     ///
     /// In reality, this is an complex calculation dependent on factors such as temperature and pressure. But it can be
     /// Approximated to a reasonable extent assuming some factors.
     ///
+    /// Altitudes below the horizon are taken as negative, see [`coord::refraction()`](crate::coord::refraction) for the
+    /// limit on how far down the formula holds.
+    ///
     /// From <https://www.celestialprogramming.com/snippets/atmosphericrefraction.html>
     pub fn refractdelta(self) -> Self {
+        let h = self.to_latitude().degrees();
         Angle::from_degminsec(
             0,
             0,
-            (1.02
-                / (self.degrees() + (10.3 / (self.degrees() + 5.11)))
-                    .to_radians()
-                    .tan())
-                * 60.0,
+            (1.02 / (h + (10.3 / (h + 5.11))).to_radians().tan()) * 60.0,
         )
     }
     /// Accounts for atmospheric refraction
@@ -257,21 +338,78 @@ impl Angle {
             self
         }
     }
+    /// Removes atmospheric refraction, the inverse of [`Angle::refract()`]
+    ///
+    /// This takes an apparent altitude, such as one measured with a theodolite or sextant, and returns the geometric altitude.
+    /// Since the refraction depends on the geometric altitude, this is solved by iteration.
+    ///
+    /// This does not calculate refraction on altitudes under the horizon
+    pub fn unrefract(self) -> Self {
+        if self.to_latitude().degrees() <= 0.0 {
+            return self;
+        }
+        let mut h = self;
+        for _ in 0..10 {
+            let r = Angle::from_degrees(h.to_latitude().degrees().max(0.0)).refractdelta();
+            h = self - r;
+        }
+        h
+    }
+
+    /// Formats the angle as hours, minutes, and seconds with `precision` decimal places of seconds
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// Angle::from_clock(14, 16, 50.0).fmt_hms(1); // "14h16m50.0s"
+    /// ```
+    pub fn fmt_hms(self, precision: usize) -> String {
+        let (h, m, s) = self.clock_rounded(precision);
+        format!("{}h{:02}m{}s", h, m, fmt_seconds(s, precision))
+    }
+    /// Formats the angle as degrees, arcminutes, and arcseconds with `precision` decimal places of arcseconds
+    ///
+    /// Angles are formatted in \[0°, 360°\), use [`Angle::to_latitude()`] first for signed angles.
+    /// ```
+    /// # use pracstro::time::Angle;
+    /// Angle::from_degrees(-25.5).to_latitude().fmt_dms(0); // "-25°30'00\""
+    /// ```
+    pub fn fmt_dms(self, precision: usize) -> String {
+        let (neg, d, m, s) = sexagesimal(self.degrees(), precision);
+        let (sign, d) = if neg { ("-", d) } else { ("", d % 360) };
+        format!("{}{}°{:02}'{}\"", sign, d, m, fmt_seconds(s, precision))
+    }
+}
+/// Zero-pads seconds to two integer digits
+fn fmt_seconds(s: f64, precision: usize) -> String {
+    let width = if precision > 0 { precision + 3 } else { 2 };
+    format!("{:0w$.p$}", s, w = width, p = precision)
+}
+/// Splits a value into its sign, whole part, sixtieths, and three-thousand-six-hundredths
+///
+/// The last field is rounded to `precision` decimal places first, so that it never displays as 60.
+fn sexagesimal(x: f64, precision: usize) -> (bool, u64, u8, f64) {
+    let scale = 10u64.pow(precision as u32);
+    let total = (x.abs() * 3600.0 * scale as f64).round() as u64;
+    let (whole, rest) = (total / (3600 * scale), total % (3600 * scale));
+    (
+        x < 0.0,
+        whole,
+        (rest / (60 * scale)) as u8,
+        (rest % (60 * scale)) as f64 / scale as f64,
+    )
+}
+/// Degrees, arcminutes, and arcseconds, the precision of arcseconds defaults to 2 decimal places
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.fmt_dms(f.precision().unwrap_or(2)))
+    }
 }
 /// Used in testing
 impl fmt::Debug for Angle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (d, m, s) = self.degminsec();
+        let (neg, d, m, s) = self.degminsec_rounded(2);
+        let sign = if neg { "-" } else { "" };
         // Hubble has a resolution of 0.1", this is more than sufficent
-        write!(f, "{}°{}'{:.2}\"", d, m, s)
-    }
-}
-/// Does not check if arcseconds are equal
-impl PartialEq for Angle {
-    fn eq(&self, other: &Self) -> bool {
-        let (d, m, _) = self.degminsec();
-        let (d2, m2, _) = other.degminsec();
-        d == d2 && m == m2
+        write!(f, "{}{}°{}'{:.2}\"", sign, d, m, s)
     }
 }
 impl Add<Angle> for Angle {
@@ -288,6 +426,63 @@ impl Sub<Angle> for Angle {
         Angle::from_radians(self.radians() - x.radians())
     }
 }
+impl Add<f64> for Angle {
+    type Output = Angle;
+    /// Addition of degrees
+    fn add(self, x: f64) -> Self {
+        self + Angle::from_degrees(x)
+    }
+}
+impl Sub<f64> for Angle {
+    type Output = Angle;
+    /// Subtraction of degrees
+    fn sub(self, x: f64) -> Self {
+        self - Angle::from_degrees(x)
+    }
+}
+impl Neg for Angle {
+    type Output = Angle;
+    /// Negation, the same as [`Angle::inverse()`]
+    fn neg(self) -> Self {
+        Angle::from_radians(-self.radians())
+    }
+}
+impl AddAssign<Angle> for Angle {
+    /// Addition, For advancing an angle by a rate
+    fn add_assign(&mut self, x: Angle) {
+        *self = *self + x;
+    }
+}
+impl SubAssign<Angle> for Angle {
+    /// Subtraction
+    fn sub_assign(&mut self, x: Angle) {
+        *self = *self - x;
+    }
+}
+impl AddAssign<f64> for Angle {
+    /// Addition of degrees
+    fn add_assign(&mut self, x: f64) {
+        *self = *self + x;
+    }
+}
+impl SubAssign<f64> for Angle {
+    /// Subtraction of degrees
+    fn sub_assign(&mut self, x: f64) {
+        *self = *self - x;
+    }
+}
+impl MulAssign<f64> for Angle {
+    /// Multiplication
+    fn mul_assign(&mut self, x: f64) {
+        *self = *self * x;
+    }
+}
+/// Sum of angles, such as the terms of a series
+impl std::iter::Sum for Angle {
+    fn sum<I: Iterator<Item = Angle>>(iter: I) -> Self {
+        iter.fold(Angle::default(), |a, b| a + b)
+    }
+}
 impl Mul<f64> for Angle {
     type Output = Angle;
     /// Multiplication
@@ -303,55 +498,410 @@ impl Div<f64> for Angle {
     }
 }
 
+/**
+A rate of change of an angle, such as a mean motion or a proper motion
+
+| Property            | To Method                              | From Method                                 |
+|---------------------|----------------------------------------|---------------------------------------------|
+| Radians per Day     | [`AngularRate::radians_per_day()`]     | [`AngularRate::from_radians_per_day()`]     |
+| Radians per Second  | [`AngularRate::radians_per_second()`]  | [`AngularRate::from_radians_per_second()`]  |
+| Degrees per Day     | [`AngularRate::degrees_per_day()`]     | [`AngularRate::from_degrees_per_day()`]     |
+| Degrees per Century | [`AngularRate::degrees_per_century()`] | [`AngularRate::from_degrees_per_century()`] |
+| Arcseconds per Year | [`AngularRate::arcsec_per_year()`]     | [`AngularRate::from_arcsec_per_year()`]     |
+
+Unlike an [`Angle`], a rate isn't reduced to a range, and can be negative.
+Multiplying a rate by a [`std::time::Duration`] gives the angle moved in that time,
+[`AngularRate::over()`] does the same for a (possibly negative) number of days.
+```
+# use pracstro::time::{Angle, AngularRate};
+let mut l = Angle::from_degrees(100.0);
+l += AngularRate::from_degrees_per_day(0.9856) * std::time::Duration::from_secs(86400 * 10);
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct AngularRate(f64);
+impl AngularRate {
+    /// Returns the rate in radians per day
+    ///
+    /// This is the only function that should directly read the fields of the type
+    pub const fn radians_per_day(self) -> f64 {
+        self.0
+    }
+    /// Constructs a rate from radians per day
+    ///
+    /// This is the only function that should directly write the fields of the type
+    pub const fn from_radians_per_day(x: f64) -> Self {
+        AngularRate(x)
+    }
+
+    /// Returns the rate in radians per SI second
+    pub const fn radians_per_second(self) -> f64 {
+        self.radians_per_day() / 86400.0
+    }
+    /// Constructs a rate from radians per SI second
+    pub const fn from_radians_per_second(x: f64) -> Self {
+        AngularRate::from_radians_per_day(x * 86400.0)
+    }
+
+    /// Returns the rate in degrees per day
+    pub const fn degrees_per_day(self) -> f64 {
+        self.radians_per_day().to_degrees()
+    }
+    /// Constructs a rate from degrees per day
+    pub const fn from_degrees_per_day(x: f64) -> Self {
+        AngularRate::from_radians_per_day(x.to_radians())
+    }
+
+    /// Returns the rate in degrees per Julian century, which is how the rates of orbital elements are given
+    pub const fn degrees_per_century(self) -> f64 {
+        self.degrees_per_day() * 36525.0
+    }
+    /// Constructs a rate from degrees per Julian century
+    pub const fn from_degrees_per_century(x: f64) -> Self {
+        AngularRate::from_degrees_per_day(x / 36525.0)
+    }
+
+    /// Returns the rate in arcseconds per Julian year, which is how proper motions are given
+    pub const fn arcsec_per_year(self) -> f64 {
+        self.degrees_per_day() * 3600.0 * 365.25
+    }
+    /// Constructs a rate from arcseconds per Julian year
+    pub const fn from_arcsec_per_year(x: f64) -> Self {
+        AngularRate::from_degrees_per_day(x / (3600.0 * 365.25))
+    }
+
+    /// The angle moved through in a number of days, which can be negative
+    pub const fn over(self, days: f64) -> Angle {
+        Angle::from_radians(self.radians_per_day() * days)
+    }
+}
+impl Mul<std::time::Duration> for AngularRate {
+    type Output = Angle;
+    /// The angle moved through in a duration
+    fn mul(self, x: std::time::Duration) -> Angle {
+        Angle::from_radians(self.radians_per_second() * x.as_secs_f64())
+    }
+}
+impl Mul<f64> for AngularRate {
+    type Output = AngularRate;
+    /// Scaling
+    fn mul(self, x: f64) -> Self {
+        AngularRate::from_radians_per_day(self.radians_per_day() * x)
+    }
+}
+impl Add<AngularRate> for AngularRate {
+    type Output = AngularRate;
+    /// Addition, for combining motions
+    fn add(self, x: Self) -> Self {
+        AngularRate::from_radians_per_day(self.radians_per_day() + x.radians_per_day())
+    }
+}
+impl Sub<AngularRate> for AngularRate {
+    type Output = AngularRate;
+    /// Subtraction, for relative motion
+    fn sub(self, x: Self) -> Self {
+        AngularRate::from_radians_per_day(self.radians_per_day() - x.radians_per_day())
+    }
+}
+
+/**
+A signed angle in the range of \[-90°, 90°\], such as a latitude, declination, or altitude
+
+[`Angle`] is always positive, so a declination of -25° is stored as 335°, and is easy to print or compare as such.
+This type keeps the sign, so it orders and formats like a latitude does.
+An angle past a pole is the latitude it comes back down to on the other side, so 100° is 80°, and 270° is -90°.
+```
+# use pracstro::time::{Angle, Latitude};
+let de = Latitude::from(Angle::from_degrees(-25.5));
+assert!(de < Latitude::from_degrees(0.0));
+de.to_string(); // "-25°30'00.00\""
+```
+
+| Property          | To Method                  | From Method                     |
+|-------------------|----------------------------|---------------------------------|
+| Degrees (Decimal) | [`Latitude::degrees()`]    | [`Latitude::from_degrees()`]    |
+| Radians           | [`Latitude::radians()`]    | [`Latitude::from_radians()`]    |
+| Angle             | [`Latitude::angle()`]      | [`Latitude::from()`]            |
+*/
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Latitude(f64);
+impl Latitude {
+    /// Returns the latitude as signed radians
+    pub const fn radians(self) -> f64 {
+        self.0
+    }
+    /// Constructs a latitude from radians, reducing it to the range of \[-PI/2, PI/2\]
+    pub const fn from_radians(x: f64) -> Self {
+        let x = match -PI < x && x <= PI {
+            true => x,
+            false => Angle::from_radians(x).to_latitude().radians(),
+        };
+        if x > FRAC_PI_2 {
+            Latitude(PI - x)
+        } else if x < -FRAC_PI_2 {
+            Latitude(-PI - x)
+        } else {
+            Latitude(x)
+        }
+    }
+    /// Returns the latitude as signed degrees
+    pub const fn degrees(self) -> f64 {
+        self.radians().to_degrees()
+    }
+    /// Constructs a latitude from degrees
+    pub const fn from_degrees(x: f64) -> Self {
+        Latitude::from_radians(x.to_radians())
+    }
+    /// Returns the latitude as an [`Angle`], for use in calculations
+    pub const fn angle(self) -> Angle {
+        Angle::from_radians(self.radians())
+    }
+    /// Checks if two latitudes are within a tolerance of each other
+    pub fn approx_eq(self, other: Self, tolerance: Angle) -> bool {
+        self.angle().approx_eq(other.angle(), tolerance)
+    }
+}
+impl From<Angle> for Latitude {
+    fn from(a: Angle) -> Self {
+        Latitude::from_radians(a.radians())
+    }
+}
+impl From<Latitude> for Angle {
+    fn from(l: Latitude) -> Self {
+        l.angle()
+    }
+}
+/// Signed degrees, arcminutes, and arcseconds, the precision of arcseconds defaults to 2 decimal places
+impl fmt::Display for Latitude {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(
+            &self
+                .angle()
+                .to_latitude()
+                .fmt_dms(f.precision().unwrap_or(2)),
+        )
+    }
+}
+impl fmt::Debug for Latitude {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.2}", self)
+    }
+}
+
+/// Error returned when a string can't be parsed as an [`Angle`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseAngleError;
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid angle")
+    }
+}
+impl std::error::Error for ParseAngleError {}
+
+/// Parses an angle from text
+///
+/// Accepted forms are:
+/// - Hours, minutes, and seconds: `14h16m50s`
+/// - Degrees, arcminutes, and arcseconds: `19°02'50.1"` or `19d02m50.1s`
+/// - Colon separated degrees, arcminutes, and arcseconds: `-16:45:06.8`
+/// - Decimal degrees: `133.94531`
+///
+/// Trailing fields can be left out (`14h16m`), and a leading sign applies to the whole angle.
+/// ```
+/// # use pracstro::time::{parse_angle, Angle};
+/// let arcturus_ra = parse_angle("14h16m50s").unwrap();
+/// assert!(arcturus_ra.approx_eq(Angle::from_clock(14, 16, 50.0), Angle::from_degminsec(0, 0, 0.001)));
+/// assert_eq!("-16:45:00".parse(), Ok(Angle::from_degrees(-16.75)));
+/// ```
+pub fn parse_angle(s: &str) -> Result<Angle, ParseAngleError> {
+    let s = s.trim();
+    let (sign, body) = match s.strip_prefix('-') {
+        Some(b) => (-1.0, b),
+        None => (1.0, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if let Ok(x) = body.parse::<f64>() {
+        return match x.is_finite() {
+            true => Ok(Angle::from_degrees(sign * x)),
+            false => Err(ParseAngleError),
+        };
+    }
+
+    const SEPS: [char; 11] = ['h', 'd', '°', 'm', '\'', '′', ':', 's', '"', '″', ' '];
+    let hours = body.find(|c: char| SEPS.contains(&c)) == body.find('h');
+    let mut fields = body.split(SEPS).filter(|f| !f.is_empty());
+    let mut value = 0.0;
+    for scale in [1.0, 60.0, 3600.0] {
+        let Some(f) = fields.next() else { break };
+        let x = f.parse::<f64>().map_err(|_| ParseAngleError)?;
+        if !x.is_finite() || x < 0.0 || (scale > 1.0 && x >= 60.0) {
+            return Err(ParseAngleError);
+        }
+        value += x / scale;
+    }
+    if fields.next().is_some() || body.is_empty() || body.starts_with(SEPS) {
+        return Err(ParseAngleError);
+    }
+
+    Ok(match hours {
+        true => Angle::from_decimal(sign * value),
+        false => Angle::from_degrees(sign * value),
+    })
+}
+impl FromStr for Angle {
+    type Err = ParseAngleError;
+    /// See [`parse_angle()`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_angle(s)
+    }
+}
+
 /**
 Continuous Instant in Time
 
-| Property          | To Method             | From Method                |
-|-------------------|-----------------------|----------------------------|
-| Julian Day        | [`Date::julian()`]    | [`Date::from_julian()`]    |
-| Calendar          | [`Date::calendar()`]  | [`Date::from_calendar()`]  |
-| Unix Time         | [`Date::unix()`]      | [`Date::from_unix()`]      |
-| Date/Time         | [`Date::time()`]      | [`Date::from_time()`]      |
+| Property              | To Method               | From Method                  |
+|-----------------------|-------------------------|------------------------------|
+| Julian Day            | [`Date::julian()`]      | [`Date::from_julian()`]      |
+| Julian Day (Split)    | [`Date::jd_parts()`]    | [`Date::from_jd_parts()`]    |
+| Calendar              | [`Date::calendar()`]    | [`Date::from_calendar()`]    |
+| Other Calendar System | [`Date::calendar_in()`] | [`Date::from_calendar_in()`] |
+| Local Calendar        | [`Date::calendar_tz()`] | [`Date::from_calendar_tz()`] |
+| Unix Time             | [`Date::unix()`]        | [`Date::from_unix()`]        |
+| Islamic Calendar      | [`Date::islamic()`]     | [`Date::from_islamic()`]     |
+| Hebrew Calendar       | [`Date::hebrew()`]      | [`Date::from_hebrew()`]      |
+| Date/Time             | [`Date::time()`]        | [`Date::from_time()`]        |
 
 Additional Methods
-* Get the current time: [`Date::now()`]
+* Get the current time: [`Date::now()`] and [`Date::try_now()`], or from any [`Clock`]
 * Julian Centuries since J2000: [`Date::centuries()`]
+* Besselian epochs: [`Date::besselian()`] and [`Date::from_besselian()`]
+* Julian epochs: [`Date::julian_epoch()`] and [`Date::from_julian_epoch()`]
+* Decimal years: [`Date::decimal_year()`] and [`Date::from_decimal_year()`]
+* Dates at a fixed step, for tables: [`Date::range()`]
+* Sidereal time: [`Date::gst()`] and [`Date::lst()`], or [`Date::gmst()`] from the Earth Rotation Angle [`Date::era()`]
+* Atomic time: [`Date::to_tai()`] and [`Date::from_tai()`], with the offset from [`Date::tai_utc()`]
+* Calendar properties: [`Date::weekday()`], [`Date::day_of_year()`], and [`Date::iso_week()`]
+
+Dates are compared, ordered, and hashed to the nearest millisecond, so they can be used as keys in maps and sets.
 */
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Date(f64);
+#[derive(Debug, Clone, Copy)]
+pub struct Date(f64, f64);
 impl Date {
     /// Returns time as Julian Days
     ///
     /// Direct interface to type
     ///
-    /// This is the only function that should directly read the fields of the type
+    /// This is one of the two only functions that should directly read the fields of the type
     pub const fn julian(self) -> f64 {
-        self.0
+        self.0 + self.1
     }
     /// Constructs time from Julian Days
     ///
     /// Direct interface to type
+    pub const fn from_julian(x: f64) -> Self {
+        Date::from_jd_parts(x, 0.0)
+    }
+
+    /// Returns time as a whole Julian Day number and a fraction of a day in \[0, 1\)
+    ///
+    /// A single [`f64`] Julian date has a resolution of around 40 microseconds for modern dates,
+    /// the fraction of the day kept on its own is far more precise.
+    /// ```
+    /// # use pracstro::time::Date;
+    /// Date::from_julian(2_446_113.75).jd_parts(); // (2446113.0, 0.75)
+    /// ```
+    pub const fn jd_parts(self) -> (f64, f64) {
+        (self.0, self.1)
+    }
+    /// Constructs time from a Julian Day and a fraction of a day
+    ///
+    /// Neither part needs to be normalized, any whole days in the fraction are carried over.
     ///
     /// This is the only function that should directly write the fields of the type
-    pub const fn from_julian(x: f64) -> Self {
-        Date(x)
+    pub const fn from_jd_parts(day: f64, fraction: f64) -> Self {
+        let whole = day.floor();
+        let f = (day - whole) + fraction;
+        let carry = f.floor();
+        Date(whole + carry, f - carry)
+    }
+    /// The Julian Day and the millisecond of that day, the quantum dates are compared with
+    ///
+    /// The day is kept as a float, so dates too far off for an [`i64`] of milliseconds, and NaN, still compare
+    /// consistently.
+    fn millis(self) -> (f64, i64) {
+        let (day, f) = self.jd_parts();
+        match (f * 86_400_000.0).round() as i64 {
+            86_400_000 => (day + 1.0, 0),
+            ms => (day, ms),
+        }
     }
 
-    /// Returns Julian Centuries since 1900.
+    /// Returns Julian Centuries since J2000.
     ///
     /// Used heavily in astronomical estimation of things that change slowly
     pub const fn centuries(self) -> f64 {
-        (self.julian() - 2451545.0) / 36525.0
+        let (day, f) = self.jd_parts();
+        ((day - 2451545.0) + f) / 36525.0
+    }
+
+    /// Returns the Besselian epoch, such as 1950.0, which older star catalogs use
+    ///
+    /// Besselian years are tropical years, starting when the mean sun is at 280° of longitude.
+    pub const fn besselian(self) -> f64 {
+        let (day, f) = self.jd_parts();
+        1900.0 + ((day - 2415020.0) + (f - 0.31352)) / 365.242198781
+    }
+    /// Constructs a date from a Besselian epoch
+    /// ```
+    /// # use pracstro::time::Date;
+    /// Date::from_besselian(1950.0).julian(); // 2433282.4235
+    /// ```
+    pub const fn from_besselian(b: f64) -> Self {
+        Date::from_jd_parts(2415020.0, 0.31352 + (b - 1900.0) * 365.242198781)
+    }
+
+    /// Returns the Julian epoch, such as 2000.0 or 2016.0, which modern star catalogs use
+    ///
+    /// Julian years are exactly 365.25 days, from J2000.
+    pub const fn julian_epoch(self) -> f64 {
+        let (day, f) = self.jd_parts();
+        2000.0 + ((day - 2451545.0) + f) / 365.25
+    }
+    /// Constructs a date from a Julian epoch
+    /// ```
+    /// # use pracstro::time::Date;
+    /// Date::from_julian_epoch(2016.0).julian(); // 2457389.0
+    /// ```
+    pub const fn from_julian_epoch(j: f64) -> Self {
+        Date::from_jd_parts(2451545.0, (j - 2000.0) * 365.25)
     }
 
     /// Returns Year, Month, Day (time is Angle::from_decimal(day.fract()))
     ///
+    /// Dates from 1582-10-15 onwards are in the Gregorian calendar, and dates before in the Julian calendar.
+    /// See [`Date::calendar_in()`] for other calendar systems.
+    ///
     /// Algorithm from Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
     pub fn calendar(self) -> (i64, u8, u8, Angle) {
-        let j = self.julian() + 0.5;
-        let (i, f) = (j.trunc(), j.fract());
+        self.calendar_in(CalendarSystem::ROME)
+    }
+    /// Returns Year, Month, Day (time is Angle::from_decimal(day.fract())) in a certain calendar system
+    ///
+    /// Algorithm from Practical Astronomy with Your Calculator, Although similar algorithms exist in other sources
+    pub fn calendar_in(self, cal: CalendarSystem) -> (i64, u8, u8, Angle) {
+        let (i, f) = {
+            let (day, f) = self.jd_parts();
+            match f + 0.5 >= 1.0 {
+                true => (day + 1.0, f - 0.5),
+                false => (day, f + 0.5),
+            }
+        };
 
-        let b = if i > 2_299_160.0 {
+        let gregorian = match cal {
+            CalendarSystem::Gregorian => true,
+            CalendarSystem::Julian => false,
+            CalendarSystem::Switch(first) => i >= (first.julian() + 0.5).trunc(),
+        };
+        let b = if gregorian {
             let a = ((i - 1867216.25) / 36524.25).trunc();
             i + 1.0 + a - (a / 4.0).trunc()
         } else {
@@ -364,29 +914,170 @@ impl Date {
 
         let m = if g < 13.5 { g - 1.0 } else { g - 13.0 };
         let y = if m > 2.5 { d - 4716.0 } else { d - 4715.0 };
-        let d = b - e + f - (30.6001 * g).trunc();
+        let d = b - e - (30.6001 * g).trunc();
 
-        (y as i64, m as u8, d as u8, Angle::from_turns(d.fract()))
+        (y as i64, m as u8, d as u8, Angle::from_turns(f))
     }
     /// Takes Year, Month, and Day
     ///
+    /// Dates from 1582-10-15 onwards are in the Gregorian calendar, and dates before in the Julian calendar.
+    /// See [`Date::from_calendar_in()`] for other calendar systems.
+    ///
+    /// Algorithm from Practical Astronomy with Your Calculator, although similar algorithms exist in other sources
+    pub const fn from_calendar(y: i64, m: u8, day: u8, t: Angle) -> Self {
+        Date::from_calendar_in(y, m, day, t, CalendarSystem::ROME)
+    }
+    /// Takes Year, Month, and Day in a certain calendar system
+    /// ```
+    /// # use pracstro::time::{Angle, CalendarSystem, Date};
+    /// // Newton was born on Christmas Day 1642 in England, which was in early January elsewhere in Europe
+    /// let newton = Date::from_calendar_in(1642, 12, 25, Angle::default(), CalendarSystem::BRITAIN);
+    /// assert_eq!(newton.calendar().0, 1643);
+    /// ```
+    ///
     /// Algorithm from Practical Astronomy with Your Calculator, although similar algorithms exist in other sources
-    pub fn from_calendar(y: i64, m: u8, day: u8, t: Angle) -> Self {
+    pub const fn from_calendar_in(y: i64, m: u8, day: u8, t: Angle, cal: CalendarSystem) -> Self {
         let (year, month) = if m < 3 { (y - 1, m + 12) } else { (y, m) };
 
-        Date::from_julian(
-            if year >= 1582 {
-                2 - (year / 100) + (year / 400)
+        let julian = (365.25 * year as f64 - if year < 0 { 0.75 } else { 0.0 }).trunc()
+            + (30.6001 * (month + 1) as f64).trunc()
+            + day as f64
+            + 1_720_994.5;
+        let correction = (2 - (year / 100) + (year / 400)) as f64;
+        let gregorian = match cal {
+            CalendarSystem::Gregorian => true,
+            CalendarSystem::Julian => false,
+            CalendarSystem::Switch(first) => julian + correction >= first.julian(),
+        };
+
+        Date::from_jd_parts(julian + if gregorian { correction } else { 0.0 }, t.turns())
+    }
+    /// Takes Year, Month, and Day, checking that they form a real calendar date
+    ///
+    /// Unlike [`Date::from_calendar()`], which happily rolls month 14 over into the next year,
+    /// this returns an error for months and days outside of their range, or a non-finite time.
+    /// The days skipped by the switch to the Gregorian calendar, 1582-10-05 to 1582-10-14, are errors too.
+    /// ```
+    /// # use pracstro::time::{Angle, Date, InvalidDate};
+    /// assert!(Date::try_from_calendar(2024, 2, 29, Angle::default()).is_ok());
+    /// assert_eq!(Date::try_from_calendar(2024, 14, 20, Angle::default()), Err(InvalidDate));
+    /// assert_eq!(Date::try_from_calendar(1582, 10, 10, Angle::default()), Err(InvalidDate));
+    /// ```
+    pub fn try_from_calendar(y: i64, m: u8, day: u8, t: Angle) -> Result<Self, InvalidDate> {
+        Date::try_from_calendar_in(y, m, day, t, CalendarSystem::ROME)
+    }
+    /// Takes Year, Month, and Day in a certain calendar system, checking that they form a real calendar date
+    ///
+    /// As [`Date::try_from_calendar()`], with leap years and the skipped days of a switch following `cal`.
+    /// ```
+    /// # use pracstro::time::{Angle, CalendarSystem, Date, InvalidDate};
+    /// // 1700 was a leap year in Britain, and 1752-09-03 never happened there
+    /// assert!(Date::try_from_calendar_in(1700, 2, 29, Angle::default(), CalendarSystem::BRITAIN).is_ok());
+    /// assert_eq!(
+    ///     Date::try_from_calendar_in(1752, 9, 3, Angle::default(), CalendarSystem::BRITAIN),
+    ///     Err(InvalidDate)
+    /// );
+    /// ```
+    pub fn try_from_calendar_in(
+        y: i64,
+        m: u8,
+        day: u8,
+        t: Angle,
+        cal: CalendarSystem,
+    ) -> Result<Self, InvalidDate> {
+        if !(1..=12).contains(&m)
+            || day < 1
+            || day > days_in_month(y, m, cal)
+            || !t.radians().is_finite()
+        {
+            return Err(InvalidDate);
+        }
+        // A skipped day comes out as another one
+        let (y2, m2, day2, _) =
+            Date::from_calendar_in(y, m, day, Angle::default(), cal).calendar_in(cal);
+        if (y2, m2, day2) != (y, m, day) {
+            return Err(InvalidDate);
+        }
+        Ok(Date::from_calendar_in(y, m, day, t, cal))
+    }
+    /// Returns Year, Month, Day, and time of day in a local timezone
+    ///
+    /// The UTC offset is in hours, e.g. `-5.0` for UTC-5 or `13.0` for UTC+13.
+    /// ```
+    /// # use pracstro::time::{Angle, Date};
+    /// Date::from_calendar(2025, 1, 1, Angle::from_clock(2, 0, 0.0)).calendar_tz(-5.0); // 2024-12-31 21:00
+    /// ```
+    pub fn calendar_tz(self, utc_offset: f64) -> (i64, u8, u8, Angle) {
+        let (day, f) = self.jd_parts();
+        Date::from_jd_parts(day, f + utc_offset / 24.0).calendar()
+    }
+    /// Takes Year, Month, Day, and time of day in a local timezone, with the UTC offset in hours
+    ///
+    /// The inverse of [`Date::calendar_tz()`], day rollover is handled by the Julian day arithmetic.
+    pub fn from_calendar_tz(y: i64, m: u8, day: u8, t: Angle, utc_offset: f64) -> Self {
+        let (jd, f) = Date::from_calendar(y, m, day, t).jd_parts();
+        Date::from_jd_parts(jd, f - utc_offset / 24.0)
+    }
+    /// Day of the week, from 0 for Sunday to 6 for Saturday
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Chapter 7
+    pub fn weekday(self) -> u8 {
+        let (day, f) = self.jd_parts();
+        (day + (f + 1.5).floor()).rem_euclid(7.0) as u8
+    }
+    /// Day of the year, from 1 for January 1st
+    ///
+    /// Leap years follow the same Julian/Gregorian split as [`Date::calendar()`]
+    ///
+    /// From Astronomical Algorithms by Jean Meeus, Chapter 7
+    pub fn day_of_year(self) -> u16 {
+        let (y, m, d, _) = self.calendar();
+        let (m, k) = (
+            m as u16,
+            if is_leap_year(y, CalendarSystem::ROME) {
+                1
             } else {
-                0
-            } as f64
-                + (365.25 * year as f64 - if year < 0 { 0.75 } else { 0.0 }).trunc()
-                + (30.6001 * (month + 1) as f64).trunc()
-                + day as f64
-                + t.turns()
-                + 1_720_994.5,
+                2
+            },
+        );
+        (275 * m / 9) - k * ((m + 9) / 12) + d as u16 - 30
+    }
+    /// ISO 8601 week-numbering year and week number
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one containing its first Thursday.
+    /// ```
+    /// # use pracstro::time::{Angle, Date};
+    /// Date::from_calendar(2024, 12, 30, Angle::default()).iso_week(); // (2025, 1)
+    /// ```
+    pub fn iso_week(self) -> (i64, u8) {
+        let iso_weekday = (self.weekday() + 6) % 7 + 1;
+        let thursday = Date::from_julian(self.julian() + 4.0 - iso_weekday as f64);
+        (
+            thursday.calendar().0,
+            ((thursday.day_of_year() - 1) / 7 + 1) as u8,
         )
     }
+    /// The year with the fraction of it that has passed, such as 2025.29
+    ///
+    /// The fraction is of the length of the calendar year, so it accounts for leap years.
+    pub fn decimal_year(self) -> f64 {
+        let y = self.calendar().0;
+        let start = Date::from_calendar(y, 1, 1, Angle::default());
+        let end = Date::from_calendar(y + 1, 1, 1, Angle::default());
+        y as f64 + (self.julian() - start.julian()) / (end.julian() - start.julian())
+    }
+    /// Constructs a date from a year with a fraction, such as 2025.29
+    /// ```
+    /// # use pracstro::time::Date;
+    /// Date::from_decimal_year(2025.5).calendar(); // (2025, 7, 2, 12h00m00s)
+    /// ```
+    pub fn from_decimal_year(x: f64) -> Self {
+        let y = x.floor() as i64;
+        let start = Date::from_calendar(y, 1, 1, Angle::default());
+        let end = Date::from_calendar(y + 1, 1, 1, Angle::default());
+        let (day, f) = start.jd_parts();
+        Date::from_jd_parts(day, f + (x - y as f64) * (end.julian() - start.julian()))
+    }
     /// Gets the time of day in a current calendar date
     pub fn time(self) -> Angle {
         self.calendar().3
@@ -397,44 +1088,725 @@ impl Date {
         Self::from_calendar(y, m, d, t)
     }
 
+    /// Greenwich mean sidereal time
+    ///
+    /// This is mean sidereal time, it does not account for nutation, which can make the apparent sidereal time differ by around a second.
+    ///
+    /// Unlike calling [`Angle::gst()`] with this date directly, the slowly changing term is taken at 0h UT, as the algorithm expects.
+    /// ```
+    /// # use pracstro::time::{Angle, Date};
+    /// Date::from_julian(2_444_351.5 + 14.614_333 / 24.0).gst().clock(); // (4, 40, 5.23)
+    /// ```
+    pub fn gst(self) -> Angle {
+        self.time().gst(Date::from_time(self, Angle::default()))
+    }
+    /// Local mean sidereal time at a longitude, positive to the east
+    ///
+    /// This is the right ascension currently on the observers meridian, which makes hour angles a single subtraction.
+    pub fn lst(self, longi: Angle) -> Angle {
+        self.gst() + longi
+    }
+
+    /// Earth Rotation Angle (IAU 2000), the date is taken as UT1
+    ///
+    /// The angle the earth has rotated about its axis, which is linear in time. The whole days are split off
+    /// first so that the angle keeps its precision far from J2000.
+    /// ```
+    /// # use pracstro::time::{Date, J2000};
+    /// J2000.era().degrees(); // 280.46061837504
+    /// ```
+    pub fn era(self) -> Angle {
+        let (day, f) = self.jd_parts();
+        let du = (day - 2451545.0) + f;
+        Angle::from_turns(f + 0.7790572732640 + 0.00273781191135448 * du)
+    }
+    /// Greenwich mean sidereal time from the Earth Rotation Angle (IAU 2006)
+    ///
+    /// A more accurate alternative to [`Date::gst()`], good to a few milliseconds over centuries.
+    /// The difference between TT and UT1 is ignored in the precession term, which is well below that.
+    pub fn gmst(self) -> Angle {
+        let t = self.centuries();
+        let arcsec = 0.014506
+            + t * (4612.156534
+                + t * (1.3915817 + t * (-0.00000044 + t * (-0.000029956 + t * -0.0000000368))));
+        self.era() + Angle::from_degrees(arcsec / 3600.0)
+    }
+
     /// Interface for unix time, Does not correct for the 1582 Julain/Gregorian split
     pub const fn unix(self) -> f64 {
-        (self.julian() - 2440587.5) * 86400.0
+        let (day, f) = self.jd_parts();
+        ((day - 2440587.5) + f) * 86400.0
     }
     /// Interface for unix time, Does not correct for the 1582 Julain/Gregorian split
     pub const fn from_unix(t: f64) -> Self {
-        Date::from_julian((t / 86400.0) + 2440587.5)
+        let days = (t / 86400.0).floor();
+        Date::from_jd_parts(days + 2440587.5, (t - days * 86400.0) / 86400.0)
     }
 
     /// Gets the current date
     ///
-    /// Since this function relies on SystemTime and duration_since, it does not work for dates before 1970.
+    /// Since this function relies on SystemTime and duration_since, it panics for dates before 1970.
+    /// See [`Date::try_now()`] for a version that doesn't panic, or [`Clock`] for other sources of time.
     pub fn now() -> Self {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Expected pre-1970-01-01 date")
-            .as_secs() as f64;
-        Date::from_unix(now)
+        Date::try_now().expect("Expected pre-1970-01-01 date")
+    }
+    /// Gets the current date from the system clock, failing if the clock is set before 1970
+    pub fn try_now() -> Result<Self, ClockError> {
+        SystemClock.now()
     }
-}
 
-/// Calculate the date of Easter
-pub fn easter(year: i32) -> (i32, i32) {
-    let a = year % 19;
+    /// The difference between TAI and UTC at this date, in seconds
+    ///
+    /// Uses the built in [`LEAP_SECONDS`] table, see [`Date::tai_utc_with()`] to supply another.
+    pub fn tai_utc(self) -> f64 {
+        self.tai_utc_with(&LEAP_SECONDS)
+    }
+    /// The difference between TAI and UTC at this date, in seconds, from a table of leap seconds
+    ///
+    /// The table is a list of (Modified Julian Date, TAI−UTC) pairs in ascending order,
+    /// in the same form as [`LEAP_SECONDS`]. Dates before the first entry have no offset.
+    pub fn tai_utc_with(self, table: &[(f64, f64)]) -> f64 {
+        let mjd = self.julian() - 2_400_000.5;
+        table
+            .iter()
+            .take_while(|(start, _)| *start <= mjd)
+            .last()
+            .map_or(0.0, |(_, offset)| *offset)
+    }
+    /// Converts this date, taken as UTC, to International Atomic Time
+    ///
+    /// Since [`Date`] doesn't record its time scale, the result is another [`Date`] on the TAI scale.
+    /// ```
+    /// # use pracstro::time::{Angle, Date};
+    /// Date::from_calendar(2025, 1, 1, Angle::default()).to_tai().time().clock(); // (0, 0, 37.0)
+    /// ```
+    pub fn to_tai(self) -> Self {
+        self.to_tai_with(&LEAP_SECONDS)
+    }
+    /// Converts a date on the TAI scale back to UTC
+    pub fn from_tai(tai: Self) -> Self {
+        Date::from_tai_with(tai, &LEAP_SECONDS)
+    }
+    /// [`Date::to_tai()`] with a table of leap seconds, see [`Date::tai_utc_with()`]
+    pub fn to_tai_with(self, table: &[(f64, f64)]) -> Self {
+        let (day, f) = self.jd_parts();
+        Date::from_jd_parts(day, f + self.tai_utc_with(table) / 86400.0)
+    }
+    /// [`Date::from_tai()`] with a table of leap seconds, see [`Date::tai_utc_with()`]
+    pub fn from_tai_with(tai: Self, table: &[(f64, f64)]) -> Self {
+        let (day, f) = tai.jd_parts();
+        let approx = Date::from_jd_parts(day, f - tai.tai_utc_with(table) / 86400.0);
+        Date::from_jd_parts(day, f - approx.tai_utc_with(table) / 86400.0)
+    }
+
+    /// Returns Year, Month, Day in the tabular Islamic calendar
+    ///
+    /// This is the arithmetic calendar with 11 leap years in a 30 year cycle, observed calendars based on sighting
+    /// the crescent moon can differ by a day or two. The Islamic day begins at sunset, but this uses the civil day.
+    ///
+    /// From Calendrical Calculations by Dershowitz and Reingold
+    pub fn islamic(self) -> (i64, u8, u8) {
+        let jd = self.civil_day();
+        let y = (30 * (jd - ISLAMIC_EPOCH) + 10646).div_euclid(10631);
+        let m = ((jd - 29 - islamic_day(y, 1, 1)) as f64 / 29.5).ceil() as i64 + 1;
+        let m = m.clamp(1, 12);
+        (y, m as u8, (jd - islamic_day(y, m, 1) + 1) as u8)
+    }
+    /// Takes Year, Month, Day in the tabular Islamic calendar, returning 0h UT of that civil day
+    pub fn from_islamic(y: i64, m: u8, d: u8) -> Self {
+        Date::from_jd_parts(islamic_day(y, m as i64, d as i64) as f64, -0.5)
+    }
+
+    /// Returns Year, Month, Day in the Hebrew calendar
+    ///
+    /// Months are numbered from Nisan (1) so that Tishri, the first month of the civil year, is 7.
+    /// In leap years Adar I is 12 and Adar II is 13. The Hebrew day begins at sunset, but this uses the civil day.
+    ///
+    /// From Calendrical Calculations by Dershowitz and Reingold
+    pub fn hebrew(self) -> (i64, u8, u8) {
+        let jd = self.civil_day();
+        let mut y = (jd - HEBREW_EPOCH) * 98496 / 35975351 - 1;
+        while jd >= hebrew_day(y + 1, 7, 1) {
+            y += 1;
+        }
+        let mut m = if jd < hebrew_day(y, 1, 1) { 7 } else { 1 };
+        while jd > hebrew_day(y, m, hebrew_month_days(y, m)) {
+            m += 1;
+        }
+        (y, m as u8, (jd - hebrew_day(y, m, 1) + 1) as u8)
+    }
+    /// Takes Year, Month, Day in the Hebrew calendar, returning 0h UT of that civil day
+    pub fn from_hebrew(y: i64, m: u8, d: u8) -> Self {
+        Date::from_jd_parts(hebrew_day(y, m as i64, d as i64) as f64, -0.5)
+    }
+
+    /// Dates from `start` up to (but not including) `end`, every `step`
+    ///
+    /// Each date is computed from `start` directly, so the error doesn't build up over long ranges.
+    /// ```
+    /// # use pracstro::{sol, time::{Angle, Date}};
+    /// # use std::time::Duration;
+    /// let start = Date::from_calendar(2025, 4, 16, Angle::default());
+    /// let end = Date::from_calendar(2025, 4, 18, Angle::default());
+    /// for d in Date::range(start, end, Duration::from_secs(6 * 3600)) {
+    ///     println!("{:?} {}", d.calendar(), sol::MARS.location(d));
+    /// }
+    /// ```
+    pub fn range(start: Date, end: Date, step: std::time::Duration) -> DateRange {
+        DateRange {
+            start,
+            end,
+            step: step.as_secs_f64() / 86400.0,
+            i: 0,
+        }
+    }
+
+    /// The Julian day number of the civil day, which starts at noon the day before
+    fn civil_day(self) -> i64 {
+        let (day, f) = self.jd_parts();
+        (day + (f + 0.5).floor()) as i64
+    }
+}
+
+/// Iterator over dates at a fixed step, from [`Date::range()`]
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+    step: f64,
+    i: u64,
+}
+impl Iterator for DateRange {
+    type Item = Date;
+    fn next(&mut self) -> Option<Date> {
+        let (day, f) = self.start.jd_parts();
+        let d = Date::from_jd_parts(day, f + self.i as f64 * self.step);
+        if self.step <= 0.0 || d >= self.end {
+            return None;
+        }
+        self.i += 1;
+        Some(d)
+    }
+}
+
+/// A source of the current time
+///
+/// [`SystemClock`] is what [`Date::now()`] uses. A [`Date`] is also a clock stuck at that instant, which is useful in tests.
+/// Targets without a system clock can implement this over whatever time source they have.
+/// ```
+/// # use pracstro::time::{Clock, Date, J2000};
+/// fn days_since_j2000(clock: &impl Clock) -> f64 {
+///     clock.now().unwrap().julian() - J2000.julian()
+/// }
+/// assert_eq!(days_since_j2000(&Date::from_julian(2451546.0)), 1.0);
+/// ```
+pub trait Clock {
+    /// The current date according to this clock
+    fn now(&self) -> Result<Date, ClockError>;
+}
+/// The system clock, through [`std::time::SystemTime`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+impl Clock for SystemClock {
+    fn now(&self) -> Result<Date, ClockError> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ClockError)?;
+        Ok(Date::from_unix(now.as_secs_f64()))
+    }
+}
+impl Clock for Date {
+    fn now(&self) -> Result<Date, ClockError> {
+        Ok(*self)
+    }
+}
+/// Error returned when a [`Clock`] can't tell the time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockError;
+impl fmt::Display for ClockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "clock is unavailable or set before 1970")
+    }
+}
+impl std::error::Error for ClockError {}
+
+/// The calendar that a calendar date is in
+///
+/// Countries switched from the Julian to the Gregorian calendar at different times, a calendar date in history
+/// depends on where it was written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalendarSystem {
+    /// The Gregorian calendar, extended back before its introduction
+    Gregorian,
+    /// The Julian calendar, extended forward after the introduction of the Gregorian calendar
+    Julian,
+    /// The Julian calendar, switching to the Gregorian calendar on this date
+    Switch(Date),
+}
+impl CalendarSystem {
+    /// The original switch in Catholic Europe, where 1582-10-04 was followed by 1582-10-15
+    pub const ROME: CalendarSystem = CalendarSystem::Switch(Date::from_julian(2_299_160.5));
+    /// The switch in Great Britain and its colonies, where 1752-09-02 was followed by 1752-09-14
+    pub const BRITAIN: CalendarSystem = CalendarSystem::Switch(Date::from_julian(2_361_221.5));
+}
+
+/// Leap seconds, as (Modified Julian Date of 0h UTC, TAI−UTC in seconds) from when each offset came into effect
+///
+/// From the IERS Bulletin C, current as of the leap second at the start of 2017.
+pub const LEAP_SECONDS: [(f64, f64); 28] = [
+    (41317.0, 10.0), // 1972-01-01
+    (41499.0, 11.0), // 1972-07-01
+    (41683.0, 12.0), // 1973-01-01
+    (42048.0, 13.0), // 1974-01-01
+    (42413.0, 14.0), // 1975-01-01
+    (42778.0, 15.0), // 1976-01-01
+    (43144.0, 16.0), // 1977-01-01
+    (43509.0, 17.0), // 1978-01-01
+    (43874.0, 18.0), // 1979-01-01
+    (44239.0, 19.0), // 1980-01-01
+    (44786.0, 20.0), // 1981-07-01
+    (45151.0, 21.0), // 1982-07-01
+    (45516.0, 22.0), // 1983-07-01
+    (46247.0, 23.0), // 1985-07-01
+    (47161.0, 24.0), // 1988-01-01
+    (47892.0, 25.0), // 1990-01-01
+    (48257.0, 26.0), // 1991-01-01
+    (48804.0, 27.0), // 1992-07-01
+    (49169.0, 28.0), // 1993-07-01
+    (49534.0, 29.0), // 1994-07-01
+    (50083.0, 30.0), // 1996-01-01
+    (50630.0, 31.0), // 1997-07-01
+    (51179.0, 32.0), // 1999-01-01
+    (53736.0, 33.0), // 2006-01-01
+    (54832.0, 34.0), // 2009-01-01
+    (56109.0, 35.0), // 2012-07-01
+    (57204.0, 36.0), // 2015-07-01
+    (57754.0, 37.0), // 2017-01-01
+];
+
+/// Error returned when a calendar date doesn't exist
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidDate;
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid calendar date")
+    }
+}
+impl std::error::Error for InvalidDate {}
+
+/// Error returned when a date can't be converted to the time type of another crate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateOutOfRange;
+impl fmt::Display for DateOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "date out of the range of the time type")
+    }
+}
+impl std::error::Error for DateOutOfRange {}
+
+/// Leap years follow the rule of the calendar that February of the year is in
+fn is_leap_year(y: i64, cal: CalendarSystem) -> bool {
+    let gregorian = match cal {
+        CalendarSystem::Gregorian => true,
+        CalendarSystem::Julian => false,
+        CalendarSystem::Switch(first) => {
+            Date::from_calendar_in(y, 3, 1, Angle::default(), CalendarSystem::Gregorian).julian()
+                > first.julian()
+        }
+    };
+    match gregorian {
+        true => (y % 4 == 0 && y % 100 != 0) || y % 400 == 0,
+        false => y % 4 == 0,
+    }
+}
+fn days_in_month(y: i64, m: u8, cal: CalendarSystem) -> u8 {
+    match m {
+        2 if is_leap_year(y, cal) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Julian day number of 1 Muharram AH 1
+const ISLAMIC_EPOCH: i64 = 1948440;
+/// Julian day number of a tabular Islamic date
+fn islamic_day(y: i64, m: i64, d: i64) -> i64 {
+    d + (29.5 * (m - 1) as f64).ceil() as i64
+        + (y - 1) * 354
+        + (3 + 11 * y).div_euclid(30)
+        + ISLAMIC_EPOCH
+        - 1
+}
+
+/// Julian day number of the day before 1 Tishri AM 1
+const HEBREW_EPOCH: i64 = 347997;
+fn hebrew_leap(y: i64) -> bool {
+    (7 * y + 1).rem_euclid(19) < 7
+}
+/// Days from the epoch to the new year, delayed so that it doesn't fall on a Sunday, Wednesday, or Friday
+fn hebrew_elapsed(y: i64) -> i64 {
+    let months = (235 * y - 234).div_euclid(19);
+    let parts = 12084 + 13753 * months;
+    let day = months * 29 + parts.div_euclid(25920);
+    match (3 * (day + 1)).rem_euclid(7) < 3 {
+        true => day + 1,
+        false => day,
+    }
+}
+/// Further delays to the new year to keep year lengths within range
+fn hebrew_delay(y: i64) -> i64 {
+    let (last, present, next) = (
+        hebrew_elapsed(y - 1),
+        hebrew_elapsed(y),
+        hebrew_elapsed(y + 1),
+    );
+    if next - present == 356 {
+        2
+    } else if present - last == 382 {
+        1
+    } else {
+        0
+    }
+}
+fn hebrew_month_days(y: i64, m: i64) -> i64 {
+    let year_days = hebrew_day(y + 1, 7, 1) - hebrew_day(y, 7, 1);
+    match m {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !hebrew_leap(y) => 29,
+        8 if year_days % 10 != 5 => 29,
+        9 if year_days % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+/// Julian day number of a Hebrew date
+fn hebrew_day(y: i64, m: i64, d: i64) -> i64 {
+    let months = if hebrew_leap(y) { 13 } else { 12 };
+    let mut jd = HEBREW_EPOCH + hebrew_elapsed(y) + hebrew_delay(y) + d;
+    if m < 7 {
+        jd += (7..=months)
+            .map(|mon| hebrew_month_days(y, mon))
+            .sum::<i64>();
+        jd += (1..m).map(|mon| hebrew_month_days(y, mon)).sum::<i64>();
+    } else {
+        jd += (7..m).map(|mon| hebrew_month_days(y, mon)).sum::<i64>();
+    }
+    jd
+}
+
+/// Calculate the date of Easter
+///
+/// Returns (Month, Day) in the Gregorian calendar
+///
+/// From Astronomical Algorithms by Jean Meeus, Chapter 8
+pub fn easter(year: i32) -> (i32, i32) {
+    let a = year % 19;
     let (b, c) = (year / 100, year % 100);
-    let d = b / 4;
+    let (d, e) = (b / 4, b % 4);
     let g = (b - (b + 8) / 25 + 1) / 3;
     let h = ((19 * a) + b - d - g + 15) % 30;
-    let l = (32 + (2 * b % 4) + (2 * c / 4) - h - c % 4) % 7;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + (2 * e) + (2 * i) - h - k) % 7;
     let m = (a + (11 * h) + (22 * l)) / 451;
     let n = (h + l - (7 * m) + 114) / 31;
     let p = (h + l - (7 * m) + 114) % 31;
     (n, p + 1)
 }
+/// Calculate the date of Easter by the Julian computus, used by the Orthodox churches
+///
+/// Returns (Month, Day) in the Julian calendar, see [`easter_date()`] for the date itself.
+///
+/// From Astronomical Algorithms by Jean Meeus, Chapter 8
+pub fn easter_julian(year: i32) -> (i32, i32) {
+    let (a, b, c) = (year % 4, year % 7, year % 19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let f = d + e + 114;
+    (f / 31, f % 31 + 1)
+}
+/// Easter Sunday by either the Gregorian or the Julian (Orthodox) computus, at 0h UT
+/// ```
+/// # use pracstro::time::{easter_date, Angle, Date};
+/// assert_eq!(easter_date(2024, true), Date::from_calendar(2024, 5, 5, Angle::default()));
+/// ```
+pub fn easter_date(year: i32, orthodox: bool) -> Date {
+    match orthodox {
+        true => {
+            // Meeus 7.1 without the Gregorian correction, for a date in the Julian calendar
+            let (m, d) = easter_julian(year);
+            let (y, m) = if m < 3 { (year - 1, m + 12) } else { (year, m) };
+            Date::from_julian(
+                (365.25 * (y + 4716) as f64).floor()
+                    + (30.6001 * (m + 1) as f64).floor()
+                    + d as f64
+                    - 1524.5,
+            )
+        }
+        false => {
+            let (m, d) = easter(year);
+            Date::from_calendar(year as i64, m as u8, d as u8, Angle::default())
+        }
+    }
+}
+/// Ash Wednesday, 46 days before Easter, the start of Lent
+///
+/// The Orthodox churches don't keep Ash Wednesday, their Lent starts on Clean Monday,
+/// 48 days before Easter, which is what `orthodox` gives.
+/// ```
+/// # use pracstro::time::{ash_wednesday, Angle, Date};
+/// assert_eq!(ash_wednesday(2025, true), Date::from_calendar(2025, 3, 3, Angle::default()));
+/// ```
+pub fn ash_wednesday(year: i32, orthodox: bool) -> Date {
+    let days = if orthodox { 48.0 } else { 46.0 };
+    Date::from_julian(easter_date(year, orthodox).julian() - days)
+}
+/// Pentecost, 49 days after Easter
+pub fn pentecost(year: i32, orthodox: bool) -> Date {
+    Date::from_julian(easter_date(year, orthodox).julian() + 49.0)
+}
+/// The first Sunday of Advent, the fourth Sunday before Christmas
+pub fn advent(year: i32) -> Date {
+    let christmas = Date::from_calendar(year as i64, 12, 25, Angle::default());
+    let back = match christmas.weekday() {
+        0 => 7,
+        wd => wd,
+    };
+    Date::from_julian(christmas.julian() - back as f64 - 21.0)
+}
+
+/// Dates within the same millisecond are equal
+impl PartialEq for Date {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+impl Eq for Date {}
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let ((d1, m1), (d2, m2)) = (self.millis(), other.millis());
+        d1.total_cmp(&d2).then(m1.cmp(&m2))
+    }
+}
+impl std::hash::Hash for Date {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let (day, ms) = self.millis();
+        (day.to_bits(), ms).hash(state);
+    }
+}
 
 /// Time at epoch J2000
 pub const J2000: Date = Date::from_julian(2451545.0);
+/// Time at epoch B1950, the Besselian epoch of the FK4 catalog
+pub const B1950: Date = Date::from_besselian(1950.0);
+
+/// Conversion from a [`chrono`] UTC timestamp, keeping sub-second precision
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Date {
+    fn from(t: chrono::DateTime<chrono::Utc>) -> Self {
+        Date::from_unix(t.timestamp() as f64 + t.timestamp_subsec_nanos() as f64 / 1e9)
+    }
+}
+/// Conversion to a [`chrono`] UTC timestamp, rounded to the nearest microsecond
+///
+/// Dates outside of the range chrono can represent are a [`DateOutOfRange`].
+#[cfg(feature = "chrono")]
+impl TryFrom<Date> for chrono::DateTime<chrono::Utc> {
+    type Error = DateOutOfRange;
+    fn try_from(d: Date) -> Result<Self, DateOutOfRange> {
+        // The cast saturates, which is still out of the range of chrono
+        let micros = (d.unix() * 1e6).round();
+        match micros.is_finite() {
+            true => chrono::DateTime::from_timestamp_micros(micros as i64).ok_or(DateOutOfRange),
+            false => Err(DateOutOfRange),
+        }
+    }
+}
+/// Conversion from a [`chrono`] calendar date, at midnight UTC
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(d: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        Date::from_calendar(
+            d.year() as i64,
+            d.month() as u8,
+            d.day() as u8,
+            Angle::default(),
+        )
+    }
+}
+
+/// Conversion from a [`time`] timestamp, keeping sub-second precision
+#[cfg(feature = "time")]
+impl From<::time::OffsetDateTime> for Date {
+    fn from(t: ::time::OffsetDateTime) -> Self {
+        Date::from_unix(t.unix_timestamp_nanos() as f64 / 1e9)
+    }
+}
+/// Conversion to a UTC [`time`] timestamp, rounded to the nearest microsecond
+///
+/// Dates outside of the range the `time` crate can represent are a [`DateOutOfRange`].
+#[cfg(feature = "time")]
+impl TryFrom<Date> for ::time::OffsetDateTime {
+    type Error = DateOutOfRange;
+    fn try_from(d: Date) -> Result<Self, DateOutOfRange> {
+        // The cast saturates, which is still out of the range of the time crate
+        let micros = (d.unix() * 1e6).round();
+        let nanos = (micros as i128)
+            .checked_mul(1_000)
+            .filter(|_| micros.is_finite())
+            .ok_or(DateOutOfRange)?;
+        ::time::OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| DateOutOfRange)
+    }
+}
+
+/// Compares the shortest distance between two angles, with the epsilon in radians
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Angle {
+    type Epsilon = f64;
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.signed_diff(*other).radians().abs() <= epsilon
+    }
+}
+/// The relative tolerance is taken of the larger of the two angles in radians
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Angle {
+    fn default_max_relative() -> f64 {
+        f64::EPSILON
+    }
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        let largest = self.radians().max(other.radians());
+        approx::AbsDiffEq::abs_diff_eq(self, other, epsilon.max(largest * max_relative))
+    }
+}
+
+/// Serialized as radians
+#[cfg(feature = "serde")]
+impl serde::Serialize for Angle {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_f64(self.radians())
+    }
+}
+/// Deserialized from radians, reducing it to the range of \[0, 2*PI\]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Angle {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        f64::deserialize(d).map(Angle::from_radians)
+    }
+}
+/// Serialized as signed radians
+#[cfg(feature = "serde")]
+impl serde::Serialize for Latitude {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_f64(self.radians())
+    }
+}
+/// Deserialized from radians, reducing it to the range of \[-PI/2, PI/2\]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Latitude {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        f64::deserialize(d).map(Latitude::from_radians)
+    }
+}
+/// Serialized as radians per day
+#[cfg(feature = "serde")]
+impl serde::Serialize for AngularRate {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_f64(self.radians_per_day())
+    }
+}
+/// Deserialized from radians per day
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AngularRate {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        f64::deserialize(d).map(AngularRate::from_radians_per_day)
+    }
+}
+/// Serialized as the pair of the whole Julian day and the fraction of the day, see [`Date::jd_parts()`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.jd_parts(), s)
+    }
+}
+/// Deserialized from a pair of the whole Julian day and the fraction of the day, see [`Date::from_jd_parts()`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        <(f64, f64)>::deserialize(d).map(|(day, frac)| Date::from_jd_parts(day, frac))
+    }
+}
+
+/// Constructs an [`Angle`] in a constant expression
+///
+/// Takes degrees, arcminutes, and arcseconds separated by colons, hours, minutes, and seconds marked with `h`, `m`, and `s`,
+/// or plain decimal degrees. Since `°`, `'` and `"` aren't valid in Rust source outside of strings, those can't be used,
+/// see [`parse_angle()`](crate::time::parse_angle) for parsing them at runtime.
+/// ```
+/// use pracstro::{angle, time::Angle};
+///
+/// const INCLINATION: Angle = angle!(5:08:43);
+/// const ARCTURUS_RA: Angle = angle!(14 h 16 m 50.0 s);
+/// const SIRIUS_DE: Angle = angle!(-16:42:58.0);
+/// const OBLIQUITY: Angle = angle!(23.43928);
+/// assert_eq!(INCLINATION, Angle::from_degminsec(5, 8, 43.0));
+/// ```
+#[macro_export]
+macro_rules! angle {
+    ($h:literal h $m:literal m $s:literal s) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        const HOURS: f64 = $h as f64 + ($m as f64 + $s as f64 / 60.0) / 60.0;
+        $crate::time::Angle::from_decimal(HOURS)
+    }};
+    ($h:literal h $m:literal m) => {
+        $crate::angle!($h h $m m 0 s)
+    };
+    (- $d:literal : $m:literal : $s:literal) => {
+        $crate::time::Angle::from_radians(-$crate::angle!($d : $m : $s).radians())
+    };
+    ($d:literal : $m:literal : $s:literal) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        const DEGREES: f64 = $d as f64 + ($m as f64 + $s as f64 / 60.0) / 60.0;
+        $crate::time::Angle::from_degrees(DEGREES)
+    }};
+    (- $d:literal) => {
+        $crate::time::Angle::from_degrees(-($d as f64))
+    };
+    ($d:literal) => {
+        $crate::time::Angle::from_degrees($d as f64)
+    };
+}
+
+/// Constructs a [`Date`] in a constant expression
+///
+/// Takes a calendar date in UT as `YYYY-MM-DD`, optionally followed by a time of day as `HH:MM:SS`.
+/// ```
+/// use pracstro::{date, time::{Angle, Date}};
+///
+/// const EPOCH1980: Date = date!(1979-12-31);
+/// const EQUINOX: Date = date!(2025-03-20 09:01:00);
+/// assert_eq!(EPOCH1980, Date::from_julian(2444238.5));
+/// ```
+#[macro_export]
+macro_rules! date {
+    ($y:literal - $mo:literal - $d:literal) => {
+        $crate::date!($y - $mo - $d 0 : 0 : 0)
+    };
+    ($y:literal - $mo:literal - $d:literal $h:literal : $mi:literal : $s:literal) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATE: $crate::time::Date =
+            $crate::time::Date::from_calendar($y, $mo, $d, $crate::angle!($h h $mi m $s s));
+        DATE
+    }};
+}
 
 #[cfg(test)]
 mod tests {
@@ -451,10 +1823,10 @@ mod tests {
             (1985, 2, 17, Angle::from_decimal(6.0))
         );
         assert_eq!(
-            Date::from_calendar(1967, 04, 12, Angle::from_turns(0.6))
+            Date::from_calendar(1967, 4, 12, Angle::from_turns(0.6))
                 .time()
                 .decimal(),
-            14.400000002235174
+            14.400000000000002
         );
     }
 
@@ -471,8 +1843,260 @@ mod tests {
     }
 
     #[test]
-    fn test_decimalhrs() {
+    fn test_jd_parts() {
+        assert_eq!(
+            Date::from_julian(2_446_113.75).jd_parts(),
+            (2_446_113.0, 0.75)
+        );
+        assert_eq!(
+            Date::from_jd_parts(2_446_113.5, 0.75),
+            Date::from_jd_parts(2_446_114.0, 0.25)
+        );
+        // 1µs is well under the resolution of a single f64 Julian date
+        let d = Date::from_unix(1_744_832_471.000_001);
+        assert_eq!(d.unix(), 1_744_832_471.000_001);
+        assert_ne!(Date::from_julian(d.julian()).unix(), d.unix());
+    }
+
+    #[test]
+    fn test_weekday() {
+        // Astronomical Algorithms, Example 7.e and 7.f
+        assert_eq!(Date::from_julian(2_434_923.5).weekday(), 3);
+        // Julian day 0 began at noon on a Monday, and days before it keep counting back through the week
+        assert_eq!(Date::from_julian(0.0).weekday(), 1);
+        assert_eq!(Date::from_julian(-1.0).weekday(), 0);
+        assert_eq!(Date::from_julian(-2.0).weekday(), 6);
+        assert_eq!(
+            Date::from_julian(-7000.25).weekday(),
+            Date::from_julian(-0.25).weekday()
+        );
+        assert_eq!(
+            Date::from_calendar(1978, 11, 14, Angle::from_decimal(23.0)).day_of_year(),
+            318
+        );
+        assert_eq!(
+            Date::from_calendar(1988, 4, 22, Angle::default()).day_of_year(),
+            113
+        );
+        assert_eq!(
+            Date::from_calendar(2024, 12, 30, Angle::default()).iso_week(),
+            (2025, 1)
+        );
+        assert_eq!(
+            Date::from_calendar(2021, 1, 3, Angle::from_decimal(12.0)).iso_week(),
+            (2020, 53)
+        );
+        assert_eq!(
+            Date::from_calendar(2025, 6, 18, Angle::default()).iso_week(),
+            (2025, 25)
+        );
+    }
+
+    #[test]
+    fn test_lunar_calendars() {
+        let y2k = Date::from_calendar(2000, 1, 1, Angle::from_decimal(18.0));
+        assert_eq!(y2k.islamic(), (1420, 9, 24));
+        assert_eq!(y2k.hebrew(), (5760, 10, 23));
+        assert_eq!(
+            Date::from_islamic(1420, 9, 24),
+            Date::from_calendar(2000, 1, 1, Angle::default())
+        );
+        // Passover and Rosh Hashanah
+        assert_eq!(
+            Date::from_hebrew(5785, 1, 15),
+            Date::from_calendar(2025, 4, 13, Angle::default())
+        );
+        assert_eq!(
+            Date::from_calendar(2025, 9, 23, Angle::default()).hebrew(),
+            (5786, 7, 1)
+        );
+        assert_eq!(
+            Date::from_calendar(2024, 3, 20, Angle::default()).hebrew(),
+            (5784, 13, 10)
+        );
+    }
+
+    #[test]
+    fn test_calendar_tz() {
+        let est = -5.0;
+        let d = Date::from_calendar_tz(2024, 12, 31, Angle::from_clock(21, 0, 30.0), est);
+        let ms = Angle::from_clock(0, 0, 0.001);
+        let (y, m, day, t) = d.calendar();
+        assert_eq!((y, m, day), (2025, 1, 1));
+        assert_approx_eq!(t, Angle::from_clock(2, 0, 30.0), ms);
+        let (y, m, day, t) = d.calendar_tz(est);
+        assert_eq!((y, m, day), (2024, 12, 31));
+        assert_approx_eq!(t, Angle::from_clock(21, 0, 30.0), ms);
+        let (y, m, day, t) = d.calendar_tz(9.0);
+        assert_eq!((y, m, day), (2025, 1, 1));
+        assert_approx_eq!(t, Angle::from_clock(11, 0, 30.0), ms);
+
+        // Offsets past twelve hours, as in Samoa and Kiribati
+        let noon = Date::from_calendar(2025, 1, 1, Angle::from_clock(12, 0, 0.0));
+        let (y, m, day, t) = noon.calendar_tz(13.0);
+        assert_eq!((y, m, day), (2025, 1, 2));
+        assert_approx_eq!(t, Angle::from_clock(1, 0, 0.0), ms);
+        let (y, m, day, t) = noon.calendar_tz(14.0);
+        assert_eq!((y, m, day), (2025, 1, 2));
+        assert_approx_eq!(t, Angle::from_clock(2, 0, 0.0), ms);
+        let back = Date::from_calendar_tz(2025, 1, 2, Angle::from_clock(1, 0, 0.0), 13.0);
+        assert_eq!(back, noon);
+        let (_, _, day, t) = noon.calendar_tz(-12.0);
+        assert_eq!(day, 1);
+        assert_approx_eq!(t, Angle::default(), ms);
+    }
+
+    #[test]
+    fn test_calendar_system() {
+        // The day after 1582-10-04 was 1582-10-15
+        let switch = Date::from_calendar(1582, 10, 4, Angle::default());
         assert_eq!(
+            Date::from_julian(switch.julian() + 1.0).calendar(),
+            (1582, 10, 15, Angle::default())
+        );
+        assert_eq!(
+            Date::from_calendar(1582, 6, 1, Angle::default()).calendar(),
+            (1582, 6, 1, Angle::default())
+        );
+        assert_eq!(
+            switch.calendar_in(CalendarSystem::Gregorian),
+            (1582, 10, 14, Angle::default())
+        );
+        assert_eq!(
+            Date::from_calendar_in(2025, 4, 7, Angle::default(), CalendarSystem::Julian).calendar(),
+            (2025, 4, 20, Angle::default())
+        );
+        let london = Date::from_calendar_in(1752, 9, 2, Angle::default(), CalendarSystem::BRITAIN);
+        assert_eq!(
+            Date::from_julian(london.julian() + 1.0).calendar_in(CalendarSystem::BRITAIN),
+            (1752, 9, 14, Angle::default())
+        );
+    }
+
+    #[test]
+    fn test_try_calendar() {
+        assert_eq!(
+            Date::try_from_calendar(1985, 2, 17, Angle::from_decimal(6.0)),
+            Ok(Date::from_julian(2_446_113.75))
+        );
+        assert_eq!(
+            Date::try_from_calendar(2024, 14, 20, Angle::default()),
+            Err(InvalidDate)
+        );
+        assert_eq!(
+            Date::try_from_calendar(2023, 2, 29, Angle::default()),
+            Err(InvalidDate)
+        );
+        assert_eq!(
+            Date::try_from_calendar(1900, 2, 29, Angle::default()),
+            Err(InvalidDate)
+        );
+        assert!(Date::try_from_calendar(1500, 2, 29, Angle::default()).is_ok());
+        assert_eq!(
+            Date::try_from_calendar(2024, 4, 31, Angle::default()),
+            Err(InvalidDate)
+        );
+        assert_eq!(
+            Date::try_from_calendar(2024, 4, 0, Angle::default()),
+            Err(InvalidDate)
+        );
+        assert_eq!(
+            Date::try_from_calendar(2024, 4, 1, Angle::from_degrees(f64::NAN)),
+            Err(InvalidDate)
+        );
+
+        // The days skipped by the switch to the Gregorian calendar
+        for day in 5..=14 {
+            assert_eq!(
+                Date::try_from_calendar(1582, 10, day, Angle::default()),
+                Err(InvalidDate)
+            );
+        }
+        assert_eq!(
+            Date::try_from_calendar(1582, 10, 4, Angle::default()),
+            Ok(Date::from_julian(2_299_159.5))
+        );
+        assert_eq!(
+            Date::try_from_calendar(1582, 10, 15, Angle::default()),
+            Ok(Date::from_julian(2_299_160.5))
+        );
+
+        // Leap years and skipped days follow the calendar system
+        let try_in = |y, m, day, cal| Date::try_from_calendar_in(y, m, day, Angle::default(), cal);
+        assert!(try_in(1700, 2, 29, CalendarSystem::BRITAIN).is_ok());
+        assert_eq!(try_in(1700, 2, 29, CalendarSystem::ROME), Err(InvalidDate));
+        assert!(try_in(1900, 2, 29, CalendarSystem::Julian).is_ok());
+        assert_eq!(
+            try_in(1500, 2, 29, CalendarSystem::Gregorian),
+            Err(InvalidDate)
+        );
+        assert!(try_in(1582, 10, 10, CalendarSystem::Gregorian).is_ok());
+        assert!(try_in(1582, 10, 10, CalendarSystem::BRITAIN).is_ok());
+        assert_eq!(
+            try_in(1752, 9, 3, CalendarSystem::BRITAIN),
+            Err(InvalidDate)
+        );
+        assert_eq!(
+            try_in(1752, 9, 14, CalendarSystem::BRITAIN),
+            Ok(Date::from_julian(2_361_221.5))
+        );
+    }
+
+    #[test]
+    fn test_clock() {
+        let d = Date::from_julian(2_446_113.75);
+        assert_eq!(d.now(), Ok(d));
+        assert!(Date::try_now().unwrap().julian() > 2_460_000.0);
+    }
+
+    #[test]
+    fn test_tai() {
+        let d = Date::from_calendar(2016, 12, 31, Angle::from_clock(23, 59, 0.0));
+        assert_eq!(d.tai_utc(), 36.0);
+        assert_eq!(Date::from_julian(d.julian() + 1.0).tai_utc(), 37.0);
+        assert_eq!(
+            Date::from_calendar(1970, 1, 1, Angle::default()).tai_utc(),
+            0.0
+        );
+        let ny = Date::from_calendar(2025, 1, 1, Angle::default());
+        assert!((ny.to_tai().unix() - ny.unix() - 37.0).abs() < 1e-6);
+        assert_eq!(Date::from_tai(d.to_tai()), d);
+        assert_eq!(d.tai_utc_with(&[(0.0, 1.0)]), 1.0);
+    }
+
+    #[test]
+    fn test_macros() {
+        const A: Angle = crate::angle!(-16:45:06.8);
+        assert_eq!(A, Angle::from_degrees(-(16.0 + (45.0 + 6.8 / 60.0) / 60.0)));
+        assert_eq!(
+            crate::angle!(14 h 16 m 50 s),
+            Angle::from_clock(14, 16, 50.0)
+        );
+        assert_eq!(crate::angle!(6 h 45 m), Angle::from_clock(6, 45, 0.0));
+        assert_eq!(crate::angle!(-25), Angle::from_degrees(-25.0));
+        const D: Date = crate::date!(1985-02-17 06:00:00);
+        assert_eq!(D, Date::from_julian(2_446_113.75));
+        assert_eq!(crate::date!(2000-01-01 12:00:00), J2000);
+    }
+
+    #[test]
+    fn test_angular_rate() {
+        let mut l = Angle::from_degrees(100.0);
+        l += AngularRate::from_degrees_per_day(1.0) * std::time::Duration::from_secs(86400 * 10);
+        assert_eq!(l, Angle::from_degrees(110.0));
+        let pm = AngularRate::from_arcsec_per_year(-1.2);
+        assert_eq!(pm.over(-36525.0 / 3.0), Angle::from_degminsec(0, 0, 40.0));
+        assert!((pm.arcsec_per_year() + 1.2).abs() < 1e-12);
+        assert!(
+            (AngularRate::from_degrees_per_century(35999.37244981).degrees_per_day() - 0.98560)
+                .abs()
+                < 1e-5
+        );
+    }
+
+    #[test]
+    fn test_decimalhrs() {
+        assert_approx_eq!(
             Angle::from_clock(18, 31, 27.0),
             Angle::from_decimal(18.52417)
         );
@@ -481,16 +2105,140 @@ mod tests {
 
     #[test]
     fn test_gst() {
-        assert_eq!(
+        assert_approx_eq!(
             Angle::from_clock(14, 36, 51.6).gst(Date::from_julian(2_444_351.5)),
             Angle::from_clock(4, 40, 5.23)
         );
-        assert_eq!(
+        assert_approx_eq!(
             Angle::from_clock(4, 40, 5.23).ungst(Date::from_julian(2_444_351.5)),
             Angle::from_clock(14, 36, 51.6)
         );
     }
 
+    #[test]
+    fn test_sidereal() {
+        let d = Date::from_calendar(1980, 4, 22, Angle::from_clock(14, 36, 51.67));
+        assert_approx_eq!(d.gst(), Angle::from_clock(4, 40, 5.23));
+        assert_approx_eq!(
+            d.lst(Angle::from_degrees(-64.0)),
+            Angle::from_clock(0, 24, 5.23)
+        );
+        assert_approx_eq!(d.gmst(), Angle::from_clock(4, 40, 5.23));
+    }
+
+    #[test]
+    fn test_besselian() {
+        assert!((B1950.julian() - 2433282.4235).abs() < 1e-4);
+        assert!((Date::from_besselian(1900.0).julian() - 2415020.31352).abs() < 1e-6);
+        assert!((J2000.besselian() - 2000.0012775).abs() < 1e-6);
+        assert!((Date::from_besselian(1875.0).besselian() - 1875.0).abs() < 1e-9);
+        assert_eq!(Date::from_julian_epoch(2016.0).julian(), 2457389.0);
+        assert_eq!(J2000.julian_epoch(), 2000.0);
+    }
+
+    #[test]
+    fn test_latitude() {
+        let de = Latitude::from(Angle::from_degrees(-25.5));
+        assert!((de.degrees() + 25.5).abs() < 1e-12);
+        assert_eq!(Latitude::from_degrees(-25.5).degrees(), -25.5);
+        assert_eq!(format!("{:.0}", de), "-25°30'00\"");
+        assert_eq!(format!("{:.0}", Angle::from(de)), "334°30'00\"");
+        assert!(de < Latitude::from_degrees(10.0));
+        assert_eq!(Latitude::from_degrees(-90.0).degrees(), -90.0);
+        assert_eq!(Latitude::from_degrees(270.0).degrees(), -90.0);
+        assert_eq!(Latitude::from_degrees(90.0).degrees(), 90.0);
+        // Past a pole, back down the other side
+        assert!((Latitude::from_degrees(100.0).degrees() - 80.0).abs() < 1e-12);
+        assert!((Latitude::from_degrees(-100.0).degrees() + 80.0).abs() < 1e-12);
+        assert!((Latitude::from_degrees(200.0).degrees() + 20.0).abs() < 1e-12);
+        assert_eq!(Latitude::from_radians(PI).radians(), 0.0);
+        assert_eq!(Latitude::from_radians(-PI).radians(), 0.0);
+        assert_eq!(Angle::from_radians(PI).to_latitude().radians(), PI);
+        assert!(de.approx_eq(
+            Latitude::from_degrees(-25.50001),
+            Angle::from_degminsec(0, 0, 1.0)
+        ));
+    }
+
+    #[test]
+    fn test_date_ord() {
+        use std::collections::{BTreeSet, HashSet};
+        let a = Date::from_calendar(2025, 4, 16, Angle::from_clock(12, 0, 0.0));
+        let b = Date::from_julian(a.julian() + 0.0001 / 86400.0);
+        let c = Date::from_julian(a.julian() + 1.0 / 86400.0);
+        assert_eq!(a, b);
+        assert!(a < c && c > b);
+        assert_eq!(HashSet::from([a, b, c]).len(), 2);
+        let sorted: Vec<Date> = BTreeSet::from([c, J2000, a]).into_iter().collect();
+        assert_eq!(sorted, vec![J2000, a, c]);
+
+        // Far past the milliseconds an i64 can count, and NaN, which is only equal to itself
+        let (far, farther) = (Date::from_julian(2e11), Date::from_julian(2e11 + 1.0));
+        assert!(far < farther && far != farther);
+        assert_eq!(far, Date::from_julian(2e11));
+        let nan = Date::from_julian(f64::NAN);
+        assert_ne!(nan, Date::from_julian(0.0));
+        assert_eq!(nan, nan);
+        assert_eq!(HashSet::from([nan, Date::from_julian(0.0)]).len(), 2);
+        // Rounding up to the next day is the start of that day
+        let midnight = Date::from_jd_parts(2451545.0, 1.0 - 1e-10);
+        assert_eq!(midnight, Date::from_julian(2451546.0));
+    }
+
+    #[test]
+    fn test_range() {
+        let start = Date::from_calendar(2025, 4, 16, Angle::default());
+        let end = Date::from_calendar(2025, 4, 18, Angle::default());
+        let six_hours = std::time::Duration::from_secs(6 * 3600);
+        let dates: Vec<Date> = Date::range(start, end, six_hours).collect();
+        assert_eq!(dates.len(), 8);
+        assert_eq!(dates[0], start);
+        assert_eq!(
+            dates[7],
+            Date::from_calendar(2025, 4, 17, Angle::from_clock(18, 0, 0.0))
+        );
+        // A year of minutes doesn't drift
+        let minute = std::time::Duration::from_secs(60);
+        let next_year = Date::from_calendar(2026, 4, 16, Angle::default());
+        assert_eq!(Date::range(start, next_year, minute).count(), 365 * 1440);
+        assert_eq!(Date::range(end, start, six_hours).count(), 0);
+        assert_eq!(
+            Date::range(start, end, std::time::Duration::ZERO).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_decimal_year() {
+        let d = Date::from_calendar(2025, 7, 2, Angle::from_clock(12, 0, 0.0));
+        assert_eq!(d.decimal_year(), 2025.5);
+        assert_eq!(Date::from_decimal_year(2025.5), d);
+        // Leap years are longer
+        assert_eq!(
+            Date::from_calendar(2024, 7, 2, Angle::default()).decimal_year(),
+            2024.5
+        );
+        assert_eq!(
+            Date::from_decimal_year(-44.0).calendar(),
+            (-44, 1, 1, Angle::default())
+        );
+        let y = Date::from_decimal_year(2025.29).decimal_year();
+        assert!((y - 2025.29).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_era() {
+        let ms = Angle::from_clock(0, 0, 0.001);
+        assert_approx_eq!(J2000.era(), Angle::from_degrees(280.46061837504), ms);
+        // Astronomical Algorithms, Example 12.b
+        let d = Date::from_calendar(1987, 4, 10, Angle::from_clock(19, 21, 0.0));
+        assert_approx_eq!(
+            d.gmst(),
+            Angle::from_clock(8, 34, 57.0896),
+            Angle::from_clock(0, 0, 0.01)
+        );
+    }
+
     #[test]
     fn test_lati() {
         assert_eq!(
@@ -499,6 +2247,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_approx_eq() {
+        let a = Angle::from_degminsec(19, 2, 50.1);
+        assert_ne!(a, Angle::from_degminsec(19, 2, 50.2));
+        assert!(a.approx_eq(
+            Angle::from_degminsec(19, 2, 50.2),
+            Angle::from_degminsec(0, 0, 0.2)
+        ));
+        assert!(!a.approx_eq(
+            Angle::from_degminsec(19, 2, 50.2),
+            Angle::from_degminsec(0, 0, 0.05)
+        ));
+        // Across 0°
+        assert!(Angle::from_degrees(359.99)
+            .approx_eq(Angle::from_degrees(0.01), Angle::from_degrees(0.03)));
+        assert!(!Angle::from_degrees(180.0)
+            .approx_eq(Angle::from_degrees(0.0), Angle::from_degrees(179.0)));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx() {
+        let a = Angle::from_degrees(359.999_999_9);
+        approx::assert_abs_diff_eq!(a, Angle::from_degrees(0.0), epsilon = 1e-8);
+        approx::assert_relative_ne!(a, Angle::from_degrees(0.0));
+        approx::assert_relative_eq!(Angle::from_degrees(90.0), Angle::from_turns(0.25));
+    }
+
+    #[test]
+    fn test_signed_diff() {
+        let d = |a: f64, b: f64| {
+            Angle::from_degrees(a)
+                .signed_diff(Angle::from_degrees(b))
+                .degrees()
+        };
+        assert!((d(5.0, 355.0) - 10.0).abs() < 1e-9);
+        assert!((d(355.0, 5.0) + 10.0).abs() < 1e-9);
+        assert!((d(90.0, 270.0) - 180.0).abs() < 1e-9);
+        assert!((d(270.0, 90.0) - 180.0).abs() < 1e-9);
+        assert_eq!(d(42.0, 42.0), 0.0);
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut a = Angle::from_degrees(350.0);
+        a += 15.0;
+        assert_approx_eq!(a, Angle::from_degrees(5.0));
+        a -= Angle::from_degrees(10.0);
+        assert_approx_eq!(a, Angle::from_degrees(355.0));
+        a *= 2.0;
+        assert_approx_eq!(a, Angle::from_degrees(350.0));
+        assert_approx_eq!(-a, Angle::from_degrees(10.0));
+        assert_approx_eq!(a - 360.5, Angle::from_degrees(349.5));
+        let terms = [6.289, -1.274, 0.658, 0.214].map(Angle::from_degrees);
+        assert_approx_eq!(terms.into_iter().sum::<Angle>(), Angle::from_degrees(5.887));
+    }
+
     #[test]
     fn test_turn() {
         assert_eq!(Angle::from_turns(0.5), Angle::from_degrees(180.0));
@@ -509,11 +2314,206 @@ mod tests {
     fn test_easter() {
         assert_eq!(easter(2000), (4, 23));
         assert_eq!(easter(2024), (3, 31));
+        assert_eq!(easter(2026), (4, 5));
+        assert_eq!(easter(1961), (4, 2));
+        assert_eq!(easter_julian(2025), (4, 7));
+        assert_eq!(
+            easter_date(2025, true),
+            Date::from_calendar(2025, 4, 20, Angle::default())
+        );
+        assert_eq!(
+            easter_date(2024, true),
+            Date::from_calendar(2024, 5, 5, Angle::default())
+        );
+        assert_eq!(
+            ash_wednesday(2024, false),
+            Date::from_calendar(2024, 2, 14, Angle::default())
+        );
+        // Clean Monday, a Monday
+        assert_eq!(
+            ash_wednesday(2024, true),
+            Date::from_calendar(2024, 3, 18, Angle::default())
+        );
+        assert_eq!(ash_wednesday(2024, true).weekday(), 1);
+        assert_eq!(ash_wednesday(2024, false).weekday(), 3);
+        assert_eq!(
+            pentecost(2024, false),
+            Date::from_calendar(2024, 5, 19, Angle::default())
+        );
+        assert_eq!(
+            advent(2024),
+            Date::from_calendar(2024, 12, 1, Angle::default())
+        );
+        assert_eq!(
+            advent(2023),
+            Date::from_calendar(2023, 12, 3, Angle::default())
+        );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_refract() {
+    fn test_chrono() {
+        let t = chrono::DateTime::from_timestamp(1_744_832_471, 250_001_000).unwrap();
+        assert_eq!(Date::from(t).unix(), 1_744_832_471.250_001);
+        assert_eq!(
+            chrono::DateTime::<chrono::Utc>::try_from(Date::from(t)),
+            Ok(t)
+        );
+        for far in [
+            Date::from_julian(1e12),
+            Date::from_julian(-1e12),
+            Date::from_julian(f64::NAN),
+        ] {
+            assert_eq!(
+                chrono::DateTime::<chrono::Utc>::try_from(far),
+                Err(DateOutOfRange)
+            );
+        }
+        assert_eq!(
+            Date::from(chrono::NaiveDate::from_ymd_opt(1985, 2, 17).unwrap()),
+            Date::from_calendar(1985, 2, 17, Angle::default())
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_crate() {
+        let t =
+            ::time::OffsetDateTime::from_unix_timestamp_nanos(1_744_832_471_250_001_000).unwrap();
+        assert_eq!(::time::OffsetDateTime::try_from(Date::from(t)), Ok(t));
+        for far in [
+            Date::from_julian(1e12),
+            Date::from_julian(1e40),
+            Date::from_julian(f64::NAN),
+        ] {
+            assert_eq!(::time::OffsetDateTime::try_from(far), Err(DateOutOfRange));
+        }
+        assert_eq!(
+            Date::from(::time::OffsetDateTime::UNIX_EPOCH),
+            Date::from_calendar(1970, 1, 1, Angle::default())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let d = Date::from_calendar(2025, 4, 16, Angle::from_clock(19, 41, 11.25));
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, format!("[{:?},{:?}]", d.jd_parts().0, d.jd_parts().1));
+        assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), d);
+
+        let a = Angle::from_degminsec(19, 2, 50.1);
+        let json = serde_json::to_string(&a).unwrap();
         assert_eq!(
+            serde_json::from_str::<Angle>(&json).unwrap().radians(),
+            a.radians()
+        );
+        assert_eq!(
+            serde_json::from_str::<Angle>("-1.5707963267948966").unwrap(),
+            Angle::from_degrees(270.0)
+        );
+
+        let r = AngularRate::from_degrees_per_day(0.9856);
+        let back: AngularRate = serde_json::from_str(&serde_json::to_string(&r).unwrap()).unwrap();
+        assert_eq!(back, r);
+    }
+
+    #[test]
+    fn test_parse_angle() {
+        let tol = Angle::from_degminsec(0, 0, 1e-6);
+        let parse = |x: &str| x.parse::<Angle>().unwrap();
+        assert_approx_eq!(parse("14h16m50s"), Angle::from_clock(14, 16, 50.0), tol);
+        assert_approx_eq!(
+            parse("19°02'50.1\""),
+            Angle::from_degminsec(19, 2, 50.1),
+            tol
+        );
+        assert_approx_eq!(
+            parse("-16:45:06.8"),
+            Angle::from_degrees(-(16.0 + 45.0 / 60.0 + 6.8 / 3600.0)),
+            tol
+        );
+        assert_approx_eq!(parse("133.94531"), Angle::from_degrees(133.94531), tol);
+        assert_approx_eq!(parse("6h45m"), Angle::from_clock(6, 45, 0.0), tol);
+        assert_approx_eq!(parse("12d30m"), Angle::from_degminsec(12, 30, 0.0), tol);
+        assert_eq!("".parse::<Angle>(), Err(ParseAngleError));
+        assert_eq!("12h75m".parse::<Angle>(), Err(ParseAngleError));
+        assert_eq!("1:2:3:4".parse::<Angle>(), Err(ParseAngleError));
+        assert_eq!("north".parse::<Angle>(), Err(ParseAngleError));
+        for s in ["nan", "NaN", "-inf", "infinity", "+Infinity", "1e400"] {
+            assert_eq!(s.parse::<Angle>(), Err(ParseAngleError), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Angle::from_clock(14, 16, 50.0).fmt_hms(1), "14h16m50.0s");
+        assert_eq!(Angle::from_clock(6, 5, 3.25).fmt_hms(0), "6h05m03s");
+        assert_eq!(
+            Angle::from_degminsec(19, 2, 50.1).fmt_dms(1),
+            "19°02'50.1\""
+        );
+        assert_eq!(
+            Angle::from_degrees(-25.5).to_latitude().fmt_dms(0),
+            "-25°30'00\""
+        );
+        assert_eq!(
+            format!("{:.1}", Angle::from_degrees(-25.5)),
+            "334°30'00.0\""
+        );
+        assert_eq!(format!("{}", Angle::from_degrees(90.0)), "90°00'00.00\"");
+        assert_eq!(
+            format!("{:.0}", Angle::from_degrees(359.99999)),
+            "0°00'00\""
+        );
+    }
+
+    #[test]
+    fn test_rounded() {
+        let a = Angle::from_clock(7, 59, 59.9999);
+        assert_eq!(a.clock().0, 7);
+        assert_eq!(a.clock_rounded(2), (8, 0, 0.0));
+        assert_eq!(a.clock_rounded(4), (7, 59, 59.9999));
+        assert_eq!(
+            Angle::from_clock(23, 59, 59.99).clock_rounded(1),
+            (0, 0, 0.0)
+        );
+        assert_eq!(
+            Angle::from_degminsec(19, 2, 59.996).degminsec_rounded(2),
+            (false, 19, 3, 0.0)
+        );
+        assert_eq!(
+            Angle::from_degrees(-25.5)
+                .to_latitude()
+                .degminsec_rounded(0),
+            (true, 25, 30, 0.0)
+        );
+        // Less than a degree south is still south
+        assert_eq!(
+            Angle::from_degrees(-0.5).to_latitude().degminsec_rounded(0),
+            (true, 0, 30, 0.0)
+        );
+        assert_eq!(
+            format!("{:?}", Angle::from_degrees(-0.5).to_latitude()),
+            "-0°30'0.00\""
+        );
+    }
+
+    #[test]
+    fn test_unrefract() {
+        for alt in [0.5, 5.0, 25.0, 80.0] {
+            let h = Angle::from_degrees(alt);
+            assert!((h.refract().unrefract().degrees() - alt).abs() < 1e-6);
+        }
+        assert_eq!(
+            Angle::from_degrees(-25.0).unrefract(),
+            Angle::from_degminsec(-25, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_refract() {
+        assert_approx_eq!(
             Angle::from_degrees(25.0).refractdelta(),
             Angle::from_degminsec(0, 2, 9.2)
         );